@@ -0,0 +1,105 @@
+//! Starknet accounts
+//!
+//! Starknet account is a contract which verifies signatures with the public key passed to its
+//! constructor. Address of the account is known before it's deployed (i.e. counterfactual), as it's
+//! derived from the class hash, salt, and constructor calldata. Popular account implementations use the
+//! public key as the salt and differ in constructor calldata layout.
+//!
+//! ```rust
+//! use stark_curve::{account, elliptic_curve::Field, FieldElement, Scalar};
+//!
+//! # let class_hash = FieldElement::from(0x123);
+//! let mut rng = rand::rngs::OsRng;
+//! let secret = Scalar::random(&mut rng);
+//! let public_key = account::public_key(&secret);
+//!
+//! let address = account::openzeppelin_address(class_hash, public_key);
+//! println!("public key: {public_key:#x}, address: {address:#x}");
+//! ```
+
+use crate::bigint::U256;
+use crate::hash::pedersen;
+use crate::{FieldElement, ProjectivePoint, Scalar};
+
+/// Contract addresses are less than $2^{251} - 256$
+pub const ADDRESS_UPPER_BOUND: U256 =
+    U256::from_be_hex("07ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff00");
+
+/// Computes Stark public key corresponding to `secret`
+///
+/// Public key is the $x$ coordinate of $\text{secret} \cdot G$. Returns zero if `secret` is zero.
+pub fn public_key(secret: &Scalar) -> FieldElement {
+    crate::affine_coordinates(&(ProjectivePoint::GENERATOR * secret).to_affine())
+        .map(|(x, _y)| x)
+        .unwrap_or_default()
+}
+
+/// Computes address of a contract
+///
+/// $$
+/// \text{address} = H(\text{"STARKNET\\_CONTRACT\\_ADDRESS"}, \text{deployer}, \text{salt}, \text{class\\_hash},
+///     H(\text{calldata})) \bmod (2^{251} - 256)
+/// $$
+///
+/// where $H$ is [Pedersen array hash](pedersen::hash_array). `deployer_address` is zero for contracts
+/// deployed via `DEPLOY_ACCOUNT` transaction or via deploy syscall with `deploy_from_zero` flag set.
+pub fn contract_address(
+    deployer_address: FieldElement,
+    salt: FieldElement,
+    class_hash: FieldElement,
+    constructor_calldata: &[FieldElement],
+) -> FieldElement {
+    let prefix = FieldElement::from_be_bytes_mod_order(b"STARKNET_CONTRACT_ADDRESS");
+    let address = pedersen::hash_array(&[
+        prefix,
+        deployer_address,
+        salt,
+        class_hash,
+        pedersen::hash_array(constructor_calldata),
+    ]);
+    reduce_address(address)
+}
+
+/// Reduces `address` modulo [`ADDRESS_UPPER_BOUND`]
+pub(crate) fn reduce_address(address: FieldElement) -> FieldElement {
+    let address = address.to_uint();
+    if address >= ADDRESS_UPPER_BOUND {
+        // Field modulus is less than twice the bound, so single subtraction is enough
+        FieldElement::from_uint_mod_order(&address.wrapping_sub(&ADDRESS_UPPER_BOUND))
+    } else {
+        FieldElement::from_uint_mod_order(&address)
+    }
+}
+
+/// Computes counterfactual address of OpenZeppelin account
+///
+/// Constructor calldata is `[public_key]`, salt is the public key.
+pub fn openzeppelin_address(class_hash: FieldElement, public_key: FieldElement) -> FieldElement {
+    contract_address(FieldElement::ZERO, public_key, class_hash, &[public_key])
+}
+
+/// Computes counterfactual address of Argent account
+///
+/// Constructor calldata is `[owner, guardian]` (Argent account v0.3), salt is the owner public key.
+/// `guardian` is zero if account has no guardian.
+pub fn argent_address(
+    class_hash: FieldElement,
+    owner: FieldElement,
+    guardian: FieldElement,
+) -> FieldElement {
+    contract_address(FieldElement::ZERO, owner, class_hash, &[owner, guardian])
+}
+
+/// Computes counterfactual address of Braavos account
+///
+/// Braavos accounts are deployed as a base account which is upgraded to the actual implementation
+/// in the constructor, so `base_class_hash` is the class hash of the base account. Constructor calldata
+/// is `[public_key]`, salt is the public key.
+pub fn braavos_address(base_class_hash: FieldElement, public_key: FieldElement) -> FieldElement {
+    contract_address(
+        FieldElement::ZERO,
+        public_key,
+        base_class_hash,
+        &[public_key],
+    )
+}
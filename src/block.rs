@@ -0,0 +1,441 @@
+//! Starknet block hashes
+//!
+//! Block hash commits to the block header, which in turn contains commitments to transactions, events,
+//! and, since Starknet v0.13.2, receipts and the state diff. Commitments to lists are roots of
+//! [tries](trie) of height $64$ keyed by index.
+//!
+//! Block hash layout changed over time, see [`BlockHashVersion`]. Blocks produced before v0.13.2 were
+//! later rehashed with the v0.13.2 layout, so both hashes exist for them.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::hash::{pedersen, poseidon, sn_keccak};
+use crate::trie::{self, TrieHash};
+use crate::FieldElement;
+
+/// Height of commitment tries
+const COMMITMENT_TRIE_HEIGHT: u8 = 64;
+
+/// Layout of block hash and commitments
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BlockHashVersion {
+    /// Pedersen hash of the header, used before Starknet v0.13.2
+    Legacy,
+    /// Poseidon hash of the header with `STARKNET_BLOCK_HASH0` prefix, since Starknet v0.13.2
+    V0_13_2,
+    /// Poseidon hash of the header with `STARKNET_BLOCK_HASH1` prefix, gas prices (including L2 gas)
+    /// are hashed separately, since Starknet v0.13.4
+    V0_13_4,
+}
+
+/// Transaction hash and signature, leaf of the transaction commitment
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransactionData {
+    /// Transaction hash
+    pub hash: FieldElement,
+    /// Transaction signature, empty for transactions without one
+    pub signature: Vec<FieldElement>,
+}
+
+impl TransactionData {
+    /// Computes the leaf of the transaction commitment
+    ///
+    /// Legacy leaf is Pedersen hash of transaction hash and [array hash](pedersen::hash_array) of the
+    /// signature. Since v0.13.2, it's Poseidon hash of transaction hash followed by the signature, where
+    /// empty signature is replaced by a single zero.
+    pub fn leaf(&self, version: BlockHashVersion) -> FieldElement {
+        match version {
+            BlockHashVersion::Legacy => {
+                pedersen::hash(self.hash, pedersen::hash_array(&self.signature))
+            }
+            _ => {
+                let mut hasher = poseidon::Hasher::new().chain(self.hash);
+                if self.signature.is_empty() {
+                    hasher.update(FieldElement::ZERO);
+                }
+                self.signature.iter().for_each(|s| hasher.update(*s));
+                hasher.finalize()
+            }
+        }
+    }
+}
+
+/// Event emitted by a transaction
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Event {
+    /// Address of the contract emitting the event
+    pub from_address: FieldElement,
+    /// Hash of the transaction emitting the event, not included in legacy hash
+    pub transaction_hash: FieldElement,
+    /// Keys of the event, the first key is usually the event selector
+    pub keys: Vec<FieldElement>,
+    /// Data of the event
+    pub data: Vec<FieldElement>,
+}
+
+impl Event {
+    /// Computes the leaf of the event commitment
+    ///
+    /// Legacy leaf is $H(\text{from\\_address}, H(\text{keys}), H(\text{data}))$ where $H$ is
+    /// [Pedersen array hash](pedersen::hash_array). Since v0.13.2, it's Poseidon hash of from address,
+    /// transaction hash, number of keys, keys, number of data elements, and data.
+    pub fn leaf(&self, version: BlockHashVersion) -> FieldElement {
+        match version {
+            BlockHashVersion::Legacy => pedersen::hash_array(&[
+                self.from_address,
+                pedersen::hash_array(&self.keys),
+                pedersen::hash_array(&self.data),
+            ]),
+            _ => {
+                let hasher = poseidon::Hasher::new()
+                    .chain(self.from_address)
+                    .chain(self.transaction_hash);
+                let hasher = chain_array(hasher, &self.keys);
+                chain_array(hasher, &self.data).finalize()
+            }
+        }
+    }
+}
+
+/// Message sent from L2 to L1
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MessageToL1 {
+    /// Address of L2 contract sending the message
+    pub from_address: FieldElement,
+    /// Address of L1 contract receiving the message
+    pub to_address: FieldElement,
+    /// Payload of the message
+    pub payload: Vec<FieldElement>,
+}
+
+/// Transaction receipt, leaf of the receipt commitment (since v0.13.2)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Receipt {
+    /// Transaction hash
+    pub transaction_hash: FieldElement,
+    /// Fee charged for the transaction
+    pub actual_fee: FieldElement,
+    /// Messages sent to L1
+    pub messages_sent: Vec<MessageToL1>,
+    /// Revert reason, `None` if the transaction succeeded
+    pub revert_reason: Option<String>,
+    /// L1 gas consumed by the transaction
+    pub l1_gas: FieldElement,
+    /// L1 data gas consumed by the transaction
+    pub l1_data_gas: FieldElement,
+}
+
+impl Receipt {
+    /// Computes the leaf of the receipt commitment
+    ///
+    /// Leaf is Poseidon hash of transaction hash, actual fee, hash of messages, [`sn_keccak`] of revert
+    /// reason (zero if the transaction succeeded), zero (L2 gas, not committed to), L1 gas, and L1 data gas.
+    /// Hash of messages is Poseidon hash of the number of messages followed by from address, to address,
+    /// payload length, and payload of each message.
+    pub fn leaf(&self) -> FieldElement {
+        let mut messages = poseidon::Hasher::new().chain((self.messages_sent.len() as u64).into());
+        for message in &self.messages_sent {
+            messages.update(message.from_address);
+            messages.update(message.to_address);
+            messages = chain_array(messages, &message.payload);
+        }
+        let revert_reason = self
+            .revert_reason
+            .as_ref()
+            .map(|reason| sn_keccak(reason.as_bytes()))
+            .unwrap_or_default();
+
+        poseidon::Hasher::new()
+            .chain(self.transaction_hash)
+            .chain(self.actual_fee)
+            .chain(messages.finalize())
+            .chain(revert_reason)
+            .chain(FieldElement::ZERO)
+            .chain(self.l1_gas)
+            .chain(self.l1_data_gas)
+            .finalize()
+    }
+}
+
+/// Computes transaction commitment
+///
+/// Returns error if some leaf hash is zero, which can only happen with negligible probability.
+pub fn transaction_commitment(
+    transactions: &[TransactionData],
+    version: BlockHashVersion,
+) -> Result<FieldElement, trie::Error> {
+    commitment(transactions.iter().map(|tx| tx.leaf(version)), version)
+}
+
+/// Computes event commitment
+///
+/// `events` are all events emitted in the block, in order. Returns error if some leaf hash is zero,
+/// which can only happen with negligible probability.
+pub fn event_commitment(
+    events: &[Event],
+    version: BlockHashVersion,
+) -> Result<FieldElement, trie::Error> {
+    commitment(events.iter().map(|event| event.leaf(version)), version)
+}
+
+/// Computes receipt commitment (since v0.13.2)
+///
+/// Returns error if some leaf hash is zero, which can only happen with negligible probability.
+pub fn receipt_commitment(receipts: &[Receipt]) -> Result<FieldElement, trie::Error> {
+    commitment(
+        receipts.iter().map(Receipt::leaf),
+        BlockHashVersion::V0_13_2,
+    )
+}
+
+/// Computes root of commitment trie with `leaves` keyed by index
+///
+/// Keys are always sorted and in range, so it only fails if some leaf is zero
+fn commitment(
+    leaves: impl Iterator<Item = FieldElement>,
+    version: BlockHashVersion,
+) -> Result<FieldElement, trie::Error> {
+    let hash = match version {
+        BlockHashVersion::Legacy => TrieHash::Pedersen,
+        _ => TrieHash::Poseidon,
+    };
+    let leaves: Vec<_> = (0u64..).map(FieldElement::from).zip(leaves).collect();
+    trie::root(COMMITMENT_TRIE_HEIGHT, &leaves, hash)
+}
+
+/// Absorbs length of `array` followed by its elements
+fn chain_array(mut hasher: poseidon::Hasher, array: &[FieldElement]) -> poseidon::Hasher {
+    hasher.update((array.len() as u64).into());
+    array.iter().for_each(|x| hasher.update(*x));
+    hasher
+}
+
+/// State changes made by a block
+///
+/// Order of entries doesn't matter, they're sorted by key when hashed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    /// Addresses of deployed contracts and contracts with replaced class, with their new class hashes
+    pub deployed_contracts: Vec<(FieldElement, FieldElement)>,
+    /// Declared Sierra class hashes with their compiled class hashes
+    pub declared_classes: Vec<(FieldElement, FieldElement)>,
+    /// Declared Cairo 0 class hashes
+    pub deprecated_declared_classes: Vec<FieldElement>,
+    /// Contract addresses with their storage updates (key and new value)
+    pub storage_diffs: Vec<(FieldElement, Vec<(FieldElement, FieldElement)>)>,
+    /// Contract addresses with their new nonces
+    pub nonces: Vec<(FieldElement, FieldElement)>,
+}
+
+impl StateDiff {
+    /// Returns length of the state diff, which is included in the block hash
+    ///
+    /// Length is the total number of deployed contracts, declared classes, nonce updates, and storage
+    /// updates.
+    pub fn len(&self) -> u64 {
+        let storage_updates: usize = self.storage_diffs.iter().map(|(_, diff)| diff.len()).sum();
+        (self.deployed_contracts.len()
+            + self.declared_classes.len()
+            + self.deprecated_declared_classes.len()
+            + self.nonces.len()
+            + storage_updates) as u64
+    }
+
+    /// Checks whether the state diff is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Computes the state diff commitment (since v0.13.2)
+    ///
+    /// Commitment is Poseidon hash of `'STARKNET_STATE_DIFF0'` followed by, in order: deployed contracts,
+    /// declared classes, deprecated declared classes, $1$ and $0$ (reserved), storage diffs of contracts with
+    /// non-empty diffs, and nonces. Each list is prefixed with its length and sorted by key, storage
+    /// diff of a contract is its address followed by the list of its updates.
+    pub fn commitment(&self) -> FieldElement {
+        let sorted_pairs = |pairs: &[(FieldElement, FieldElement)]| {
+            let mut pairs = pairs.to_vec();
+            pairs.sort_by_key(|(key, _)| key.to_uint());
+            pairs
+        };
+        let chain_pairs = |mut hasher: poseidon::Hasher, pairs: &[(FieldElement, FieldElement)]| {
+            hasher.update((pairs.len() as u64).into());
+            for (key, value) in sorted_pairs(pairs) {
+                hasher.update(key);
+                hasher.update(value);
+            }
+            hasher
+        };
+
+        let hasher = poseidon::Hasher::new().chain(FieldElement::from_be_bytes_mod_order(
+            b"STARKNET_STATE_DIFF0",
+        ));
+        let hasher = chain_pairs(hasher, &self.deployed_contracts);
+        let hasher = chain_pairs(hasher, &self.declared_classes);
+
+        let mut deprecated_classes = self.deprecated_declared_classes.clone();
+        deprecated_classes.sort_by_key(|class_hash| class_hash.to_uint());
+        let mut hasher = chain_array(hasher, &deprecated_classes)
+            .chain(FieldElement::ONE)
+            .chain(FieldElement::ZERO);
+
+        let mut storage_diffs: Vec<_> = self
+            .storage_diffs
+            .iter()
+            .filter(|(_, diff)| !diff.is_empty())
+            .collect();
+        storage_diffs.sort_by_key(|(address, _)| address.to_uint());
+        hasher.update((storage_diffs.len() as u64).into());
+        for (address, diff) in storage_diffs {
+            hasher.update(*address);
+            hasher = chain_pairs(hasher, diff);
+        }
+
+        chain_pairs(hasher, &self.nonces).finalize()
+    }
+}
+
+/// Mode of publishing state diffs to L1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum L1DataAvailabilityMode {
+    /// State diff is published as calldata
+    #[default]
+    Calldata,
+    /// State diff is published as blob
+    Blob,
+}
+
+/// Gas prices of a block
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GasPrices {
+    /// Price of L1 gas in Wei
+    pub l1_gas_price_wei: FieldElement,
+    /// Price of L1 gas in Fri
+    pub l1_gas_price_fri: FieldElement,
+    /// Price of L1 data gas in Wei
+    pub l1_data_gas_price_wei: FieldElement,
+    /// Price of L1 data gas in Fri
+    pub l1_data_gas_price_fri: FieldElement,
+    /// Price of L2 gas in Wei, hashed since v0.13.4
+    pub l2_gas_price_wei: FieldElement,
+    /// Price of L2 gas in Fri, hashed since v0.13.4
+    pub l2_gas_price_fri: FieldElement,
+}
+
+/// Block header
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlockHeader {
+    /// Hash of the parent block
+    pub parent_hash: FieldElement,
+    /// Number of the block
+    pub block_number: u64,
+    /// Global state root after the block
+    pub state_root: FieldElement,
+    /// Address of the sequencer
+    pub sequencer_address: FieldElement,
+    /// Unix timestamp of the block
+    pub timestamp: u64,
+    /// Number of transactions in the block
+    pub transaction_count: u64,
+    /// Number of events emitted in the block
+    pub event_count: u64,
+    /// Length of the state diff, see [`StateDiff::len`] (since v0.13.2)
+    pub state_diff_length: u64,
+    /// See [`transaction_commitment`]
+    pub transaction_commitment: FieldElement,
+    /// See [`event_commitment`]
+    pub event_commitment: FieldElement,
+    /// See [`receipt_commitment`] (since v0.13.2)
+    pub receipt_commitment: FieldElement,
+    /// See [`StateDiff::commitment`] (since v0.13.2)
+    pub state_diff_commitment: FieldElement,
+    /// Gas prices (since v0.13.2)
+    pub gas_prices: GasPrices,
+    /// Mode of publishing state diff to L1 (since v0.13.2)
+    pub l1_da_mode: L1DataAvailabilityMode,
+    /// Starknet version as short string, e.g. `'0.13.2'` (since v0.13.2)
+    pub protocol_version: FieldElement,
+}
+
+impl BlockHeader {
+    /// Computes the block hash
+    ///
+    /// Legacy hash is [Pedersen array hash](pedersen::hash_array) of block number, state root, sequencer
+    /// address, timestamp, transaction count, transaction commitment, event count, event commitment, two
+    /// zeroes, and parent hash.
+    ///
+    /// Since v0.13.2, block hash is Poseidon hash of the prefix, block number, state root, sequencer
+    /// address, timestamp, packed counts, state diff commitment, transaction commitment, event
+    /// commitment, receipt commitment, gas prices, protocol version, zero, and parent hash. Counts are
+    /// packed as 64-bit transaction count, event count, state diff length, followed by one bit of L1 DA mode
+    /// and zero padding. Gas prices are L1 gas and L1 data gas prices in Wei and Fri, and since v0.13.4
+    /// they're replaced by a single Poseidon hash of `'STARKNET_GAS_PRICES0'` and all six gas prices.
+    pub fn hash(&self, version: BlockHashVersion) -> FieldElement {
+        let prefix: &[u8] = match version {
+            BlockHashVersion::Legacy => {
+                return pedersen::hash_array(&[
+                    FieldElement::from(self.block_number),
+                    self.state_root,
+                    self.sequencer_address,
+                    FieldElement::from(self.timestamp),
+                    FieldElement::from(self.transaction_count),
+                    self.transaction_commitment,
+                    FieldElement::from(self.event_count),
+                    self.event_commitment,
+                    FieldElement::ZERO,
+                    FieldElement::ZERO,
+                    self.parent_hash,
+                ])
+            }
+            BlockHashVersion::V0_13_2 => b"STARKNET_BLOCK_HASH0",
+            BlockHashVersion::V0_13_4 => b"STARKNET_BLOCK_HASH1",
+        };
+
+        let mut counts = [0u8; 32];
+        counts[..8].copy_from_slice(&self.transaction_count.to_be_bytes());
+        counts[8..16].copy_from_slice(&self.event_count.to_be_bytes());
+        counts[16..24].copy_from_slice(&self.state_diff_length.to_be_bytes());
+        if self.l1_da_mode == L1DataAvailabilityMode::Blob {
+            counts[24] = 0x80;
+        }
+
+        let mut hasher = poseidon::Hasher::new()
+            .chain(FieldElement::from_be_bytes_mod_order(prefix))
+            .chain(FieldElement::from(self.block_number))
+            .chain(self.state_root)
+            .chain(self.sequencer_address)
+            .chain(FieldElement::from(self.timestamp))
+            .chain(FieldElement::from_be_bytes_mod_order(&counts))
+            .chain(self.state_diff_commitment)
+            .chain(self.transaction_commitment)
+            .chain(self.event_commitment)
+            .chain(self.receipt_commitment);
+
+        let prices = &self.gas_prices;
+        let l1_prices = [
+            prices.l1_gas_price_wei,
+            prices.l1_gas_price_fri,
+            prices.l1_data_gas_price_wei,
+            prices.l1_data_gas_price_fri,
+        ];
+        if version == BlockHashVersion::V0_13_2 {
+            l1_prices.iter().for_each(|price| hasher.update(*price));
+        } else {
+            let prices_hash = poseidon::hash_many(
+                [FieldElement::from_be_bytes_mod_order(
+                    b"STARKNET_GAS_PRICES0",
+                )]
+                .iter()
+                .chain(&l1_prices)
+                .chain(&[prices.l2_gas_price_wei, prices.l2_gas_price_fri]),
+            );
+            hasher.update(prices_hash);
+        }
+
+        hasher
+            .chain(self.protocol_version)
+            .chain(FieldElement::ZERO)
+            .chain(self.parent_hash)
+            .finalize()
+    }
+}
@@ -0,0 +1,919 @@
+//! Encoding and decoding values described by Cairo 1 contract ABI
+//!
+//! [`Abi`] is parsed from the ABI JSON of a Sierra class, and encodes and decodes function calldata,
+//! return values, and events at runtime, without generating code for each contract. Values are
+//! represented by [`Value`], and are laid out as in [Cairo Serde](super::serde).
+//!
+//! ```rust
+//! use stark_curve::cairo::abi::{Abi, Value};
+//! use stark_curve::{bigint::U256, FieldElement};
+//!
+//! # fn main() -> Result<(), stark_curve::cairo::abi::Error> {
+//! let abi = Abi::from_json(r#"[
+//!     {
+//!         "type": "function",
+//!         "name": "transfer",
+//!         "inputs": [
+//!             {"name": "recipient", "type": "core::starknet::contract_address::ContractAddress"},
+//!             {"name": "amount", "type": "core::integer::u256"}
+//!         ],
+//!         "outputs": [{"type": "core::bool"}],
+//!         "state_mutability": "external"
+//!     }
+//! ]"#)?;
+//!
+//! let calldata = abi.encode_inputs(
+//!     "transfer",
+//!     &[Value::Felt(FieldElement::from(0x1234)), Value::UnsignedInteger(U256::from_u64(100))],
+//! )?;
+//! assert_eq!(calldata.len(), 3);
+//! assert_eq!(abi.decode_outputs("transfer", &[FieldElement::ONE])?, [Value::Boolean(true)]);
+//! # Ok(()) }
+//! ```
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use super::serde::{CairoDeserialize, CairoSerialize};
+use crate::bigint::U256;
+use crate::hash::sn_keccak;
+use crate::messaging::EthAddress;
+use crate::types::{ContractAddress, StorageKey};
+use crate::FieldElement;
+
+/// Max nesting of types, protects from infinitely recursive types in malformed ABI
+const MAX_DEPTH: usize = 128;
+
+/// Contract ABI
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Abi {
+    /// Functions of the contract: external and view functions of all interfaces, constructor, and L1
+    /// handlers
+    pub functions: Vec<Function>,
+    /// Struct types
+    pub structs: Vec<Struct>,
+    /// Enum types
+    pub enums: Vec<Enum>,
+    /// Event types
+    pub events: Vec<Event>,
+}
+
+/// Function of a contract
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct Function {
+    /// Name of the function
+    pub name: String,
+    /// Arguments of the function
+    pub inputs: Vec<Member>,
+    /// Types of returned values, empty for constructor and L1 handlers
+    #[serde(default)]
+    pub outputs: Vec<Output>,
+}
+
+/// Returned value of a function
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct Output {
+    /// Type of the value
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+/// Struct type
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct Struct {
+    /// Full path of the type, e.g. `core::integer::u256`
+    pub name: String,
+    /// Members of the struct in order of serialization
+    pub members: Vec<Member>,
+}
+
+/// Enum type
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct Enum {
+    /// Full path of the type, e.g. `core::option::Option::<core::felt252>`
+    pub name: String,
+    /// Variants of the enum in order of their indexes
+    pub variants: Vec<Member>,
+}
+
+/// Named and typed member of a struct, variant of an enum, or argument of a function
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct Member {
+    /// Name of the member
+    pub name: String,
+    /// Type of the member
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+/// Event type
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct Event {
+    /// Full path of the type
+    pub name: String,
+    /// Members of a struct event, or variants of an enum event
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+impl Event {
+    /// Returns members of a struct event, or variants of an enum event
+    pub fn members(&self) -> &[EventMember] {
+        match &self.kind {
+            EventKind::Struct { members } => members,
+            EventKind::Enum { variants } => variants,
+        }
+    }
+}
+
+/// Layout of an event
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EventKind {
+    /// Event is a struct
+    Struct {
+        /// Members of the struct
+        members: Vec<EventMember>,
+    },
+    /// Event is an enum of other events
+    Enum {
+        /// Variants of the enum
+        variants: Vec<EventMember>,
+    },
+}
+
+/// Member of a struct event, or variant of an enum event
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct EventMember {
+    /// Name of the member
+    pub name: String,
+    /// Type of the member
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Where the member is stored
+    pub kind: EventMemberKind,
+}
+
+/// Where a member of an event is stored
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventMemberKind {
+    /// Member of a struct event serialized into keys
+    Key,
+    /// Member of a struct event serialized into data
+    Data,
+    /// Variant of an enum event, selector of the variant name is appended to keys
+    Nested,
+    /// Variant of an enum event, payload is emitted as is
+    Flat,
+}
+
+/// Item of ABI JSON
+#[derive(serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Item {
+    Function(Function),
+    Constructor(Function),
+    L1Handler(Function),
+    Interface {
+        items: Vec<Item>,
+    },
+    Struct(Struct),
+    Enum(Enum),
+    Event(Event),
+    #[serde(other)]
+    Other,
+}
+
+/// Value of a Cairo type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    /// `felt252`, and types represented by a single field element: `ContractAddress`, `ClassHash`,
+    /// `StorageAddress`, `EthAddress`, `bytes31`
+    Felt(FieldElement),
+    /// `bool`
+    Boolean(bool),
+    /// Unsigned integer from `u8` to `u256`
+    UnsignedInteger(U256),
+    /// Signed integer from `i8` to `i128`
+    SignedInteger(i128),
+    /// `ByteArray`
+    String(String),
+    /// `Array<T>`, `Span<T>`, or fixed-size array `[T; N]`
+    Array(Vec<Value>),
+    /// Tuple, `()` is the empty tuple
+    Tuple(Vec<Value>),
+    /// Struct, members are in order of serialization
+    Struct(Vec<(String, Value)>),
+    /// Variant of an enum
+    Enum {
+        /// Name of the variant
+        variant: String,
+        /// Payload of the variant, empty tuple if the variant has no payload
+        value: Box<Value>,
+    },
+}
+
+impl Value {
+    /// Constructs a struct from named members
+    pub fn structure<K: Into<String>>(members: impl IntoIterator<Item = (K, Value)>) -> Self {
+        Self::Struct(members.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+
+    /// Constructs a variant of an enum
+    pub fn variant(variant: impl Into<String>, value: Value) -> Self {
+        Self::Enum {
+            variant: variant.into(),
+            value: Box::new(value),
+        }
+    }
+
+    /// Constructs a variant of an enum without payload
+    pub fn unit_variant(variant: impl Into<String>) -> Self {
+        Self::variant(variant, Self::Tuple(Vec::new()))
+    }
+}
+
+impl From<FieldElement> for Value {
+    fn from(x: FieldElement) -> Self {
+        Self::Felt(x)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Self::String(s.into())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Self::String(s)
+    }
+}
+
+impl From<U256> for Value {
+    fn from(x: U256) -> Self {
+        Self::UnsignedInteger(x)
+    }
+}
+
+impl From<u128> for Value {
+    fn from(x: u128) -> Self {
+        Self::UnsignedInteger(U256::from_u128(x))
+    }
+}
+
+impl From<i128> for Value {
+    fn from(x: i128) -> Self {
+        Self::SignedInteger(x)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(x: bool) -> Self {
+        Self::Boolean(x)
+    }
+}
+
+impl From<Vec<Value>> for Value {
+    fn from(x: Vec<Value>) -> Self {
+        Self::Array(x)
+    }
+}
+
+impl Abi {
+    /// Parses ABI JSON
+    ///
+    /// ABI is the `abi` field of a Sierra class, which is either a JSON array or a string containing it.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let items: Vec<Item> = match serde_json::from_str::<String>(json) {
+            Ok(inner) => serde_json::from_str(&inner),
+            Err(_) => serde_json::from_str(json),
+        }
+        .map_err(|err| Error(Reason::Json(err.to_string())))?;
+
+        let mut abi = Self::default();
+        abi.extend(items);
+        Ok(abi)
+    }
+
+    fn extend(&mut self, items: Vec<Item>) {
+        for item in items {
+            match item {
+                Item::Function(f) | Item::Constructor(f) | Item::L1Handler(f) => {
+                    self.functions.push(f)
+                }
+                Item::Interface { items } => self.extend(items),
+                Item::Struct(s) => self.structs.push(s),
+                Item::Enum(e) => self.enums.push(e),
+                Item::Event(e) => self.events.push(e),
+                Item::Other => {}
+            }
+        }
+    }
+
+    /// Returns function with given name
+    pub fn function(&self, name: &str) -> Option<&Function> {
+        self.functions.iter().find(|f| f.name == name)
+    }
+
+    /// Returns event with given full path
+    pub fn event(&self, name: &str) -> Option<&Event> {
+        self.events.iter().find(|e| e.name == name)
+    }
+
+    /// Encodes calldata of a function
+    ///
+    /// `args` must match the inputs of the function.
+    pub fn encode_inputs(
+        &self,
+        function: &str,
+        args: &[Value],
+    ) -> Result<Vec<FieldElement>, Error> {
+        let function = self.find_function(function)?;
+        if args.len() != function.inputs.len() {
+            return Err(Error(Reason::ArgumentCount {
+                expected: function.inputs.len(),
+                actual: args.len(),
+            }));
+        }
+        let mut output = Vec::new();
+        for (input, arg) in function.inputs.iter().zip(args) {
+            self.encode(&input.ty, arg, &mut output)?;
+        }
+        Ok(output)
+    }
+
+    /// Decodes calldata of a function
+    pub fn decode_inputs(
+        &self,
+        function: &str,
+        calldata: &[FieldElement],
+    ) -> Result<Vec<Value>, Error> {
+        let function = self.find_function(function)?;
+        self.decode_all(function.inputs.iter().map(|i| i.ty.as_str()), calldata)
+    }
+
+    /// Decodes values returned by a function
+    pub fn decode_outputs(
+        &self,
+        function: &str,
+        output: &[FieldElement],
+    ) -> Result<Vec<Value>, Error> {
+        let function = self.find_function(function)?;
+        self.decode_all(function.outputs.iter().map(|o| o.ty.as_str()), output)
+    }
+
+    /// Returns the event enum of the contract, i.e. the enum event which isn't a member of other events
+    ///
+    /// All events emitted by the contract are variants of this enum.
+    pub fn contract_event(&self) -> Option<&Event> {
+        self.events.iter().find(|event| {
+            matches!(event.kind, EventKind::Enum { .. })
+                && !self
+                    .events
+                    .iter()
+                    .flat_map(Event::members)
+                    .any(|member| member.ty == event.name)
+        })
+    }
+
+    /// Decodes event emitted by the contract from its `keys` and `data`
+    ///
+    /// Event is decoded as [contract event](Self::contract_event), see [`decode_event`](Self::decode_event).
+    pub fn decode_contract_event(
+        &self,
+        keys: &[FieldElement],
+        data: &[FieldElement],
+    ) -> Result<Value, Error> {
+        let event = self
+            .contract_event()
+            .ok_or(Error(Reason::NoContractEvent))?;
+        self.decode_event(&event.name, keys, data)
+    }
+
+    /// Decodes event with given full path from emitted `keys` and `data`
+    ///
+    /// Members of a struct event marked as keys are decoded from `keys`, others are decoded from `data`.
+    /// Variant of an enum event is matched by the selector ([`sn_keccak`]) of its name, which precedes keys
+    /// of the payload. Flat variants don't have a selector, they match if their payload can be decoded.
+    ///
+    /// Returns an error if no variant matches, or if `keys` or `data` contain anything after the event.
+    pub fn decode_event(
+        &self,
+        name: &str,
+        mut keys: &[FieldElement],
+        mut data: &[FieldElement],
+    ) -> Result<Value, Error> {
+        let value = self.decode_event_at(name, &mut keys, &mut data, 0)?;
+        if !keys.is_empty() || !data.is_empty() {
+            return Err(Error(Reason::TrailingData(keys.len() + data.len())));
+        }
+        Ok(value)
+    }
+
+    /// Encodes `value` of type `ty` (full path of the type), and appends it to `output`
+    pub fn encode(
+        &self,
+        ty: &str,
+        value: &Value,
+        output: &mut Vec<FieldElement>,
+    ) -> Result<(), Error> {
+        self.encode_at(ty, value, output, 0)
+    }
+
+    /// Decodes a value of type `ty` (full path of the type) from the beginning of `input`, and advances
+    /// `input` past it
+    pub fn decode(&self, ty: &str, input: &mut &[FieldElement]) -> Result<Value, Error> {
+        self.decode_at(ty, input, 0)
+    }
+
+    fn find_function(&self, name: &str) -> Result<&Function, Error> {
+        self.function(name)
+            .ok_or_else(|| Error(Reason::UnknownFunction(name.into())))
+    }
+
+    fn decode_all<'t>(
+        &self,
+        types: impl Iterator<Item = &'t str>,
+        mut input: &[FieldElement],
+    ) -> Result<Vec<Value>, Error> {
+        let values = types
+            .map(|ty| self.decode(ty, &mut input))
+            .collect::<Result<Vec<_>, _>>()?;
+        if !input.is_empty() {
+            return Err(Error(Reason::TrailingData(input.len())));
+        }
+        Ok(values)
+    }
+
+    fn decode_event_at(
+        &self,
+        name: &str,
+        keys: &mut &[FieldElement],
+        data: &mut &[FieldElement],
+        depth: usize,
+    ) -> Result<Value, Error> {
+        if depth > MAX_DEPTH {
+            return Err(Error(Reason::TooDeep));
+        }
+        let event = self
+            .event(name)
+            .ok_or_else(|| Error(Reason::UnknownEvent(name.into())))?;
+
+        match &event.kind {
+            EventKind::Struct { members } => {
+                let mut value = Vec::with_capacity(members.len());
+                for member in members {
+                    let x = match member.kind {
+                        EventMemberKind::Key => self.decode_at(&member.ty, keys, depth + 1)?,
+                        EventMemberKind::Data => self.decode_at(&member.ty, data, depth + 1)?,
+                        EventMemberKind::Nested | EventMemberKind::Flat => {
+                            self.decode_event_at(&member.ty, keys, data, depth + 1)?
+                        }
+                    };
+                    value.push((member.name.clone(), x));
+                }
+                Ok(Value::Struct(value))
+            }
+            EventKind::Enum { variants } => {
+                let selector = keys.first().copied();
+                let nested = variants.iter().find(|variant| {
+                    variant.kind != EventMemberKind::Flat
+                        && Some(sn_keccak(variant.name.as_bytes())) == selector
+                });
+                if let Some(variant) = nested {
+                    *keys = &keys[1..];
+                    let value = self.decode_event_at(&variant.ty, keys, data, depth + 1)?;
+                    return Ok(Value::variant(variant.name.as_str(), value));
+                }
+
+                for variant in variants
+                    .iter()
+                    .filter(|variant| variant.kind == EventMemberKind::Flat)
+                {
+                    let (mut flat_keys, mut flat_data) = (*keys, *data);
+                    let value = self.decode_event_at(
+                        &variant.ty,
+                        &mut flat_keys,
+                        &mut flat_data,
+                        depth + 1,
+                    );
+                    if let Ok(value) = value {
+                        (*keys, *data) = (flat_keys, flat_data);
+                        return Ok(Value::variant(variant.name.as_str(), value));
+                    }
+                }
+                Err(Error(Reason::UnmatchedEvent {
+                    name: name.into(),
+                    selector,
+                }))
+            }
+        }
+    }
+
+    fn encode_at(
+        &self,
+        ty: &str,
+        value: &Value,
+        output: &mut Vec<FieldElement>,
+        depth: usize,
+    ) -> Result<(), Error> {
+        if depth > MAX_DEPTH {
+            return Err(Error(Reason::TooDeep));
+        }
+        let mismatch = || Error(Reason::TypeMismatch(ty.into()));
+        match (Type::parse(ty)?, value) {
+            (Type::Felt(kind), Value::Felt(x)) => {
+                if !kind.contains(*x) {
+                    return Err(Error(Reason::OutOfRange(ty.into())));
+                }
+                output.push(*x);
+            }
+            (Type::Bool, Value::Boolean(x)) => x.serialize(output),
+            (Type::Unsigned(bits), Value::UnsignedInteger(x)) => {
+                if x.bits() > bits {
+                    return Err(Error(Reason::OutOfRange(ty.into())));
+                }
+                if bits == 256 {
+                    x.serialize(output)
+                } else {
+                    output.push(FieldElement::from_uint_mod_order(x))
+                }
+            }
+            (Type::Signed(bits), Value::SignedInteger(x)) => {
+                if !int_fits(*x, bits) {
+                    return Err(Error(Reason::OutOfRange(ty.into())));
+                }
+                x.serialize(output)
+            }
+            (Type::ByteArray, Value::String(s)) => s.serialize(output),
+            (Type::Array(inner), Value::Array(xs)) => {
+                let len =
+                    u32::try_from(xs.len()).map_err(|_| Error(Reason::OutOfRange(ty.into())))?;
+                len.serialize(output);
+                for x in xs {
+                    self.encode_at(inner, x, output, depth + 1)?;
+                }
+            }
+            (Type::FixedArray(inner, len), Value::Array(xs)) => {
+                if xs.len() != len {
+                    return Err(mismatch());
+                }
+                for x in xs {
+                    self.encode_at(inner, x, output, depth + 1)?;
+                }
+            }
+            (Type::Tuple(types), Value::Tuple(xs)) => {
+                if xs.len() != types.len() {
+                    return Err(mismatch());
+                }
+                for (ty, x) in types.iter().zip(xs) {
+                    self.encode_at(ty, x, output, depth + 1)?;
+                }
+            }
+            (Type::NonZero(inner), value) => {
+                let start = output.len();
+                self.encode_at(inner, value, output, depth + 1)?;
+                if output[start..].iter().all(|x| *x == FieldElement::ZERO) {
+                    return Err(Error(Reason::OutOfRange(ty.into())));
+                }
+            }
+            (Type::Named(name), value) => {
+                if let Some(s) = self.structs.iter().find(|s| s.name == name) {
+                    let Value::Struct(members) = value else {
+                        return Err(mismatch());
+                    };
+                    if members.len() != s.members.len() {
+                        return Err(mismatch());
+                    }
+                    for (member, (member_name, x)) in s.members.iter().zip(members) {
+                        if member.name != *member_name {
+                            return Err(mismatch());
+                        }
+                        self.encode_at(&member.ty, x, output, depth + 1)?;
+                    }
+                } else if let Some(e) = self.enums.iter().find(|e| e.name == name) {
+                    let Value::Enum { variant, value } = value else {
+                        return Err(mismatch());
+                    };
+                    let (index, variant) = e
+                        .variants
+                        .iter()
+                        .enumerate()
+                        .find(|(_, v)| v.name == *variant)
+                        .ok_or_else(mismatch)?;
+                    (index as u64).serialize(output);
+                    self.encode_at(&variant.ty, value, output, depth + 1)?;
+                } else {
+                    return Err(Error(Reason::UnknownType(name.into())));
+                }
+            }
+            _ => return Err(mismatch()),
+        }
+        Ok(())
+    }
+
+    fn decode_at(
+        &self,
+        ty: &str,
+        input: &mut &[FieldElement],
+        depth: usize,
+    ) -> Result<Value, Error> {
+        if depth > MAX_DEPTH {
+            return Err(Error(Reason::TooDeep));
+        }
+        let value = match Type::parse(ty)? {
+            Type::Felt(kind) => {
+                let x = FieldElement::deserialize(input)?;
+                if !kind.contains(x) {
+                    return Err(Error(Reason::OutOfRange(ty.into())));
+                }
+                Value::Felt(x)
+            }
+            Type::Bool => Value::Boolean(bool::deserialize(input)?),
+            Type::Unsigned(256) => Value::UnsignedInteger(U256::deserialize(input)?),
+            Type::Unsigned(bits) => {
+                let x = FieldElement::deserialize(input)?.to_uint();
+                if x.bits() > bits {
+                    return Err(Error(Reason::OutOfRange(ty.into())));
+                }
+                Value::UnsignedInteger(x)
+            }
+            Type::Signed(bits) => {
+                let x = i128::deserialize(input)?;
+                if !int_fits(x, bits) {
+                    return Err(Error(Reason::OutOfRange(ty.into())));
+                }
+                Value::SignedInteger(x)
+            }
+            Type::ByteArray => Value::String(String::deserialize(input)?),
+            Type::Array(inner) => {
+                let len = u32::deserialize(input)? as usize;
+                let mut xs = Vec::with_capacity(len.min(input.len()));
+                for _ in 0..len {
+                    xs.push(self.decode_at(inner, input, depth + 1)?);
+                }
+                Value::Array(xs)
+            }
+            Type::FixedArray(inner, len) => Value::Array(
+                (0..len)
+                    .map(|_| self.decode_at(inner, input, depth + 1))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Type::Tuple(types) => Value::Tuple(
+                types
+                    .iter()
+                    .map(|ty| self.decode_at(ty, input, depth + 1))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Type::NonZero(inner) => {
+                let start = *input;
+                let value = self.decode_at(inner, input, depth + 1)?;
+                let consumed = &start[..start.len() - input.len()];
+                if consumed.iter().all(|x| *x == FieldElement::ZERO) {
+                    return Err(Error(Reason::OutOfRange(ty.into())));
+                }
+                value
+            }
+            Type::Named(name) => {
+                if let Some(s) = self.structs.iter().find(|s| s.name == name) {
+                    Value::Struct(
+                        s.members
+                            .iter()
+                            .map(|m| Ok((m.name.clone(), self.decode_at(&m.ty, input, depth + 1)?)))
+                            .collect::<Result<_, Error>>()?,
+                    )
+                } else if let Some(e) = self.enums.iter().find(|e| e.name == name) {
+                    let index = u32::deserialize(input)?;
+                    let variant = e.variants.get(index as usize).ok_or_else(|| {
+                        Error(Reason::UnknownVariant {
+                            ty: name.into(),
+                            index,
+                        })
+                    })?;
+                    Value::Enum {
+                        variant: variant.name.clone(),
+                        value: Box::new(self.decode_at(&variant.ty, input, depth + 1)?),
+                    }
+                } else {
+                    return Err(Error(Reason::UnknownType(name.into())));
+                }
+            }
+        };
+        Ok(value)
+    }
+}
+
+/// Type referenced in ABI
+enum Type<'a> {
+    Felt(FeltKind),
+    Bool,
+    Unsigned(usize),
+    Signed(u32),
+    ByteArray,
+    Array(&'a str),
+    FixedArray(&'a str, usize),
+    Tuple(Vec<&'a str>),
+    NonZero(&'a str),
+    /// Struct or enum declared in ABI
+    Named(&'a str),
+}
+
+/// Type represented by a single field element
+#[derive(Clone, Copy)]
+enum FeltKind {
+    Felt252,
+    ContractAddress,
+    ClassHash,
+    StorageAddress,
+    EthAddress,
+    Bytes31,
+}
+
+impl FeltKind {
+    fn contains(self, x: FieldElement) -> bool {
+        match self {
+            Self::Felt252 | Self::ClassHash => true,
+            Self::ContractAddress => ContractAddress::new(x).is_ok(),
+            Self::StorageAddress => StorageKey::new(x).is_ok(),
+            Self::EthAddress => EthAddress::try_from(x).is_ok(),
+            Self::Bytes31 => x.to_uint().bits() <= 248,
+        }
+    }
+}
+
+impl<'a> Type<'a> {
+    fn parse(ty: &'a str) -> Result<Self, Error> {
+        let ty = ty.trim();
+        let invalid = || Error(Reason::InvalidType(ty.into()));
+        let generic_arg = |prefix: &str| {
+            ty.strip_prefix(prefix)
+                .and_then(|ty| ty.strip_prefix("::<"))
+                .and_then(|ty| ty.strip_suffix('>'))
+        };
+
+        let parsed = match ty {
+            "core::felt252" => Self::Felt(FeltKind::Felt252),
+            "core::starknet::contract_address::ContractAddress" => {
+                Self::Felt(FeltKind::ContractAddress)
+            }
+            "core::starknet::class_hash::ClassHash" => Self::Felt(FeltKind::ClassHash),
+            "core::starknet::storage_access::StorageAddress" => {
+                Self::Felt(FeltKind::StorageAddress)
+            }
+            "core::starknet::eth_address::EthAddress" => Self::Felt(FeltKind::EthAddress),
+            "core::bytes_31::bytes31" => Self::Felt(FeltKind::Bytes31),
+            "core::bool" => Self::Bool,
+            "core::integer::u8" => Self::Unsigned(8),
+            "core::integer::u16" => Self::Unsigned(16),
+            "core::integer::u32" | "core::integer::usize" => Self::Unsigned(32),
+            "core::integer::u64" => Self::Unsigned(64),
+            "core::integer::u128" => Self::Unsigned(128),
+            "core::integer::u256" => Self::Unsigned(256),
+            "core::integer::i8" => Self::Signed(8),
+            "core::integer::i16" => Self::Signed(16),
+            "core::integer::i32" => Self::Signed(32),
+            "core::integer::i64" => Self::Signed(64),
+            "core::integer::i128" => Self::Signed(128),
+            "core::byte_array::ByteArray" => Self::ByteArray,
+            _ => {
+                if let Some(inner) =
+                    generic_arg("core::array::Array").or_else(|| generic_arg("core::array::Span"))
+                {
+                    Self::Array(inner)
+                } else if let Some(inner) = generic_arg("core::zeroable::NonZero") {
+                    Self::NonZero(inner)
+                } else if let Some(inner) = ty.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+                    let types = split_top_level(inner).ok_or_else(invalid)?;
+                    Self::Tuple(types)
+                } else if let Some(inner) = ty.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+                    let (inner, len) = inner.rsplit_once(';').ok_or_else(invalid)?;
+                    let len = len.trim().parse().map_err(|_| invalid())?;
+                    Self::FixedArray(inner.trim(), len)
+                } else if ty.is_empty() {
+                    return Err(invalid());
+                } else {
+                    Self::Named(ty)
+                }
+            }
+        };
+        Ok(parsed)
+    }
+}
+
+/// Checks that `x` fits into signed integer of given size
+fn int_fits(x: i128, bits: u32) -> bool {
+    if bits >= 128 {
+        return true;
+    }
+    let bound = 1i128 << (bits - 1);
+    (-bound..bound).contains(&x)
+}
+
+/// Splits comma-separated list of types, ignoring commas of nested types
+///
+/// Returns `None` if brackets are unbalanced.
+fn split_top_level(list: &str) -> Option<Vec<&str>> {
+    let mut types = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match c {
+            '(' | '[' | '<' => depth += 1,
+            ')' | ']' | '>' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                types.push(list[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return None;
+    }
+    let last = list[start..].trim();
+    if !last.is_empty() {
+        types.push(last);
+    }
+    Some(types)
+}
+
+/// ABI cannot be parsed, or value doesn't match ABI
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error(Reason);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Reason {
+    Json(String),
+    UnknownFunction(String),
+    UnknownEvent(String),
+    NoContractEvent,
+    UnmatchedEvent {
+        name: String,
+        selector: Option<FieldElement>,
+    },
+    UnknownType(String),
+    InvalidType(String),
+    ArgumentCount {
+        expected: usize,
+        actual: usize,
+    },
+    TypeMismatch(String),
+    OutOfRange(String),
+    UnknownVariant {
+        ty: String,
+        index: u32,
+    },
+    TrailingData(usize),
+    TooDeep,
+    Deserialize(super::serde::Error),
+}
+
+impl From<super::serde::Error> for Error {
+    fn from(err: super::serde::Error) -> Self {
+        Self(Reason::Deserialize(err))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Reason::Json(err) => write!(f, "invalid ABI JSON: {err}"),
+            Reason::UnknownFunction(name) => write!(f, "function `{name}` is not in ABI"),
+            Reason::UnknownEvent(name) => write!(f, "event `{name}` is not in ABI"),
+            Reason::NoContractEvent => f.write_str("ABI doesn't contain contract event enum"),
+            Reason::UnmatchedEvent {
+                name,
+                selector: Some(selector),
+            } => write!(
+                f,
+                "no variant of event `{name}` matches selector {selector:#x}"
+            ),
+            Reason::UnmatchedEvent {
+                name,
+                selector: None,
+            } => write!(f, "no variant of event `{name}` matches empty keys"),
+            Reason::UnknownType(name) => write!(f, "type `{name}` is not in ABI"),
+            Reason::InvalidType(name) => write!(f, "type `{name}` is malformed"),
+            Reason::ArgumentCount { expected, actual } => {
+                write!(f, "expected {expected} arguments, got {actual}")
+            }
+            Reason::TypeMismatch(ty) => write!(f, "value doesn't match type `{ty}`"),
+            Reason::OutOfRange(ty) => write!(f, "value is out of range of `{ty}`"),
+            Reason::UnknownVariant { ty, index } => {
+                write!(f, "enum `{ty}` doesn't have variant {index}")
+            }
+            Reason::TrailingData(len) => {
+                write!(f, "{len} field elements left after the end of values")
+            }
+            Reason::TooDeep => write!(f, "types are nested deeper than {MAX_DEPTH} levels"),
+            Reason::Deserialize(err) => err.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Encoding of Cairo values as field elements
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::FieldElement;
+
+#[cfg(feature = "abi")]
+pub mod abi;
+#[cfg(feature = "alloc")]
+pub mod serde;
+
+/// Max length of Cairo short string in bytes
+pub const SHORT_STRING_MAX_LEN: usize = 31;
+
+/// Encodes a Cairo short string, e.g. `'SN_MAIN'`, as a field element
+///
+/// Short string is an ASCII string of up to 31 characters, interpreted as a big-endian integer.
+/// Returns `None` if the string is too long or contains non-ASCII characters.
+pub fn short_string(s: &str) -> Option<FieldElement> {
+    if s.len() > SHORT_STRING_MAX_LEN || !s.is_ascii() {
+        return None;
+    }
+    Some(FieldElement::from_be_bytes_mod_order(s.as_bytes()))
+}
+
+/// Serializes bytes as Cairo `ByteArray`
+///
+/// Bytes are split into 31-byte words. Serialized `ByteArray` is the number of full words, followed by
+/// the full words, the remaining (pending) word, and the length of the pending word in bytes.
+#[cfg(feature = "alloc")]
+pub fn byte_array(bytes: &[u8]) -> Vec<FieldElement> {
+    let words = bytes.chunks_exact(SHORT_STRING_MAX_LEN);
+    let pending_word = words.remainder();
+
+    let mut serialized = Vec::with_capacity(bytes.len() / SHORT_STRING_MAX_LEN + 3);
+    serialized.push(FieldElement::from(words.len() as u64));
+    serialized.extend(words.map(FieldElement::from_be_bytes_mod_order));
+    serialized.push(FieldElement::from_be_bytes_mod_order(pending_word));
+    serialized.push(FieldElement::from(pending_word.len() as u64));
+    serialized
+}
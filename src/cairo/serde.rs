@@ -0,0 +1,422 @@
+//! Cairo Serde: serialization of values as field elements
+//!
+//! Calldata, return values, and event payloads of Cairo 1 contracts are sequences of field elements
+//! produced by the `Serde` trait of Cairo corelib. [`CairoSerialize`] and [`CairoDeserialize`] follow the
+//! same layout:
+//!
+//! * `felt252`, integers, and `bool` take a single field element. Signed integers are mapped to the
+//!   field, e.g. $-1$ is encoded as $p - 1$
+//! * `u256` ([`U256`]) is a pair of `u128` limbs, least significant first
+//! * `Array<T>` and `Span<T>` ([`Vec<T>`], slices) are prefixed with their length, fixed-size arrays
+//!   `[T; N]` aren't
+//! * `ByteArray` ([`String`]) is encoded as in [`byte_array`](super::byte_array)
+//! * Structs and tuples are concatenations of their members
+//! * Enums are the index of the variant followed by its payload, e.g. `Option::Some(x)` is `[0, x]` and
+//!   `Option::None` is `[1]`
+//!
+//! Structs and enums implement the traits with derive macros available with `derive` feature. Variants
+//! of enums are indexed in declaration order, like in Cairo.
+//!
+//! ```rust
+//! use stark_curve::cairo::serde::{from_felts, to_felts};
+//! use stark_curve::FieldElement;
+//!
+//! # fn main() -> Result<(), stark_curve::cairo::serde::Error> {
+//! let calldata = to_felts(&(FieldElement::from(0x1234), vec![1u64, 2, 3], Some(true)));
+//! assert_eq!(calldata.len(), 7);
+//!
+//! let (recipient, amounts, flag): (FieldElement, Vec<u64>, Option<bool>) = from_felts(&calldata)?;
+//! assert_eq!(amounts, [1, 2, 3]);
+//! # Ok(()) }
+//! ```
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "derive")]
+pub use stark_curve_derive::{CairoDeserialize, CairoSerialize};
+
+use crate::bigint::{Encoding, U256};
+use crate::messaging::EthAddress;
+use crate::types::{ClassHash, ContractAddress, StorageKey};
+use crate::FieldElement;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+}
+
+/// Type which can be serialized into field elements following Cairo Serde layout
+pub trait CairoSerialize {
+    /// Appends serialized value to `output`
+    fn serialize(&self, output: &mut Vec<FieldElement>);
+}
+
+/// Type which can be deserialized from field elements following Cairo Serde layout
+pub trait CairoDeserialize: Sized {
+    /// Deserializes a value from the beginning of `input`, and advances `input` past it
+    fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error>;
+}
+
+/// Serializes `value` into a vector of field elements
+pub fn to_felts<T: CairoSerialize + ?Sized>(value: &T) -> Vec<FieldElement> {
+    let mut output = Vec::new();
+    value.serialize(&mut output);
+    output
+}
+
+/// Deserializes a value from `input`
+///
+/// Returns an error if `input` contains anything after the value.
+pub fn from_felts<T: CairoDeserialize>(mut input: &[FieldElement]) -> Result<T, Error> {
+    let value = T::deserialize(&mut input)?;
+    if !input.is_empty() {
+        return Err(Error(Reason::TrailingData(input.len())));
+    }
+    Ok(value)
+}
+
+impl CairoSerialize for FieldElement {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        output.push(*self);
+    }
+}
+
+impl CairoDeserialize for FieldElement {
+    fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+        let (first, rest) = input.split_first().ok_or(Error(Reason::UnexpectedEnd))?;
+        *input = rest;
+        Ok(*first)
+    }
+}
+
+impl CairoSerialize for bool {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        output.push(FieldElement::from(u64::from(*self)));
+    }
+}
+
+impl CairoDeserialize for bool {
+    fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+        let x = FieldElement::deserialize(input)?;
+        if x == FieldElement::ZERO {
+            Ok(false)
+        } else if x == FieldElement::ONE {
+            Ok(true)
+        } else {
+            Err(Error(Reason::OutOfRange("bool")))
+        }
+    }
+}
+
+/// Implements traits for unsigned integers, which are encoded as is
+macro_rules! impl_unsigned {
+    ($($ty:ident),+) => {$(
+        impl CairoSerialize for $ty {
+            fn serialize(&self, output: &mut Vec<FieldElement>) {
+                output.push(FieldElement::from_be_bytes_mod_order(&self.to_be_bytes()));
+            }
+        }
+
+        impl CairoDeserialize for $ty {
+            fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+                let bytes: [u8; 32] = FieldElement::deserialize(input)?.to_be_bytes().into();
+                let (high, low) = bytes.split_at(32 - core::mem::size_of::<$ty>());
+                if high.iter().any(|&b| b != 0) {
+                    return Err(Error(Reason::OutOfRange(stringify!($ty))));
+                }
+                let mut x = [0u8; core::mem::size_of::<$ty>()];
+                x.copy_from_slice(low);
+                Ok($ty::from_be_bytes(x))
+            }
+        }
+    )+};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128);
+
+/// Implements traits for signed integers, negative values are encoded as $p - |x|$
+macro_rules! impl_signed {
+    ($($ty:ident),+) => {$(
+        impl CairoSerialize for $ty {
+            fn serialize(&self, output: &mut Vec<FieldElement>) {
+                let x = FieldElement::from_be_bytes_mod_order(&self.unsigned_abs().to_be_bytes());
+                output.push(if *self < 0 { -x } else { x });
+            }
+        }
+
+        impl CairoDeserialize for $ty {
+            fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+                let x = FieldElement::deserialize(input)?;
+                let out_of_range = || Error(Reason::OutOfRange(stringify!($ty)));
+                if let Ok(x) = u128::deserialize(&mut &[x][..]) {
+                    $ty::try_from(x).map_err(|_| out_of_range())
+                } else {
+                    let abs = u128::deserialize(&mut &[-x][..]).map_err(|_| out_of_range())?;
+                    0i128
+                        .checked_sub_unsigned(abs)
+                        .and_then(|x| $ty::try_from(x).ok())
+                        .ok_or_else(out_of_range)
+                }
+            }
+        }
+    )+};
+}
+
+impl_signed!(i8, i16, i32, i64, i128);
+
+impl CairoSerialize for U256 {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        let bytes = self.to_be_bytes();
+        output.push(FieldElement::from_be_bytes_mod_order(&bytes[16..]));
+        output.push(FieldElement::from_be_bytes_mod_order(&bytes[..16]));
+    }
+}
+
+impl CairoDeserialize for U256 {
+    fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+        let low = u128::deserialize(input)?;
+        let high = u128::deserialize(input)?;
+        Ok(U256::from_u128(high).shl_vartime(128) | U256::from_u128(low))
+    }
+}
+
+impl CairoSerialize for String {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        self.as_str().serialize(output)
+    }
+}
+
+impl CairoSerialize for str {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        output.extend(super::byte_array(self.as_bytes()))
+    }
+}
+
+impl CairoDeserialize for String {
+    fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+        let full_words = Vec::<FieldElement>::deserialize(input)?;
+        let pending_word = FieldElement::deserialize(input)?;
+        let pending_len = usize::from(u8::deserialize(input)?);
+        if pending_len >= super::SHORT_STRING_MAX_LEN {
+            return Err(Error(Reason::OutOfRange("ByteArray")));
+        }
+
+        let mut bytes =
+            Vec::with_capacity(full_words.len() * super::SHORT_STRING_MAX_LEN + pending_len);
+        let words = full_words
+            .iter()
+            .map(|w| (w, super::SHORT_STRING_MAX_LEN))
+            .chain([(&pending_word, pending_len)]);
+        for (word, len) in words {
+            let word: [u8; 32] = word.to_be_bytes().into();
+            let (high, low) = word.split_at(32 - len);
+            if high.iter().any(|&b| b != 0) {
+                return Err(Error(Reason::OutOfRange("ByteArray")));
+            }
+            bytes.extend_from_slice(low);
+        }
+        String::from_utf8(bytes).map_err(|_| Error(Reason::InvalidUtf8))
+    }
+}
+
+impl<T: CairoSerialize> CairoSerialize for [T] {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        (self.len() as u64).serialize(output);
+        for x in self {
+            x.serialize(output);
+        }
+    }
+}
+
+impl<T: CairoSerialize> CairoSerialize for Vec<T> {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        self.as_slice().serialize(output)
+    }
+}
+
+impl<T: CairoDeserialize> CairoDeserialize for Vec<T> {
+    fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+        let len = u32::deserialize(input)? as usize;
+        // Every element takes at least one field element, unless it's zero-sized
+        let mut output = Vec::with_capacity(len.min(input.len()));
+        for _ in 0..len {
+            output.push(T::deserialize(input)?);
+        }
+        Ok(output)
+    }
+}
+
+impl<T: CairoSerialize, const N: usize> CairoSerialize for [T; N] {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        for x in self {
+            x.serialize(output);
+        }
+    }
+}
+
+impl<T: CairoDeserialize, const N: usize> CairoDeserialize for [T; N] {
+    fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+        let mut output = Vec::with_capacity(N);
+        for _ in 0..N {
+            output.push(T::deserialize(input)?);
+        }
+        match output.try_into() {
+            Ok(array) => Ok(array),
+            Err(_) => unreachable!("exactly N elements were deserialized"),
+        }
+    }
+}
+
+impl<T: CairoSerialize> CairoSerialize for Option<T> {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        match self {
+            Some(x) => {
+                0u32.serialize(output);
+                x.serialize(output);
+            }
+            None => 1u32.serialize(output),
+        }
+    }
+}
+
+impl<T: CairoDeserialize> CairoDeserialize for Option<T> {
+    fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+        match u32::deserialize(input)? {
+            0 => Ok(Some(T::deserialize(input)?)),
+            1 => Ok(None),
+            index => Err(Error::unknown_variant("Option", index)),
+        }
+    }
+}
+
+impl<T: CairoSerialize, E: CairoSerialize> CairoSerialize for Result<T, E> {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        match self {
+            Ok(x) => {
+                0u32.serialize(output);
+                x.serialize(output);
+            }
+            Err(e) => {
+                1u32.serialize(output);
+                e.serialize(output);
+            }
+        }
+    }
+}
+
+impl<T: CairoDeserialize, E: CairoDeserialize> CairoDeserialize for Result<T, E> {
+    fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+        match u32::deserialize(input)? {
+            0 => Ok(Ok(T::deserialize(input)?)),
+            1 => Ok(Err(E::deserialize(input)?)),
+            index => Err(Error::unknown_variant("Result", index)),
+        }
+    }
+}
+
+impl<T: CairoSerialize + ?Sized> CairoSerialize for &T {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        (**self).serialize(output)
+    }
+}
+
+impl<T: CairoSerialize + ?Sized> CairoSerialize for Box<T> {
+    fn serialize(&self, output: &mut Vec<FieldElement>) {
+        (**self).serialize(output)
+    }
+}
+
+impl<T: CairoDeserialize> CairoDeserialize for Box<T> {
+    fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+        T::deserialize(input).map(Box::new)
+    }
+}
+
+/// Implements traits for tuples
+macro_rules! impl_tuple {
+    ($($ty:ident),*) => {
+        impl<$($ty: CairoSerialize),*> CairoSerialize for ($($ty,)*) {
+            #[allow(non_snake_case, unused_variables)]
+            fn serialize(&self, output: &mut Vec<FieldElement>) {
+                let ($($ty,)*) = self;
+                $($ty.serialize(output);)*
+            }
+        }
+
+        impl<$($ty: CairoDeserialize),*> CairoDeserialize for ($($ty,)*) {
+            #[allow(unused_variables)]
+            fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+                Ok(($($ty::deserialize(input)?,)*))
+            }
+        }
+    };
+}
+
+impl_tuple!();
+impl_tuple!(A);
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+impl_tuple!(A, B, C, D, E);
+impl_tuple!(A, B, C, D, E, F);
+impl_tuple!(A, B, C, D, E, F, G);
+impl_tuple!(A, B, C, D, E, F, G, H);
+
+/// Implements traits for types wrapping a single field element
+macro_rules! impl_felt_wrapper {
+    ($($ty:ident),+) => {$(
+        impl CairoSerialize for $ty {
+            fn serialize(&self, output: &mut Vec<FieldElement>) {
+                output.push(FieldElement::from(*self));
+            }
+        }
+
+        impl CairoDeserialize for $ty {
+            fn deserialize(input: &mut &[FieldElement]) -> Result<Self, Error> {
+                $ty::try_from(FieldElement::deserialize(input)?)
+                    .map_err(|_| Error(Reason::OutOfRange(stringify!($ty))))
+            }
+        }
+    )+};
+}
+
+impl_felt_wrapper!(ContractAddress, ClassHash, StorageKey, EthAddress);
+
+/// Value cannot be deserialized
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error(Reason);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Reason {
+    UnexpectedEnd,
+    TrailingData(usize),
+    OutOfRange(&'static str),
+    UnknownVariant { ty: &'static str, index: u32 },
+    InvalidUtf8,
+}
+
+impl Error {
+    /// Variant `index` of enum `ty` doesn't exist
+    ///
+    /// Used by [`CairoDeserialize`] impls of enums.
+    pub fn unknown_variant(ty: &'static str, index: u32) -> Self {
+        Self(Reason::UnknownVariant { ty, index })
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Reason::UnexpectedEnd => f.write_str("unexpected end of input"),
+            Reason::TrailingData(len) => {
+                write!(f, "{len} field elements left after the end of value")
+            }
+            Reason::OutOfRange(ty) => write!(f, "value is out of range of `{ty}`"),
+            Reason::UnknownVariant { ty, index } => {
+                write!(f, "enum `{ty}` doesn't have variant {index}")
+            }
+            Reason::InvalidUtf8 => f.write_str("byte array is not valid UTF-8"),
+        }
+    }
+}
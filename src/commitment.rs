@@ -0,0 +1,250 @@
+//! Pedersen commitments
+//!
+//! Pedersen commitment to value $v$ with blinding factor $r$ is $C = v \cdot G + r \cdot H$, where $G$ and $H$
+//! are generators with unknown discrete logarithm relative to each other. Vector commitment to $v_1, \dots, v_k$
+//! is $C = \sum_i v_i \cdot G_i + r \cdot H$. Commitments are perfectly hiding, computationally binding, and
+//! additively homomorphic: commitments can be added, subtracted, and multiplied by a public scalar.
+//!
+//! Generators can be derived using [`generators`](crate::generators) module, for instance:
+//!
+//! ```rust
+//! # #[cfg(feature = "hash2curve")] {
+//! use stark_curve::{commitment::CommitmentKey, generators, ProjectivePoint, Scalar};
+//! use stark_curve::elliptic_curve::hash2curve::ExpandMsgXmd;
+//!
+//! let h = generators::from_label::<ExpandMsgXmd<sha2::Sha256>>(
+//!     &[b"H"],
+//!     &[b"MY-APP-PEDERSEN-GENERATORS"],
+//! )?;
+//! let key = CommitmentKey::new(ProjectivePoint::GENERATOR, h.into());
+//!
+//! let mut rng = rand::rngs::OsRng;
+//! let (c1, r1) = key.commit_random(&Scalar::from(10), &mut rng);
+//! let (c2, r2) = key.commit_random(&Scalar::from(32), &mut rng);
+//! assert!(key.verify(&(c1 + c2), &Scalar::from(42), &(r1 + r2)));
+//! # }
+//! # Ok::<_, stark_curve::elliptic_curve::Error>(())
+//! ```
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use subtle::ConstantTimeEq;
+
+use crate::elliptic_curve::{rand_core::RngCore, Field};
+use crate::{ProjectivePoint, Scalar};
+
+/// Pedersen commitment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Commitment(ProjectivePoint);
+
+impl Commitment {
+    /// Wraps a point as commitment
+    pub fn from_point(point: ProjectivePoint) -> Self {
+        Self(point)
+    }
+
+    /// Returns the point $C$
+    pub fn as_point(&self) -> &ProjectivePoint {
+        &self.0
+    }
+}
+
+impl From<Commitment> for ProjectivePoint {
+    fn from(c: Commitment) -> Self {
+        c.0
+    }
+}
+
+impl ConstantTimeEq for Commitment {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl Add for Commitment {
+    type Output = Commitment;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Commitment {
+    type Output = Commitment;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Neg for Commitment {
+    type Output = Commitment;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl AddAssign for Commitment {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0
+    }
+}
+
+impl SubAssign for Commitment {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0
+    }
+}
+
+impl Mul<Scalar> for Commitment {
+    type Output = Commitment;
+
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl<'r> Mul<&'r Scalar> for Commitment {
+    type Output = Commitment;
+
+    fn mul(self, rhs: &'r Scalar) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+/// Commitment key $(G, H)$ for committing to a single value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommitmentKey {
+    g: ProjectivePoint,
+    h: ProjectivePoint,
+}
+
+impl CommitmentKey {
+    /// Constructs a commitment key
+    ///
+    /// Discrete logarithm of `h` relative to `g` must be unknown, otherwise commitment is not binding.
+    pub fn new(g: ProjectivePoint, h: ProjectivePoint) -> Self {
+        Self { g, h }
+    }
+
+    /// Returns generator $G$
+    pub fn g(&self) -> &ProjectivePoint {
+        &self.g
+    }
+
+    /// Returns generator $H$
+    pub fn h(&self) -> &ProjectivePoint {
+        &self.h
+    }
+
+    /// Commits to `value` with `blinding` factor
+    pub fn commit(&self, value: &Scalar, blinding: &Scalar) -> Commitment {
+        Commitment(self.g * value + self.h * blinding)
+    }
+
+    /// Commits to `value` with random blinding factor
+    ///
+    /// Returns commitment and blinding factor
+    pub fn commit_random(&self, value: &Scalar, rng: &mut impl RngCore) -> (Commitment, Scalar) {
+        let blinding = Scalar::random(rng);
+        (self.commit(value, &blinding), blinding)
+    }
+
+    /// Checks that `commitment` opens to `value` with `blinding` factor
+    pub fn verify(&self, commitment: &Commitment, value: &Scalar, blinding: &Scalar) -> bool {
+        self.commit(value, blinding).ct_eq(commitment).into()
+    }
+}
+
+/// Commitment key $(G_1, \dots, G_k, H)$ for committing to a vector of up to $k$ values
+///
+/// Generators can be stored in any container, e.g. an array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VectorCommitmentKey<G> {
+    gs: G,
+    h: ProjectivePoint,
+}
+
+impl<G: AsRef<[ProjectivePoint]>> VectorCommitmentKey<G> {
+    /// Constructs a vector commitment key
+    ///
+    /// Discrete logarithms between all the generators must be unknown, otherwise commitment is not
+    /// binding.
+    pub fn new(gs: G, h: ProjectivePoint) -> Self {
+        Self { gs, h }
+    }
+
+    /// Returns generators $G_1, \dots, G_k$
+    pub fn gs(&self) -> &[ProjectivePoint] {
+        self.gs.as_ref()
+    }
+
+    /// Returns generator $H$
+    pub fn h(&self) -> &ProjectivePoint {
+        &self.h
+    }
+
+    /// Commits to `values` with `blinding` factor
+    ///
+    /// Returns error if there are more values than generators $G_i$. If there are fewer values,
+    /// the remaining ones are treated as zeroes.
+    pub fn commit(
+        &self,
+        values: &[Scalar],
+        blinding: &Scalar,
+    ) -> Result<Commitment, LengthMismatch> {
+        let gs = self.gs();
+        if values.len() > gs.len() {
+            return Err(LengthMismatch {
+                values: values.len(),
+                generators: gs.len(),
+            });
+        }
+        let c = values
+            .iter()
+            .zip(gs)
+            .fold(self.h * blinding, |c, (v, g)| c + g * v);
+        Ok(Commitment(c))
+    }
+
+    /// Commits to `values` with random blinding factor
+    ///
+    /// Returns commitment and blinding factor
+    pub fn commit_random(
+        &self,
+        values: &[Scalar],
+        rng: &mut impl RngCore,
+    ) -> Result<(Commitment, Scalar), LengthMismatch> {
+        let blinding = Scalar::random(rng);
+        Ok((self.commit(values, &blinding)?, blinding))
+    }
+
+    /// Checks that `commitment` opens to `values` with `blinding` factor
+    ///
+    /// Returns `false` if there are more values than generators $G_i$
+    pub fn verify(&self, commitment: &Commitment, values: &[Scalar], blinding: &Scalar) -> bool {
+        match self.commit(values, blinding) {
+            Ok(expected) => expected.ct_eq(commitment).into(),
+            Err(_) => false,
+        }
+    }
+}
+
+/// There are more values to commit than generators in the commitment key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthMismatch {
+    values: usize,
+    generators: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot commit to {} values using {} generators",
+            self.values, self.generators
+        )
+    }
+}
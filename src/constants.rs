@@ -32,6 +32,66 @@ mod internal_bytes_repr {
         18147424675297964973,
         405578048423154473,
     ];
+    pub const SHIFT_POINT_X: [u64; 4] = [
+        1933903796324928314,
+        7739989395386261137,
+        1641324389046377921,
+        316327189671755572,
+    ];
+    pub const SHIFT_POINT_Y: [u64; 4] = [
+        14252083571674603243,
+        12587053260418384210,
+        4798858472748676776,
+        81375596133053150,
+    ];
+    pub const PEDERSEN_P0_X: [u64; 4] = [
+        3602345268353203007,
+        13758484295849329960,
+        518715844721862878,
+        241691544791834578,
+    ];
+    pub const PEDERSEN_P0_Y: [u64; 4] = [
+        13441546676070136227,
+        13001553326386915570,
+        433857700841878496,
+        368891789801938570,
+    ];
+    pub const PEDERSEN_P1_X: [u64; 4] = [
+        16491878934996302286,
+        12382025591154462459,
+        10043949394709899044,
+        253000153565733272,
+    ];
+    pub const PEDERSEN_P1_Y: [u64; 4] = [
+        13950428914333633429,
+        2545498000137298346,
+        5191292837124484988,
+        285630633187035523,
+    ];
+    pub const PEDERSEN_P2_X: [u64; 4] = [
+        1203723169299412240,
+        18195981508842736832,
+        12916675983929588442,
+        338510149841406402,
+    ];
+    pub const PEDERSEN_P2_Y: [u64; 4] = [
+        12352616181161700245,
+        11743524503750604092,
+        11088962269971685343,
+        161068411212710156,
+    ];
+    pub const PEDERSEN_P3_X: [u64; 4] = [
+        1145636535101238356,
+        10664803185694787051,
+        299781701614706065,
+        425493972656615276,
+    ];
+    pub const PEDERSEN_P3_Y: [u64; 4] = [
+        8187986478389849302,
+        4428713245976508844,
+        6033691581221864148,
+        345457391846365716,
+    ];
 }
 
 /// Coefficient $\alpha$ of curve equation
@@ -61,6 +121,81 @@ pub const GENERATOR: (FieldElement, FieldElement) = (
     )),
 );
 
+/// Shift point $P_s$ of Pedersen hash
+///
+/// Derived from the digits of $\pi$, see [`generators::from_pi_digits`](crate::generators::from_pi_digits).
+///
+/// * $x = 2089986280348253421170679821480865132823066470938446095505822317253594081284$
+/// * $y = 1713931329540660377023406109199410414810705867260802078187082345529207694986$
+pub const SHIFT_POINT: (FieldElement, FieldElement) = (
+    W::new(FieldElementCore::from_internal_repr(
+        internal_bytes_repr::SHIFT_POINT_X,
+    )),
+    W::new(FieldElementCore::from_internal_repr(
+        internal_bytes_repr::SHIFT_POINT_Y,
+    )),
+);
+
+/// Constant point $P_0$ of Pedersen hash
+///
+/// Derived from the digits of $\pi$, see [`generators::from_pi_digits`](crate::generators::from_pi_digits).
+///
+/// * $x = 996781205833008774514500082376783249102396023663454813447423147977397232763$
+/// * $y = 1668503676786377725805489344771023921079126552019160156920634619255970485781$
+pub const PEDERSEN_P0: (FieldElement, FieldElement) = (
+    W::new(FieldElementCore::from_internal_repr(
+        internal_bytes_repr::PEDERSEN_P0_X,
+    )),
+    W::new(FieldElementCore::from_internal_repr(
+        internal_bytes_repr::PEDERSEN_P0_Y,
+    )),
+);
+
+/// Constant point $P_1$ of Pedersen hash
+///
+/// Derived from the digits of $\pi$, see [`generators::from_pi_digits`](crate::generators::from_pi_digits).
+///
+/// * $x = 2251563274489750535117886426533222435294046428347329203627021249169616184184$
+/// * $y = 1798716007562728905295480679789526322175868328062420237419143593021674992973$
+pub const PEDERSEN_P1: (FieldElement, FieldElement) = (
+    W::new(FieldElementCore::from_internal_repr(
+        internal_bytes_repr::PEDERSEN_P1_X,
+    )),
+    W::new(FieldElementCore::from_internal_repr(
+        internal_bytes_repr::PEDERSEN_P1_Y,
+    )),
+);
+
+/// Constant point $P_2$ of Pedersen hash
+///
+/// Derived from the digits of $\pi$, see [`generators::from_pi_digits`](crate::generators::from_pi_digits).
+///
+/// * $x = 2138414695194151160943305727036575959195309218611738193261179310511854807447$
+/// * $y = 113410276730064486255102093846540133784865286929052426931474106396135072156$
+pub const PEDERSEN_P2: (FieldElement, FieldElement) = (
+    W::new(FieldElementCore::from_internal_repr(
+        internal_bytes_repr::PEDERSEN_P2_X,
+    )),
+    W::new(FieldElementCore::from_internal_repr(
+        internal_bytes_repr::PEDERSEN_P2_Y,
+    )),
+);
+
+/// Constant point $P_3$ of Pedersen hash
+///
+/// Derived from the digits of $\pi$, see [`generators::from_pi_digits`](crate::generators::from_pi_digits).
+///
+/// * $x = 2379962749567351885752724891227938183011949129833673362440656643086021394946$
+/// * $y = 776496453633298175483985398648758586525933812536653089401905292063708816422$
+pub const PEDERSEN_P3: (FieldElement, FieldElement) = (
+    W::new(FieldElementCore::from_internal_repr(
+        internal_bytes_repr::PEDERSEN_P3_X,
+    )),
+    W::new(FieldElementCore::from_internal_repr(
+        internal_bytes_repr::PEDERSEN_P3_Y,
+    )),
+);
+
 #[cfg(test)]
 mod tests {
     use hex_literal::hex;
@@ -0,0 +1,240 @@
+//! Class hashes of Cairo 1 contracts
+//!
+//! A Cairo 1 contract is declared as a Sierra class along with the hash of the class compiled to CASM.
+//! Both hashes are [Poseidon](poseidon) hashes over the class structure:
+//!
+//! * [`SierraClass::class_hash`] commits to entry points, the ABI, and the Sierra program. It's the hash
+//!   contracts are deployed with.
+//! * [`CompiledClass::class_hash`] (compiled class hash) commits to entry points and bytecode, it's what
+//!   the sequencer actually executes.
+//!
+//! This module takes already parsed classes, parsing compiler artifacts is left to the caller.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::hash::{poseidon, sn_keccak};
+use crate::{cairo, FieldElement};
+
+/// Prefix of Sierra class hash, short string `'CONTRACT_CLASS_V0.1.0'`
+const SIERRA_PREFIX: &[u8] = b"CONTRACT_CLASS_V0.1.0";
+/// Prefix of compiled class hash, short string `'COMPILED_CLASS_V1'`
+const COMPILED_PREFIX: &[u8] = b"COMPILED_CLASS_V1";
+
+/// Entry points of a class, grouped by type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryPointsByType<E> {
+    /// Functions callable from other contracts and transactions
+    pub external: Vec<E>,
+    /// Handlers of L1 → L2 messages
+    pub l1_handler: Vec<E>,
+    /// Constructor, at most one
+    pub constructor: Vec<E>,
+}
+
+impl<E> Default for EntryPointsByType<E> {
+    fn default() -> Self {
+        Self {
+            external: Vec::new(),
+            l1_handler: Vec::new(),
+            constructor: Vec::new(),
+        }
+    }
+}
+
+/// Entry point of Sierra class
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SierraEntryPoint {
+    /// Selector of the entry point, [`sn_keccak`] of its name
+    pub selector: FieldElement,
+    /// Index of the function in the Sierra program
+    pub function_idx: u64,
+}
+
+/// Sierra class, as submitted in `DECLARE` transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SierraClass {
+    /// Sierra program
+    pub sierra_program: Vec<FieldElement>,
+    /// Entry points of the class
+    pub entry_points_by_type: EntryPointsByType<SierraEntryPoint>,
+    /// ABI serialized as JSON string, hashed as is
+    pub abi: String,
+}
+
+impl SierraClass {
+    /// Computes the class hash
+    ///
+    /// $$
+    /// H(\texttt{"CONTRACT\\_CLASS\\_V0.1.0"}, H_e(\text{external}), H_e(\text{l1\\_handler}),
+    ///     H_e(\text{constructor}), \text{sn\\_keccak}(\text{abi}), H(\text{sierra\\_program}))
+    /// $$
+    ///
+    /// where $H$ is Poseidon hash, and $H_e$ hashes the selector and function index of each entry point.
+    pub fn class_hash(&self) -> FieldElement {
+        let entry_points = &self.entry_points_by_type;
+        let hash_entry_points = |entry_points: &[SierraEntryPoint]| {
+            let mut hasher = poseidon::Hasher::new();
+            for entry_point in entry_points {
+                hasher.update(entry_point.selector);
+                hasher.update(entry_point.function_idx.into());
+            }
+            hasher.finalize()
+        };
+
+        poseidon::Hasher::new()
+            .chain(FieldElement::from_be_bytes_mod_order(SIERRA_PREFIX))
+            .chain(hash_entry_points(&entry_points.external))
+            .chain(hash_entry_points(&entry_points.l1_handler))
+            .chain(hash_entry_points(&entry_points.constructor))
+            .chain(sn_keccak(self.abi.as_bytes()))
+            .chain(poseidon::hash_many(&self.sierra_program))
+            .finalize()
+    }
+}
+
+/// Entry point of compiled (CASM) class
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledEntryPoint {
+    /// Selector of the entry point, [`sn_keccak`] of its name
+    pub selector: FieldElement,
+    /// Offset of the entry point in the bytecode
+    pub offset: u64,
+    /// Names of builtins used by the entry point, e.g. `"range_check"`
+    pub builtins: Vec<String>,
+}
+
+/// Lengths of bytecode segments
+///
+/// Bytecode is split into a tree of segments, so that the prover only needs to load the segments
+/// which are actually executed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BytecodeSegmentLengths {
+    /// Segment of the given length
+    Leaf(u64),
+    /// Segment made of consecutive inner segments
+    Node(Vec<BytecodeSegmentLengths>),
+}
+
+/// Class compiled to Cairo assembly (CASM)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledClass {
+    /// Bytecode of the class
+    pub bytecode: Vec<FieldElement>,
+    /// Segmentation of the bytecode, `None` for classes compiled before Sierra 1.5.0
+    pub bytecode_segment_lengths: Option<BytecodeSegmentLengths>,
+    /// Entry points of the class
+    pub entry_points_by_type: EntryPointsByType<CompiledEntryPoint>,
+}
+
+impl CompiledClass {
+    /// Computes the compiled class hash
+    ///
+    /// $$
+    /// H(\texttt{"COMPILED\\_CLASS\\_V1"}, H_e(\text{external}), H_e(\text{l1\\_handler}),
+    ///     H_e(\text{constructor}), H_b(\text{bytecode}))
+    /// $$
+    ///
+    /// where $H$ is Poseidon hash, and $H_e$ hashes the selector, offset, and hash of builtin names of each
+    /// entry point. If bytecode isn't segmented, $H_b$ is Poseidon hash of the bytecode. Otherwise, the hash
+    /// of a leaf segment is Poseidon hash of its bytecode, and the hash of a node is
+    /// $1 + H(\text{len}_1, h_1, \dots, \text{len}_k, h_k)$ over its inner segments.
+    ///
+    /// Returns an error if a builtin name is not a valid short string, or if segment lengths don't add up to
+    /// the bytecode length.
+    pub fn class_hash(&self) -> Result<FieldElement, Error> {
+        let entry_points = &self.entry_points_by_type;
+        let bytecode_hash = match &self.bytecode_segment_lengths {
+            Some(lengths) => {
+                let (hash, len) = hash_segment(&self.bytecode, lengths, 0)?;
+                if len != self.bytecode.len() as u64 {
+                    return Err(Error(Reason::SegmentLengthMismatch {
+                        segments: len,
+                        bytecode: self.bytecode.len(),
+                    }));
+                }
+                hash
+            }
+            None => poseidon::hash_many(&self.bytecode),
+        };
+
+        Ok(poseidon::Hasher::new()
+            .chain(FieldElement::from_be_bytes_mod_order(COMPILED_PREFIX))
+            .chain(hash_compiled_entry_points(&entry_points.external)?)
+            .chain(hash_compiled_entry_points(&entry_points.l1_handler)?)
+            .chain(hash_compiled_entry_points(&entry_points.constructor)?)
+            .chain(bytecode_hash)
+            .finalize())
+    }
+}
+
+fn hash_compiled_entry_points(entry_points: &[CompiledEntryPoint]) -> Result<FieldElement, Error> {
+    let mut hasher = poseidon::Hasher::new();
+    for entry_point in entry_points {
+        let mut builtins = poseidon::Hasher::new();
+        for builtin in &entry_point.builtins {
+            let name = cairo::short_string(builtin)
+                .ok_or_else(|| Error(Reason::InvalidBuiltin(builtin.clone())))?;
+            builtins.update(name);
+        }
+        hasher.update(entry_point.selector);
+        hasher.update(entry_point.offset.into());
+        hasher.update(builtins.finalize());
+    }
+    Ok(hasher.finalize())
+}
+
+/// Hashes bytecode segment starting at `offset`, returns the hash and the length of the segment
+fn hash_segment(
+    bytecode: &[FieldElement],
+    lengths: &BytecodeSegmentLengths,
+    offset: u64,
+) -> Result<(FieldElement, u64), Error> {
+    match lengths {
+        BytecodeSegmentLengths::Leaf(len) => {
+            let segment = offset
+                .checked_add(*len)
+                .and_then(|end| bytecode.get(offset as usize..usize::try_from(end).ok()?))
+                .ok_or(Error(Reason::SegmentLengthMismatch {
+                    segments: offset.saturating_add(*len),
+                    bytecode: bytecode.len(),
+                }))?;
+            Ok((poseidon::hash_many(segment), *len))
+        }
+        BytecodeSegmentLengths::Node(segments) => {
+            let mut hasher = poseidon::Hasher::new();
+            let mut total_len = 0u64;
+            for segment in segments {
+                let (hash, len) = hash_segment(bytecode, segment, offset + total_len)?;
+                hasher.update(len.into());
+                hasher.update(hash);
+                total_len += len;
+            }
+            Ok((hasher.finalize() + FieldElement::ONE, total_len))
+        }
+    }
+}
+
+/// Class hash cannot be computed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error(Reason);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Reason {
+    InvalidBuiltin(String),
+    SegmentLengthMismatch { segments: u64, bytecode: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Reason::InvalidBuiltin(name) => {
+                write!(f, "builtin name `{name}` is not a valid short string")
+            }
+            Reason::SegmentLengthMismatch { segments, bytecode } => write!(
+                f,
+                "bytecode segments cover {segments} elements, but bytecode length is {bytecode}"
+            ),
+        }
+    }
+}
@@ -5,7 +5,7 @@
 
 mod wrapper;
 
-pub use wrapper::W;
+pub use wrapper::{ParseError, W};
 
 /// Field element, derived by [ff] crate
 #[allow(missing_docs)]
@@ -1,12 +1,18 @@
+use core::cmp::Ordering;
+use core::fmt;
 use core::iter::{Product, Sum};
 use core::ops::{
-    Add, AddAssign, Deref, DerefMut, Mul, MulAssign, Neg, ShlAssign, ShrAssign, Sub, SubAssign,
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref,
+    DerefMut, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
+use core::str::FromStr;
 
 use primeorder::elliptic_curve::ops::Invert;
-use subtle::{ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption};
+use subtle::{
+    ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess, CtOption,
+};
 
-use crate::bigint::{Encoding, Uint, U256};
+use crate::bigint::{CheckedAdd, CheckedMul, Encoding, NonZero, Uint, U256};
 use crate::elliptic_curve::{
     self,
     scalar::{FromUintUnchecked, IsHigh, ScalarPrimitive},
@@ -19,7 +25,10 @@ use crate::typenum;
 ///
 /// Field element and scalar, derived by [ff], initially are not compatible with [elliptic-curve] crate
 /// and cannot be used together. This struct wraps scalar or field element, and implements required traits.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+///
+/// Field elements and scalars are ordered as integers in $[0, p)$ and $[0, n)$ respectively, regardless of
+/// their internal representation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 pub struct W<F>(F);
 
 impl<F> W<F> {
@@ -99,6 +108,17 @@ impl<F: PrimeField> W<F> {
     pub fn from_uint_mod_order(uint: &U256) -> Self {
         Self::from_be_bytes_mod_order(&uint.to_be_bytes())
     }
+
+    /// Constructs integer from [U256]
+    ///
+    /// Returns `None` if it overflows maximum allowed value
+    fn from_uint(uint: &U256) -> Option<Self> {
+        let mut repr = F::Repr::default();
+        let repr_len = repr.as_ref().len();
+        repr.as_mut()
+            .copy_from_slice(&uint.to_be_bytes()[32 - repr_len..]);
+        Self::from_be_bytes(repr).into()
+    }
 }
 
 impl<F: PrimeField> W<F>
@@ -109,6 +129,127 @@ where
     pub fn to_uint(&self) -> U256 {
         U256::from_be_bytes(self.to_be_bytes().into())
     }
+
+    /// Divides `self` by `rhs` as integers, returns quotient and remainder
+    ///
+    /// Returns `None` if `rhs` is zero. Same as `DivRem` of `u256` in Cairo.
+    pub fn div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        let rhs = Option::<NonZero<U256>>::from(NonZero::new(rhs.to_uint()))?;
+        let (quotient, remainder) = self.to_uint().div_rem(&rhs);
+        // Both are not greater than `self`, so they're in range
+        Some((
+            Self::from_uint_unchecked(quotient),
+            Self::from_uint_unchecked(remainder),
+        ))
+    }
+
+    /// Divides `self` by `rhs` as integers, rounding down
+    ///
+    /// Returns `None` if `rhs` is zero. Note that it's not a field division $x \cdot y^{-1}$.
+    pub fn floor_div(&self, rhs: &Self) -> Option<Self> {
+        self.div_rem(rhs).map(|(quotient, _)| quotient)
+    }
+
+    /// Computes bitwise OR of `self` and `rhs`
+    ///
+    /// Returns `None` if the result overflows maximum allowed value
+    pub fn checked_bitor(&self, rhs: &Self) -> Option<Self> {
+        Self::from_uint(&(self.to_uint() | rhs.to_uint()))
+    }
+
+    /// Computes bitwise XOR of `self` and `rhs`
+    ///
+    /// Returns `None` if the result overflows maximum allowed value
+    pub fn checked_bitxor(&self, rhs: &Self) -> Option<Self> {
+        Self::from_uint(&(self.to_uint() ^ rhs.to_uint()))
+    }
+
+    /// Computes bitwise NOT of `self` as a 252-bit integer, i.e. $2^{252} - 1 - x$
+    ///
+    /// Returns `None` if the result overflows maximum allowed value, which is the case for every $x$
+    /// less than $2^{252} - p$
+    pub fn checked_not(&self) -> Option<Self> {
+        let mask = U256::MAX >> 4;
+        Self::from_uint(&(self.to_uint() ^ mask))
+    }
+
+    /// Shifts `self` left by `n` bits, i.e. computes $x \cdot 2^n$ as integer
+    ///
+    /// Returns `None` if the result overflows maximum allowed value. Unlike field multiplication,
+    /// the result is never reduced.
+    pub fn checked_shl(&self, n: usize) -> Option<Self> {
+        let x = self.to_uint();
+        let shifted = x << n;
+        if shifted >> n != x {
+            // Some of the bits were shifted out of 256 bits
+            return None;
+        }
+        Self::from_uint(&shifted)
+    }
+}
+
+/// Formats integer in hex without leading zeroes, e.g. `format!("{x:#x}")` outputs felt in the form
+/// commonly used in Starknet: `0x1a2b`
+impl<F: PrimeField> fmt::LowerHex for W<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self.to_be_bytes().as_ref(), b"0123456789abcdef", f)
+    }
+}
+
+/// Formats integer in hex without leading zeroes
+impl<F: PrimeField> fmt::UpperHex for W<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self.to_be_bytes().as_ref(), b"0123456789ABCDEF", f)
+    }
+}
+
+fn fmt_hex(bytes_be: &[u8], alphabet: &[u8; 16], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut buf = [0u8; 64];
+    let digits = bytes_be.iter().flat_map(|b| [b >> 4, b & 0xf]);
+    for (out, d) in buf.iter_mut().zip(digits) {
+        *out = alphabet[usize::from(d)];
+    }
+    let hex = &buf[..2 * bytes_be.len()];
+    let first_significant = hex
+        .iter()
+        .position(|d| *d != b'0')
+        .unwrap_or(hex.len().saturating_sub(1));
+    let hex = core::str::from_utf8(&hex[first_significant..]).map_err(|_| fmt::Error)?;
+    f.pad_integral(true, "0x", hex)
+}
+
+/// Parses integer from `0x`-prefixed hex or decimal string, e.g. `"0x1a2b"` or `"6699"`
+///
+/// Returns error if string is not a valid number or it overflows maximum allowed value
+impl<F: PrimeField> FromStr for W<F> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, radix) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => (hex, 16),
+            None => (s, 10),
+        };
+        if digits.is_empty() {
+            return Err(ParseError);
+        }
+        let n = digits.chars().try_fold(U256::ZERO, |n, c| {
+            let digit = c.to_digit(radix)?;
+            let n: Option<U256> = n.checked_mul(&U256::from_u32(radix)).into();
+            Option::from(n?.checked_add(&U256::from_u32(digit)))
+        });
+        let n = n.ok_or(ParseError)?;
+        Self::from_uint(&n).ok_or(ParseError)
+    }
+}
+
+/// Error returned by [`W::from_str`](FromStr::from_str)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseError;
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid integer: expected decimal or 0x-prefixed hex number less than modulus")
+    }
 }
 
 impl<F> AsRef<W<F>> for W<F> {
@@ -154,6 +295,40 @@ impl<F: ConstantTimeEq> ConstantTimeEq for W<F> {
     }
 }
 
+/// Compares integers, not their internal Montgomery form
+impl<F: PrimeField> Ord for W<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_be_bytes()
+            .as_ref()
+            .cmp(other.to_be_bytes().as_ref())
+    }
+}
+
+impl<F: PrimeField> PartialOrd for W<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares integers in constant time
+impl<F> ConstantTimeGreater for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+    fn ct_gt(&self, other: &Self) -> subtle::Choice {
+        self.to_uint().ct_gt(&other.to_uint())
+    }
+}
+
+/// Compares integers in constant time
+impl<F> ConstantTimeLess for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+}
+
 impl<F: Sum> Sum for W<F> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         Self(iter.map(|f| f.0).sum())
@@ -270,9 +445,155 @@ impl<'r, F: SubAssign<&'r F>> SubAssign<&'r W<F>> for W<F> {
     }
 }
 
-impl<F: ShlAssign<usize>> ShlAssign<usize> for W<F> {
+/// Computes remainder of integer division, same as `%` of `u256` in Cairo
+///
+/// # Panics
+/// Panics if `rhs` is zero. Use [`W::div_rem`] to handle division by zero.
+impl<F> Rem for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+    type Output = W<F>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        match self.div_rem(&rhs) {
+            Some((_, remainder)) => remainder,
+            None => panic!("attempt to calculate the remainder with a divisor of zero"),
+        }
+    }
+}
+
+/// Computes bitwise AND, the result is never greater than operands
+impl<F> BitAnd for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+    type Output = W<F>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self::from_uint_unchecked(self.to_uint() & rhs.to_uint())
+    }
+}
+
+/// Computes bitwise OR
+///
+/// # Panics
+/// Panics if the result overflows maximum allowed value. Use [`W::checked_bitor`] to handle overflow.
+impl<F> BitOr for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+    type Output = W<F>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        match self.checked_bitor(&rhs) {
+            Some(result) => result,
+            None => panic!("attempt to compute bitwise OR with overflow"),
+        }
+    }
+}
+
+/// Computes bitwise XOR
+///
+/// # Panics
+/// Panics if the result overflows maximum allowed value. Use [`W::checked_bitxor`] to handle overflow.
+impl<F> BitXor for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+    type Output = W<F>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        match self.checked_bitxor(&rhs) {
+            Some(result) => result,
+            None => panic!("attempt to compute bitwise XOR with overflow"),
+        }
+    }
+}
+
+/// Shifts integer left, i.e. computes $x \cdot 2^n$
+///
+/// # Panics
+/// Panics if the result overflows maximum allowed value. Use [`W::checked_shl`] to handle overflow.
+impl<F> Shl<usize> for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+    type Output = W<F>;
+
+    fn shl(self, rhs: usize) -> Self::Output {
+        match self.checked_shl(rhs) {
+            Some(result) => result,
+            None => panic!("attempt to shift left with overflow"),
+        }
+    }
+}
+
+/// Shifts integer right, i.e. computes $\lfloor x / 2^n \rfloor$
+impl<F> Shr<usize> for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+    type Output = W<F>;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        Self::from_uint_unchecked(self.to_uint() >> rhs)
+    }
+}
+
+impl<F> RemAssign for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs
+    }
+}
+
+impl<F> BitAndAssign for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs
+    }
+}
+
+impl<F> BitOrAssign for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs
+    }
+}
+
+impl<F> BitXorAssign for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs
+    }
+}
+
+impl<F> ShlAssign<usize> for W<F>
+where
+    [u8; 32]: From<F::Repr>,
+    F: PrimeField,
+{
     fn shl_assign(&mut self, rhs: usize) {
-        self.0 <<= rhs
+        *self = *self << rhs
     }
 }
 
@@ -282,8 +603,7 @@ where
     F: PrimeField,
 {
     fn shr_assign(&mut self, rhs: usize) {
-        let n = self.to_uint();
-        *self = Self::from_uint_mod_order(&(n >> rhs))
+        *self = *self >> rhs
     }
 }
 
@@ -374,6 +694,19 @@ where
 
 impl<F: Default + Copy> zeroize::DefaultIsZeroes for W<F> {}
 
+/// Both $p$ and $n$ are 252 bits long, so, following [RFC 9380], we take $L = \lceil (252 + 128) / 8 \rceil = 48$
+/// bytes of output keying material to obtain an integer with negligible bias after reduction.
+///
+/// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5
+#[cfg(feature = "hash2curve")]
+impl<F: PrimeField> elliptic_curve::hash2curve::FromOkm for W<F> {
+    type Length = typenum::U48;
+
+    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self {
+        Self::from_be_bytes_mod_order(data)
+    }
+}
+
 impl<F: PrimeField, C: elliptic_curve::Curve> From<ScalarPrimitive<C>> for W<F> {
     fn from(s: ScalarPrimitive<C>) -> Self {
         let bytes_be = s.as_uint().to_be_bytes();
@@ -0,0 +1,549 @@
+//! FROST threshold Schnorr signatures
+//!
+//! Implements [FROST] $t$-out-of-$n$ threshold signing following the structure of [RFC 9591], adapted to
+//! Stark curve and [Schnorr signatures](crate::schnorr) defined in this crate. Any $t$ participants holding
+//! shares of the group secret key can jointly produce a signature verifiable by
+//! [`VerifyingKey::verify`](crate::schnorr::VerifyingKey::verify) under the group public key.
+//!
+//! Protocol:
+//! 1. Key shares are generated by a trusted dealer via [`keygen_with_dealer`], or by a distributed key
+//!    generation protocol (see [`KeyPackage::new`])
+//! 2. Round one: each signer generates [`SigningNonces`] via [`commit`], and sends [`SigningCommitments`] to
+//!    the coordinator
+//! 3. Coordinator sends list of commitments and the message to the signers, who start a [`Session`]
+//! 4. Round two: each signer produces a [`SignatureShare`] via [`Session::sign`]
+//! 5. Coordinator verifies and aggregates signature shares into a [`Signature`] via [`Session::aggregate`]
+//!
+//! Challenge hash is pluggable via [`FrostHash`] trait, which is implemented for challenges defined in
+//! [`schnorr`](crate::schnorr) module. Binding factors are derived via [Poseidon](crate::hash::poseidon) hash:
+//! * $H_5 = \text{poseidon}(\texttt{"frost/commitments"}, i_1, D_{1,x}, D_{1,y}, E_{1,x}, E_{1,y}, \dots)$
+//!   over commitments sorted by identifier
+//! * $\rho_i = \text{poseidon}(\texttt{"frost/rho"}, Y_x, Y_y, H_4(m), H_5, i) \bmod n$
+//!
+//! where $Y$ is a group public key, $H_4$ is given by [`FrostHash::hash_message`], and tags are encoded as
+//! Cairo short strings.
+//!
+//! ```rust
+//! use stark_curve::frost;
+//! use stark_curve::schnorr::PoseidonChallenge;
+//! use stark_curve::FieldElement;
+//!
+//! let mut rng = rand::rngs::OsRng;
+//! let (key_packages, public_key_package) = frost::keygen_with_dealer(2, 3, &mut rng)?;
+//! let message = [FieldElement::from(1337)];
+//!
+//! // Participants 1 and 3 are signing
+//! let signers = [&key_packages[0], &key_packages[2]];
+//! let (nonces, commitments): (Vec<_>, Vec<_>) = signers
+//!     .iter()
+//!     .map(|key| (key.identifier(), frost::commit(&mut rng)))
+//!     .map(|(id, (nonces, commitments))| (nonces, (id, commitments)))
+//!     .unzip();
+//!
+//! let session = frost::Session::new::<PoseidonChallenge>(
+//!     public_key_package.group_key(),
+//!     &commitments,
+//!     &message,
+//! )?;
+//! let shares = signers
+//!     .iter()
+//!     .zip(nonces)
+//!     .map(|(key, nonces)| Ok((key.identifier(), session.sign(nonces, key)?)))
+//!     .collect::<Result<Vec<_>, frost::Error>>()?;
+//! let signature = session.aggregate(&shares, &public_key_package)?;
+//!
+//! public_key_package
+//!     .group_key()
+//!     .verify::<PoseidonChallenge>(&message, &signature)
+//!     .unwrap();
+//! # Ok::<_, frost::Error>(())
+//! ```
+//!
+//! [FROST]: https://eprint.iacr.org/2020/852
+//! [RFC 9591]: https://www.rfc-editor.org/rfc/rfc9591.html
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use subtle::ConstantTimeEq;
+
+use crate::elliptic_curve::{rand_core::RngCore, Field, PrimeField};
+use crate::hash::poseidon;
+use crate::schnorr::{ChallengeHash, PoseidonChallenge, Signature, SigningKey, VerifyingKey};
+use crate::secret_sharing::{self, Share};
+use crate::{AffinePoint, FieldElement, ProjectivePoint, Scalar};
+
+/// Hash functions used by FROST on top of [`ChallengeHash`]
+pub trait FrostHash: ChallengeHash {
+    /// Hashes the message into a field element $H_4(m)$
+    ///
+    /// Returns error if hash function cannot produce a field element
+    fn hash_message(message: &Self::Message) -> Result<FieldElement, Self::Error>;
+}
+
+/// $H_4(m) = \text{poseidon}(m_1, \dots, m_k)$
+impl FrostHash for PoseidonChallenge {
+    fn hash_message(message: &[FieldElement]) -> Result<FieldElement, Self::Error> {
+        Ok(poseidon::hash_many(message))
+    }
+}
+
+/// $H_4(m) = \text{hash\\_to\\_field}(m)$ with domain separation tag `"stark-curve/frost/message"`
+#[cfg(feature = "hash2curve")]
+impl<X> FrostHash for crate::schnorr::ExpandMsgChallenge<X>
+where
+    X: for<'a> crate::elliptic_curve::hash2curve::ExpandMsg<'a>,
+{
+    fn hash_message(message: &[u8]) -> Result<FieldElement, Self::Error> {
+        let mut h = [FieldElement::ZERO];
+        crate::elliptic_curve::hash2curve::hash_to_field::<X, _>(
+            &[message],
+            &[b"stark-curve/frost/message"],
+            &mut h,
+        )?;
+        Ok(h[0])
+    }
+}
+
+/// Participant identifier, a non-zero scalar
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Identifier(Scalar);
+
+impl Identifier {
+    /// Constructs identifier from a scalar
+    ///
+    /// Returns `None` if scalar is zero
+    pub fn new(id: Scalar) -> Option<Self> {
+        if bool::from(id.is_zero()) {
+            None
+        } else {
+            Some(Self(id))
+        }
+    }
+
+    /// Returns identifier as scalar
+    pub fn as_scalar(&self) -> &Scalar {
+        &self.0
+    }
+
+    fn to_field_element(self) -> FieldElement {
+        // n < p, so any scalar is a valid field element
+        FieldElement::from_be_bytes_mod_order(&self.0.to_repr())
+    }
+}
+
+impl TryFrom<u16> for Identifier {
+    type Error = Error;
+
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        Self::new(Scalar::from(u64::from(id))).ok_or(Error(Reason::ZeroIdentifier))
+    }
+}
+
+/// Secret key material of a participant
+#[derive(Clone)]
+pub struct KeyPackage {
+    identifier: Identifier,
+    signing_share: Scalar,
+    verifying_share: AffinePoint,
+    group_key: VerifyingKey,
+    min_signers: u16,
+}
+
+impl KeyPackage {
+    /// Constructs a key package from a secret share, e.g. obtained as output of DKG protocol
+    pub fn new(
+        identifier: Identifier,
+        signing_share: Scalar,
+        group_key: VerifyingKey,
+        min_signers: u16,
+    ) -> Self {
+        Self {
+            identifier,
+            signing_share,
+            verifying_share: (ProjectivePoint::GENERATOR * signing_share).to_affine(),
+            group_key,
+            min_signers,
+        }
+    }
+
+    /// Returns identifier of the participant
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    /// Returns secret share $s_i$
+    pub fn signing_share(&self) -> &Scalar {
+        &self.signing_share
+    }
+
+    /// Returns public share $Y_i = s_i \cdot G$
+    pub fn verifying_share(&self) -> &AffinePoint {
+        &self.verifying_share
+    }
+
+    /// Returns group public key $Y$
+    pub fn group_key(&self) -> &VerifyingKey {
+        &self.group_key
+    }
+
+    /// Returns threshold $t$
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+}
+
+impl fmt::Debug for KeyPackage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyPackage")
+            .field("identifier", &self.identifier)
+            .field("verifying_share", &self.verifying_share)
+            .field("group_key", &self.group_key)
+            .field("min_signers", &self.min_signers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for KeyPackage {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.signing_share)
+    }
+}
+
+/// Public key material: group public key and public shares of all participants
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKeyPackage {
+    verifying_shares: Vec<(Identifier, AffinePoint)>,
+    group_key: VerifyingKey,
+}
+
+impl PublicKeyPackage {
+    /// Constructs a public key package
+    pub fn new(verifying_shares: Vec<(Identifier, AffinePoint)>, group_key: VerifyingKey) -> Self {
+        Self {
+            verifying_shares,
+            group_key,
+        }
+    }
+
+    /// Returns group public key $Y$
+    pub fn group_key(&self) -> &VerifyingKey {
+        &self.group_key
+    }
+
+    /// Returns public share $Y_i$ of participant with given identifier
+    pub fn verifying_share(&self, identifier: &Identifier) -> Option<&AffinePoint> {
+        self.verifying_shares
+            .iter()
+            .find(|(id, _)| id == identifier)
+            .map(|(_, share)| share)
+    }
+}
+
+/// Generates key shares using a trusted dealer
+///
+/// Dealer samples a random group secret key and shares it among `max_signers` participants with identifiers
+/// $1, \dots, n$ such that any `min_signers` of them can sign.
+pub fn keygen_with_dealer(
+    min_signers: u16,
+    max_signers: u16,
+    rng: &mut impl RngCore,
+) -> Result<(Vec<KeyPackage>, PublicKeyPackage), Error> {
+    let group_secret = SigningKey::random(&mut *rng);
+    let group_key = *group_secret.verifying_key();
+    let (shares, _polynomial) =
+        secret_sharing::split(*group_secret.as_scalar(), min_signers, max_signers, rng)
+            .map_err(|_| Error(Reason::InvalidThreshold))?;
+
+    let key_packages = shares
+        .iter()
+        .map(|Share { index, value }| {
+            let identifier = Identifier::new(*index).ok_or(Error(Reason::ZeroIdentifier))?;
+            Ok(KeyPackage::new(identifier, *value, group_key, min_signers))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let verifying_shares = key_packages
+        .iter()
+        .map(|key| (key.identifier, key.verifying_share))
+        .collect();
+
+    Ok((
+        key_packages,
+        PublicKeyPackage::new(verifying_shares, group_key),
+    ))
+}
+
+/// Hiding and binding nonces $(d_i, e_i)$
+///
+/// Nonces must never be reused, so they're consumed when producing a signature share, cannot be cloned,
+/// and are zeroized on drop.
+pub struct SigningNonces {
+    hiding: Scalar,
+    binding: Scalar,
+}
+
+impl fmt::Debug for SigningNonces {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningNonces").finish_non_exhaustive()
+    }
+}
+
+impl Drop for SigningNonces {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.hiding);
+        zeroize::Zeroize::zeroize(&mut self.binding);
+    }
+}
+
+/// Commitments $(D_i, E_i) = (d_i \cdot G, e_i \cdot G)$ to signing nonces
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SigningCommitments {
+    /// Hiding commitment $D_i$
+    pub hiding: AffinePoint,
+    /// Binding commitment $E_i$
+    pub binding: AffinePoint,
+}
+
+/// Round one: generates signing nonces and commitments to them
+pub fn commit(rng: &mut impl RngCore) -> (SigningNonces, SigningCommitments) {
+    let nonces = SigningNonces {
+        hiding: Scalar::random(&mut *rng),
+        binding: Scalar::random(&mut *rng),
+    };
+    let commitments = SigningCommitments {
+        hiding: (ProjectivePoint::GENERATOR * nonces.hiding).to_affine(),
+        binding: (ProjectivePoint::GENERATOR * nonces.binding).to_affine(),
+    };
+    (nonces, commitments)
+}
+
+/// Signature share $z_i$
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignatureShare(pub Scalar);
+
+/// Signing session for a specific set of signers and message
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Session {
+    /// Commitments sorted by identifier, along with binding factors
+    participants: Vec<(Identifier, SigningCommitments, Scalar)>,
+    group_commitment: AffinePoint,
+    challenge: Scalar,
+}
+
+impl Session {
+    /// Starts a signing session
+    ///
+    /// Computes binding factors $\rho_i$, group commitment $R = \sum_i D_i + \rho_i \cdot E_i$, and challenge
+    /// $c = H(R, Y, m)$. Returns error if identifiers are not distinct, or some commitment is a point at infinity.
+    /// Also returns error if hash function fails.
+    pub fn new<H: FrostHash>(
+        group_key: &VerifyingKey,
+        commitments: &[(Identifier, SigningCommitments)],
+        message: &H::Message,
+    ) -> Result<Self, Error> {
+        let mut commitments = commitments.to_vec();
+        commitments.sort_by_key(|(id, _)| *id);
+        if commitments.windows(2).any(|w| w[0].0 == w[1].0) {
+            return Err(Error(Reason::DuplicateIdentifier));
+        }
+        let has_identity = commitments.iter().any(|(_, c)| {
+            bool::from(c.hiding.is_identity()) || bool::from(c.binding.is_identity())
+        });
+        if has_identity {
+            return Err(Error(Reason::IdentityCommitment));
+        }
+
+        let hasher = poseidon::Hasher::new()
+            .chain(FieldElement::from_be_bytes_mod_order(b"frost/commitments"));
+        let commitments_hash = commitments
+            .iter()
+            .fold(hasher, |h, (id, c)| {
+                h.chain(id.to_field_element())
+                    .chain_point(&c.hiding)
+                    .chain_point(&c.binding)
+            })
+            .finalize();
+
+        let message_hash = H::hash_message(message).map_err(|_| Error(Reason::HashFailed))?;
+        let hasher = poseidon::Hasher::new()
+            .chain(FieldElement::from_be_bytes_mod_order(b"frost/rho"))
+            .chain_point(group_key.as_point())
+            .chain(message_hash)
+            .chain(commitments_hash);
+
+        let participants: Vec<_> = commitments
+            .into_iter()
+            .map(|(id, c)| {
+                let rho = hasher.clone().chain(id.to_field_element()).finalize();
+                let rho = Scalar::from_be_bytes_mod_order(&rho.to_repr());
+                (id, c, rho)
+            })
+            .collect();
+
+        let group_commitment = participants
+            .iter()
+            .fold(ProjectivePoint::IDENTITY, |r, (_, c, rho)| {
+                r + c.hiding + c.binding * rho
+            })
+            .to_affine();
+        let challenge = H::challenge(&group_commitment, group_key.as_point(), message)
+            .map_err(|_| Error(Reason::HashFailed))?;
+
+        Ok(Self {
+            participants,
+            group_commitment,
+            challenge,
+        })
+    }
+
+    /// Returns group commitment $R$
+    pub fn group_commitment(&self) -> &AffinePoint {
+        &self.group_commitment
+    }
+
+    /// Round two: produces signature share $z_i = d_i + e_i \cdot \rho_i + \lambda_i \cdot s_i \cdot c$
+    ///
+    /// Returns error if signer's commitments are not included into the session, or there are fewer
+    /// signers than the threshold.
+    pub fn sign(
+        &self,
+        nonces: SigningNonces,
+        key_package: &KeyPackage,
+    ) -> Result<SignatureShare, Error> {
+        if self.participants.len() < usize::from(key_package.min_signers) {
+            return Err(Error(Reason::NotEnoughSigners));
+        }
+        let (_, commitments, rho) = self.participant(&key_package.identifier)?;
+        let expected_commitments = SigningCommitments {
+            hiding: (ProjectivePoint::GENERATOR * nonces.hiding).to_affine(),
+            binding: (ProjectivePoint::GENERATOR * nonces.binding).to_affine(),
+        };
+        if *commitments != expected_commitments {
+            return Err(Error(Reason::CommitmentsMismatch));
+        }
+
+        let lambda = self.lagrange_coefficient(&key_package.identifier)?;
+        let z = nonces.hiding
+            + nonces.binding * rho
+            + lambda * key_package.signing_share * self.challenge;
+        Ok(SignatureShare(z))
+    }
+
+    /// Verifies signature share of participant with given `identifier` and public share $Y_i$
+    ///
+    /// Checks $z_i \cdot G = D_i + \rho_i \cdot E_i + \lambda_i \cdot c \cdot Y_i$
+    pub fn verify_share(
+        &self,
+        identifier: &Identifier,
+        share: &SignatureShare,
+        verifying_share: &AffinePoint,
+    ) -> Result<(), Error> {
+        let (_, commitments, rho) = self.participant(identifier)?;
+        let lambda = self.lagrange_coefficient(identifier)?;
+
+        let lhs = ProjectivePoint::GENERATOR * share.0;
+        let rhs = ProjectivePoint::from(commitments.hiding)
+            + commitments.binding * rho
+            + *verifying_share * (lambda * self.challenge);
+        if bool::from(lhs.ct_eq(&rhs)) {
+            Ok(())
+        } else {
+            Err(Error(Reason::InvalidSignatureShare(*identifier)))
+        }
+    }
+
+    /// Verifies signature shares and aggregates them into signature $(R, \sum_i z_i)$
+    ///
+    /// Every participant of the session must provide exactly one share. If some share is invalid, returns
+    /// error which identifies misbehaving participant (see [`Error::culprit`]).
+    pub fn aggregate(
+        &self,
+        shares: &[(Identifier, SignatureShare)],
+        public_key_package: &PublicKeyPackage,
+    ) -> Result<Signature, Error> {
+        if shares.len() != self.participants.len() {
+            return Err(Error(Reason::SharesCountMismatch));
+        }
+        let mut z = Scalar::ZERO;
+        for (k, (id, share)) in shares.iter().enumerate() {
+            if shares[..k].iter().any(|(other, _)| other == id) {
+                return Err(Error(Reason::DuplicateIdentifier));
+            }
+            let verifying_share = public_key_package
+                .verifying_share(id)
+                .ok_or(Error(Reason::UnknownIdentifier))?;
+            self.verify_share(id, share, verifying_share)?;
+            z += share.0;
+        }
+        Ok(Signature {
+            r: self.group_commitment,
+            s: z,
+        })
+    }
+
+    fn participant(
+        &self,
+        identifier: &Identifier,
+    ) -> Result<&(Identifier, SigningCommitments, Scalar), Error> {
+        self.participants
+            .iter()
+            .find(|(id, _, _)| id == identifier)
+            .ok_or(Error(Reason::UnknownIdentifier))
+    }
+
+    fn lagrange_coefficient(&self, identifier: &Identifier) -> Result<Scalar, Error> {
+        let ids: Vec<_> = self.participants.iter().map(|(id, _, _)| id.0).collect();
+        secret_sharing::lagrange_coefficient(&Scalar::ZERO, &identifier.0, &ids)
+            .ok_or(Error(Reason::UnknownIdentifier))
+    }
+}
+
+/// FROST error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Error(Reason);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Reason {
+    InvalidThreshold,
+    ZeroIdentifier,
+    DuplicateIdentifier,
+    UnknownIdentifier,
+    IdentityCommitment,
+    NotEnoughSigners,
+    CommitmentsMismatch,
+    SharesCountMismatch,
+    InvalidSignatureShare(Identifier),
+    HashFailed,
+}
+
+impl Error {
+    /// Returns identifier of the participant who provided invalid signature share, if that's the reason
+    /// of the error
+    pub fn culprit(&self) -> Option<Identifier> {
+        match self.0 {
+            Reason::InvalidSignatureShare(id) => Some(id),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Reason::InvalidThreshold => f.write_str("invalid threshold"),
+            Reason::ZeroIdentifier => f.write_str("identifier must be non-zero"),
+            Reason::DuplicateIdentifier => f.write_str("identifiers are not distinct"),
+            Reason::UnknownIdentifier => f.write_str("participant is not part of the session"),
+            Reason::IdentityCommitment => f.write_str("commitment is a point at infinity"),
+            Reason::NotEnoughSigners => f.write_str("there are fewer signers than threshold"),
+            Reason::CommitmentsMismatch => {
+                f.write_str("signing nonces do not match commitments in the session")
+            }
+            Reason::SharesCountMismatch => {
+                f.write_str("number of signature shares doesn't match number of signers")
+            }
+            Reason::InvalidSignatureShare(_) => f.write_str("invalid signature share"),
+            Reason::HashFailed => f.write_str("hash function failed"),
+        }
+    }
+}
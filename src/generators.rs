@@ -0,0 +1,97 @@
+//! Deterministic derivation of curve points
+//!
+//! Provides "nothing-up-my-sleeve" points: points derived by a public procedure, such that nobody knows
+//! their discrete logarithm relative to [`AffinePoint::GENERATOR`] or to each other. Such points are
+//! required, for instance, as extra generators in commitment schemes.
+//!
+//! All the functions in this module are not constant time, they must only be used with public inputs.
+
+use crate::elliptic_curve::Field;
+use crate::{constants, AffinePoint, FieldElement};
+
+/// First $7 \cdot 76 = 532$ decimal digits of $\pi$
+///
+/// Digits are used by [`from_pi_digits`] to derive curve constants. Digits `0..76` define $\beta$,
+/// the rest derive [`constants::SHIFT_POINT`], [`constants::GENERATOR`], and
+/// [`constants::PEDERSEN_P0`]..[`constants::PEDERSEN_P3`].
+pub const PI_DIGITS: &[u8; 532] = b"\
+    3141592653589793238462643383279502884197169399375105820974944592307816406286\
+    2089986280348253421170679821480865132823066470938446095505822317253594081284\
+    8111745028410270193852110555964462294895493038196442881097566593344612847564\
+    8233786783165271201909145648566923460348610454326648213393607260249141273724\
+    5870066063155881748815209209628292540917153643678925903600113305305488204665\
+    2138414695194151160943305727036575959195309218611738193261179310511854807446\
+    2379962749567351885752724891227938183011949129833673362440656643086021394946";
+
+/// Amount of digits of $\pi$ used to derive a single point
+pub const DIGITS_PER_POINT: usize = 76;
+
+/// Derives a curve point from the `index`-th chunk of [`DIGITS_PER_POINT`] digits of $\pi$
+///
+/// Reproduces the procedure StarkWare used to generate curve constants: the chunk of digits is
+/// interpreted as an integer $x$ (reduced modulo $p$), and then passed to [`increment_to_curve`].
+/// * `index = 1` gives [`constants::SHIFT_POINT`]
+/// * `index = 2` gives [`constants::GENERATOR`]
+/// * `index = 3..=6` give [`constants::PEDERSEN_P0`]..[`constants::PEDERSEN_P3`]
+///
+/// Returns `None` if `index` is out of digits hardcoded in [`PI_DIGITS`]. Following points can be
+/// derived by feeding further digits of $\pi$ into [`from_decimal_digits`].
+pub fn from_pi_digits(index: usize) -> Option<AffinePoint> {
+    let start = index.checked_mul(DIGITS_PER_POINT)?;
+    let end = start.checked_add(DIGITS_PER_POINT)?;
+    from_decimal_digits(PI_DIGITS.get(start..end)?)
+}
+
+/// Derives a curve point from integer $x$ given as ASCII decimal digits
+///
+/// Integer is reduced modulo $p$, and then passed to [`increment_to_curve`]. Returns `None`
+/// if `digits` contain anything but `0-9`.
+pub fn from_decimal_digits(digits: &[u8]) -> Option<AffinePoint> {
+    let x = digits.iter().try_fold(FieldElement::ZERO, |x, d| {
+        let d = d.checked_sub(b'0').filter(|d| *d < 10)?;
+        Some(x * FieldElement::from(10) + FieldElement::from(u64::from(d)))
+    })?;
+    Some(increment_to_curve(x))
+}
+
+/// Derives a curve point from a label
+///
+/// Label is hashed to field element $x$ via [`hash_to_field`](crate::elliptic_curve::hash2curve::hash_to_field)
+/// using message expander `X` and domain separation tag `dst`, and then passed to [`increment_to_curve`].
+/// Deriving several independent points is done by using distinct labels (e.g. appending an index).
+#[cfg(feature = "hash2curve")]
+pub fn from_label<'a, X>(
+    label: &[&[u8]],
+    dst: &'a [&'a [u8]],
+) -> crate::elliptic_curve::Result<AffinePoint>
+where
+    X: crate::elliptic_curve::hash2curve::ExpandMsg<'a>,
+{
+    let mut x = [FieldElement::ZERO];
+    crate::elliptic_curve::hash2curve::hash_to_field::<X, _>(label, dst, &mut x)?;
+    Ok(increment_to_curve(x[0]))
+}
+
+/// Finds a curve point with smallest x-coordinate $x' \ge x$ (try-and-increment)
+///
+/// Out of two points with the same $x'$, the one with smaller y-coordinate (as integer in $[0, p)$)
+/// is returned.
+pub fn increment_to_curve(mut x: FieldElement) -> AffinePoint {
+    loop {
+        let y_squared = x.square() * x + constants::EQUATION_A * x + constants::EQUATION_B;
+        if let Some(y) = Option::<FieldElement>::from(y_squared.sqrt()) {
+            let neg_y = -y;
+            let y = if y.to_uint() < neg_y.to_uint() {
+                y
+            } else {
+                neg_y
+            };
+
+            // Coordinates satisfy curve equation, so the point is always constructed
+            if let Some(point) = crate::point_from_coordinates(&x, &y) {
+                return point;
+            }
+        }
+        x += FieldElement::ONE;
+    }
+}
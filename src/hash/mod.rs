@@ -0,0 +1,20 @@
+//! Hash functions over field elements
+//!
+//! Hash functions used by Starknet and StarkEx. They take field elements as input and produce a field element.
+
+use sha3::{Digest, Keccak256};
+
+use crate::FieldElement;
+
+pub mod pedersen;
+pub mod poseidon;
+
+/// Starknet Keccak: Keccak-256 of the input truncated to the lowest $250$ bits
+///
+/// Used by Starknet to derive selectors, storage variable addresses, and SNIP-12 type hashes from
+/// names.
+pub fn sn_keccak(data: &[u8]) -> FieldElement {
+    let mut digest: [u8; 32] = Keccak256::digest(data).into();
+    digest[0] &= 0x03;
+    FieldElement::from_be_bytes_mod_order(&digest)
+}
@@ -0,0 +1,117 @@
+//! Starknet Pedersen hash
+//!
+//! Pedersen hash over [`FieldElement`] as [specified] by Starknet:
+//!
+//! $$
+//! H(a, b) = \left[P_s + a_{low} \cdot P_0 + a_{high} \cdot P_1 + b_{low} \cdot P_2 + b_{high} \cdot P_3\right]_x
+//! $$
+//!
+//! where $a_{low}$ is the lowest $248$ bits of $a$, $a_{high}$ is the remaining $4$ bits, and
+//! $P_s, P_0, \dots, P_3$ are [constant points](crate::constants::PEDERSEN_P0).
+//!
+//! Pedersen hash is used by Starknet for contract addresses, storage addresses, and in the older
+//! (Cairo 0) parts of the protocol. Newer parts use [Poseidon](super::poseidon) which is much cheaper
+//! to compute.
+//!
+//! [specified]: https://docs.starknet.io/architecture-and-concepts/cryptography/#pedersen_hash
+
+use crate::elliptic_curve::PrimeField;
+use crate::{constants, FieldElement, ProjectivePoint, Scalar};
+
+/// Computes Pedersen hash $H(x, y)$
+///
+/// With `std` feature, [constant points](ConstantPoints) are constructed once and shared between calls.
+/// Without it, they're constructed on every call, use [`ConstantPoints::hash`] to compute many hashes.
+pub fn hash(x: FieldElement, y: FieldElement) -> FieldElement {
+    shared_points().hash(x, y)
+}
+
+/// Computes Pedersen hash of an array $H(\dots H(H(0, x_1), x_2) \dots, x_k), k)$
+///
+/// This is how Starknet hashes arrays with Pedersen, also known as `compute_hash_on_elements`.
+pub fn hash_array<'a>(msgs: impl IntoIterator<Item = &'a FieldElement>) -> FieldElement {
+    shared_points().hash_array(msgs)
+}
+
+/// Constant points shared by [`hash`] and [`hash_array`], constructed on first use
+#[cfg(feature = "std")]
+fn shared_points() -> &'static ConstantPoints {
+    static POINTS: std::sync::OnceLock<ConstantPoints> = std::sync::OnceLock::new();
+    POINTS.get_or_init(ConstantPoints::new)
+}
+
+/// Constant points used by [`hash`] and [`hash_array`]
+#[cfg(not(feature = "std"))]
+fn shared_points() -> ConstantPoints {
+    ConstantPoints::new()
+}
+
+/// Constant points $P_s, P_0, \dots, P_3$ of Pedersen hash
+///
+/// Constructing the points involves checking that they're on the curve. When computing many hashes,
+/// construct them once and reuse.
+#[derive(Clone, Copy, Debug)]
+pub struct ConstantPoints {
+    shift: ProjectivePoint,
+    p: [ProjectivePoint; 4],
+}
+
+impl ConstantPoints {
+    /// Constructs constant points
+    pub fn new() -> Self {
+        let [shift, p0, p1, p2, p3] = [
+            constants::SHIFT_POINT,
+            constants::PEDERSEN_P0,
+            constants::PEDERSEN_P1,
+            constants::PEDERSEN_P2,
+            constants::PEDERSEN_P3,
+        ]
+        .map(|(x, y)| {
+            // Constant points are on the curve, so it never falls back to identity
+            crate::point_from_coordinates(&x, &y)
+                .map(ProjectivePoint::from)
+                .unwrap_or(ProjectivePoint::IDENTITY)
+        });
+        Self {
+            shift,
+            p: [p0, p1, p2, p3],
+        }
+    }
+
+    /// Computes Pedersen hash $H(x, y)$
+    pub fn hash(&self, x: FieldElement, y: FieldElement) -> FieldElement {
+        let [p0, p1, p2, p3] = self.p;
+        let (x_low, x_high) = split(&x);
+        let (y_low, y_high) = split(&y);
+
+        let point = self.shift + p0 * x_low + p1 * x_high + p2 * y_low + p3 * y_high;
+        crate::affine_coordinates(&point.to_affine())
+            // Point at infinity would mean we found discrete log relation between constant points
+            .map(|(x, _y)| x)
+            .unwrap_or_default()
+    }
+
+    /// Computes Pedersen hash of an array, see [`hash_array`]
+    pub fn hash_array<'a>(&self, msgs: impl IntoIterator<Item = &'a FieldElement>) -> FieldElement {
+        let (acc, len) = msgs
+            .into_iter()
+            .fold((FieldElement::ZERO, 0u64), |(acc, len), m| {
+                (self.hash(acc, *m), len + 1)
+            });
+        self.hash(acc, FieldElement::from(len))
+    }
+}
+
+impl Default for ConstantPoints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Splits field element into lowest 248 bits and highest 4 bits
+fn split(x: &FieldElement) -> (Scalar, Scalar) {
+    let bytes = x.to_repr();
+    let low = Scalar::from_be_bytes_mod_order(&bytes[1..]);
+    let high = Scalar::from(u64::from(bytes[0]));
+    (low, high)
+}
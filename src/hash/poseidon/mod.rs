@@ -0,0 +1,144 @@
+//! Starknet Poseidon hash
+//!
+//! Poseidon hash over [`FieldElement`] as [specified] by Starknet: Hades permutation with state of width $3$,
+//! $8$ full rounds and $83$ partial rounds, S-box $x^3$, and sponge construction of rate $2$.
+//!
+//! [specified]: https://docs.starknet.io/architecture-and-concepts/cryptography/#poseidon_hash
+
+use crate::elliptic_curve::Field;
+use crate::{AffinePoint, FieldElement};
+
+mod round_constants;
+
+use round_constants::ROUND_CONSTANTS;
+
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 83;
+
+/// Hades permutation
+pub fn hades_permutation(state: &mut [FieldElement; 3]) {
+    let rounds = ROUND_CONSTANTS.chunks_exact(3);
+    for (i, round_constants) in rounds.enumerate() {
+        for (s, c) in state.iter_mut().zip(round_constants) {
+            *s += c;
+        }
+
+        let is_partial_round = (FULL_ROUNDS / 2..FULL_ROUNDS / 2 + PARTIAL_ROUNDS).contains(&i);
+        if !is_partial_round {
+            state.iter_mut().for_each(|s| *s = s.square() * *s);
+        } else {
+            state[2] = state[2].square() * state[2];
+        }
+
+        mix(state);
+    }
+}
+
+/// Multiplies the state by MDS matrix
+///
+/// $$
+/// M = \begin{pmatrix} 3 & 1 & 1 \\\\ 1 & -1 & 1 \\\\ 1 & 1 & -2 \end{pmatrix}
+/// $$
+fn mix(state: &mut [FieldElement; 3]) {
+    let [a, b, c] = *state;
+    let t = a + b + c;
+    *state = [t + a.double(), t - b.double(), t - c.double() - c];
+}
+
+/// Computes Poseidon hash of two field elements
+pub fn hash(x: FieldElement, y: FieldElement) -> FieldElement {
+    let mut state = [x, y, FieldElement::from(2)];
+    hades_permutation(&mut state);
+    state[0]
+}
+
+/// Computes Poseidon hash of a single field element
+pub fn hash_single(x: FieldElement) -> FieldElement {
+    let mut state = [x, FieldElement::ZERO, FieldElement::ONE];
+    hades_permutation(&mut state);
+    state[0]
+}
+
+/// Computes Poseidon hash of an arbitrary number of field elements
+///
+/// Same as absorbing all elements into [`Hasher`].
+pub fn hash_many<'a>(msgs: impl IntoIterator<Item = &'a FieldElement>) -> FieldElement {
+    let mut hasher = Hasher::new();
+    msgs.into_iter().for_each(|m| hasher.update(*m));
+    hasher.finalize()
+}
+
+/// Stateful Poseidon hasher
+///
+/// Absorbs field elements one by one, produces the same result as [`hash_many`].
+#[derive(Clone, Debug, Default)]
+pub struct Hasher {
+    state: [FieldElement; 3],
+    buffer: Option<FieldElement>,
+}
+
+impl Hasher {
+    /// Constructs a hasher with empty state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs a field element
+    pub fn update(&mut self, msg: FieldElement) {
+        match self.buffer.take() {
+            Some(prev) => {
+                self.state[0] += prev;
+                self.state[1] += msg;
+                hades_permutation(&mut self.state);
+            }
+            None => self.buffer = Some(msg),
+        }
+    }
+
+    /// Absorbs a field element, returns the hasher
+    pub fn chain(mut self, msg: FieldElement) -> Self {
+        self.update(msg);
+        self
+    }
+
+    /// Absorbs affine coordinates $(x, y)$ of the point, returns the hasher
+    ///
+    /// Point at infinity is absorbed as $(0, 0)$.
+    pub(crate) fn chain_point(self, point: &AffinePoint) -> Self {
+        let (x, y) = crate::affine_coordinates(point).unwrap_or_default();
+        self.chain(x).chain(y)
+    }
+
+    /// Pads the input and outputs the hash
+    pub fn finalize(mut self) -> FieldElement {
+        match self.buffer.take() {
+            Some(last) => {
+                self.state[0] += last;
+                self.state[1] += FieldElement::ONE;
+            }
+            None => self.state[0] += FieldElement::ONE,
+        }
+        hades_permutation(&mut self.state);
+        self.state[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::format;
+
+    use sha2::{Digest, Sha256};
+
+    use crate::FieldElement;
+
+    #[test]
+    fn round_constants_align_with_specs() {
+        for (i, c) in super::ROUND_CONSTANTS.iter().enumerate() {
+            let digest = Sha256::digest(format!("Hades{i}"));
+            let expected = FieldElement::from_be_bytes_mod_order(&digest);
+            assert_eq!(*c, expected, "constant {i}");
+        }
+    }
+}
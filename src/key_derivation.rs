@@ -0,0 +1,159 @@
+//! Derivation of Stark keys
+//!
+//! Stark keys are usually derived from Ethereum keys: either from a BIP-39 mnemonic via [EIP-2645] path, or
+//! from an Ethereum signature (see [`from_eth_signature`]). Either way, the result is a 256-bit secret which is mapped to a [`Scalar`]
+//! via [`grind_key`].
+//!
+//! ```rust
+//! use stark_curve::key_derivation::{grind_key, Eip2645Path};
+//!
+//! let eth_address = hex_literal::hex!("a4864d977b944315389d1765ffa7e66F74ee8cd7");
+//! let path = Eip2645Path::new(b"starkex", b"starkdeployement", &eth_address, 0).unwrap();
+//! assert_eq!(
+//!     path.to_string(),
+//!     "m/2645'/579218131'/891216374'/1961790679'/2135936222'/0",
+//! );
+//!
+//! // Secret derived by BIP-32 along the path
+//! let secret = hex_literal::hex!("86F3E7293141F20A8BAFF320E8EE4ACCB9D4A4BF2B4D295E8CEE784DB46E0519");
+//! let stark_key = grind_key(&secret);
+//! ```
+//!
+//! [EIP-2645]: https://eips.ethereum.org/EIPS/eip-2645
+
+use core::fmt;
+
+use sha2::{Digest, Sha256};
+
+use crate::bigint::U256;
+use crate::Scalar;
+
+/// Largest multiple of $n$ that fits into 256 bits, i.e. $2^{256} - (2^{256} \bmod n)$
+const GRIND_KEY_LIMIT: U256 =
+    U256::from_be_hex("f80000000000020efffffffffffffff738a13b4b920e9411ae6da5f40b0358b1");
+
+/// Maps a 256-bit secret to a scalar by rejection sampling
+///
+/// Computes $k_i = \text{sha256}(\text{seed} \\| i)$ for $i = 0, 1, \dots$ until $k_i < 2^{256} - (2^{256} \bmod n)$,
+/// and returns $k_i \bmod n$. Index $i$ is encoded in big-endian using the minimal number of bytes,
+/// but at least one byte. This matches StarkWare's reference implementation.
+///
+/// `seed` is hashed as is. Note that implementations taking the seed as an integer (e.g. StarkWare's
+/// Python implementation) strip its leading zero bytes, which leads to a different key for ~1/256 of
+/// seeds. Strip leading zeroes from the seed to be compatible with them.
+pub fn grind_key(seed: &[u8]) -> Scalar {
+    let mut i = 0u64;
+    loop {
+        let index = i.to_be_bytes();
+        let index_len = (8 - i.leading_zeros() as usize / 8).max(1);
+        let digest = Sha256::new()
+            .chain_update(seed)
+            .chain_update(&index[8 - index_len..])
+            .finalize();
+        if U256::from_be_slice(&digest) < GRIND_KEY_LIMIT {
+            return Scalar::from_be_bytes_mod_order(&digest);
+        }
+        i += 1;
+    }
+}
+
+/// Derives Stark private key from Ethereum signature $(r, s, v)$, as done in StarkEx onboarding
+///
+/// The user signs a fixed onboarding message with their Ethereum key, and the Stark key is derived
+/// as [`grind_key(r)`](grind_key), where $r$ is taken as 32 big-endian bytes including leading zeroes.
+/// This matches StarkWare's and noble's JavaScript implementations. Signature must be encoded as
+/// $r \| s \| v$, components $s$ and $v$ are ignored.
+///
+/// Signature is not verified, so it's the caller's responsibility to make sure it was produced by
+/// the user, and that signing is deterministic (RFC 6979), otherwise the same key can't be derived again.
+pub fn from_eth_signature(signature: &[u8; 65]) -> Scalar {
+    grind_key(&signature[..32])
+}
+
+/// [EIP-2645] derivation path `m/2645'/layer'/application'/eth_address_1'/eth_address_2'/index`
+///
+/// All components but `index` are hardened.
+///
+/// [EIP-2645]: https://eips.ethereum.org/EIPS/eip-2645
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Eip2645Path {
+    /// Lowest 31 bits of sha256 of the layer name
+    pub layer: u32,
+    /// Lowest 31 bits of sha256 of the application name
+    pub application: u32,
+    /// Lowest 31 bits of Ethereum address
+    pub eth_address_1: u32,
+    /// Next 31 bits of Ethereum address
+    pub eth_address_2: u32,
+    /// Index of the key, less than $2^{31}$
+    pub index: u32,
+}
+
+impl Eip2645Path {
+    /// Purpose component of the path
+    pub const PURPOSE: u32 = 2645;
+    /// Bit set in hardened components of the path
+    pub const HARDENED: u32 = 1 << 31;
+
+    /// Constructs a path for the `layer` (e.g. `b"starkex"`), `application`, and Ethereum address
+    ///
+    /// Returns `None` if `index` is not less than $2^{31}$
+    pub fn new(
+        layer: &[u8],
+        application: &[u8],
+        eth_address: &[u8; 20],
+        index: u32,
+    ) -> Option<Self> {
+        if index >= Self::HARDENED {
+            return None;
+        }
+        let mut low = [0u8; 8];
+        low.copy_from_slice(&eth_address[12..]);
+        let low = u64::from_be_bytes(low);
+        let mask = u64::from(!Self::HARDENED);
+        let eth_address_1 = (low & mask) as u32;
+        let eth_address_2 = ((low >> 31) & mask) as u32;
+        Some(Self {
+            layer: hash_31_bits(layer),
+            application: hash_31_bits(application),
+            eth_address_1,
+            eth_address_2,
+            index,
+        })
+    }
+
+    /// Returns BIP-32 child indexes of the path, with the hardened bit set where appropriate
+    pub fn to_child_indexes(&self) -> [u32; 6] {
+        [
+            Self::PURPOSE | Self::HARDENED,
+            self.layer | Self::HARDENED,
+            self.application | Self::HARDENED,
+            self.eth_address_1 | Self::HARDENED,
+            self.eth_address_2 | Self::HARDENED,
+            self.index,
+        ]
+    }
+}
+
+impl fmt::Display for Eip2645Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "m/{}'/{}'/{}'/{}'/{}'/{}",
+            Self::PURPOSE,
+            self.layer,
+            self.application,
+            self.eth_address_1,
+            self.eth_address_2,
+            self.index
+        )
+    }
+}
+
+/// Returns lowest 31 bits of sha256 of the input
+fn hash_31_bits(input: &[u8]) -> u32 {
+    let digest = Sha256::digest(input);
+    let mut low = [0u8; 4];
+    low.copy_from_slice(&digest[28..]);
+    u32::from_be_bytes(low) & !Eip2645Path::HARDENED
+}
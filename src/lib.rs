@@ -2,6 +2,11 @@
 #![no_std]
 #![deny(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 pub use primeorder::{
     self,
     elliptic_curve::{
@@ -21,8 +26,32 @@ use primeorder::PrimeCurveParams;
 
 use self::core::{field_element::FieldElementCore, scalar::ScalarCore, W};
 
+pub mod account;
+#[cfg(feature = "alloc")]
+pub mod block;
+pub mod cairo;
+pub mod commitment;
 pub mod constants;
+#[cfg(feature = "alloc")]
+pub mod contract_class;
 pub mod core;
+#[cfg(feature = "alloc")]
+pub mod frost;
+pub mod generators;
+pub mod hash;
+pub mod key_derivation;
+pub mod messaging;
+pub mod musig2;
+pub mod schnorr;
+#[cfg(feature = "alloc")]
+pub mod secret_sharing;
+pub mod starkex;
+pub mod storage;
+pub mod transaction;
+pub mod trie;
+#[cfg(feature = "alloc")]
+pub mod typed_data;
+pub mod types;
 
 /// Field element (unsigned integer mod $p$)
 pub type FieldElement = W<FieldElementCore>;
@@ -65,6 +94,28 @@ impl PrimeCurveParams for StarkCurve {
 
 impl elliptic_curve::FieldBytesEncoding<StarkCurve> for U256 {}
 
+/// Returns affine coordinates $(x, y)$ of the point, or `None` if it's a point at infinity
+pub(crate) fn affine_coordinates(point: &AffinePoint) -> Option<(FieldElement, FieldElement)> {
+    use elliptic_curve::{sec1::ToEncodedPoint, PrimeField};
+
+    let encoded = point.to_encoded_point(false);
+    let x = FieldElement::from_repr(*encoded.x()?);
+    let y = FieldElement::from_repr(*encoded.y()?);
+    Option::from(x.and_then(|x| y.map(|y| (x, y))))
+}
+
+/// Constructs a point from affine coordinates $(x, y)$, or returns `None` if it's not on the curve
+pub(crate) fn point_from_coordinates(x: &FieldElement, y: &FieldElement) -> Option<AffinePoint> {
+    use elliptic_curve::{
+        sec1::{EncodedPoint, FromEncodedPoint},
+        PrimeField,
+    };
+
+    let encoded =
+        EncodedPoint::<StarkCurve>::from_affine_coordinates(&x.to_repr(), &y.to_repr(), false);
+    AffinePoint::from_encoded_point(&encoded).into()
+}
+
 impl From<Scalar> for ScalarPrimitive<StarkCurve> {
     fn from(s: Scalar) -> Self {
         ScalarPrimitive::from_uint_unchecked(s.to_uint())
@@ -0,0 +1,216 @@
+//! L1 ↔ L2 messaging
+//!
+//! Messages between Ethereum and Starknet are tracked by the Starknet core contract on L1 by their
+//! Keccak-256 hashes. Hash is taken over the message encoded as a sequence of 32-byte big-endian words,
+//! the same way Solidity's `abi.encodePacked` encodes `uint256` values:
+//!
+//! * [`L1ToL2Message`] is sent by an Ethereum contract and consumed by an L1 handler on Starknet
+//! * [`L2ToL1Message`] is sent by a Starknet contract and consumed by an Ethereum contract
+//!
+//! ```rust
+//! use stark_curve::messaging::{EthAddress, L2ToL1Message};
+//! use stark_curve::FieldElement;
+//!
+//! let recipient: EthAddress = "0xb6dbfaa86bb683152e4fc2401260f9ca249519c0".parse().unwrap();
+//! let msg = L2ToL1Message {
+//!     from_address: FieldElement::from(0x1234),
+//!     to_address: recipient,
+//!     payload: &[FieldElement::from(42)],
+//! };
+//! let msg_hash: [u8; 32] = msg.hash();
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use sha3::{Digest, Keccak256};
+
+use crate::FieldElement;
+
+/// Ethereum address, 160-bit integer
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EthAddress([u8; 20]);
+
+impl EthAddress {
+    /// Constructs address from its big-endian bytes
+    pub const fn from_bytes(bytes: [u8; 20]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns big-endian bytes of the address
+    pub const fn to_bytes(&self) -> [u8; 20] {
+        self.0
+    }
+
+    /// Returns the address as a 32-byte big-endian word
+    fn to_word(self) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[12..].copy_from_slice(&self.0);
+        word
+    }
+}
+
+impl From<[u8; 20]> for EthAddress {
+    fn from(bytes: [u8; 20]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<EthAddress> for [u8; 20] {
+    fn from(address: EthAddress) -> Self {
+        address.0
+    }
+}
+
+impl From<EthAddress> for FieldElement {
+    fn from(address: EthAddress) -> Self {
+        FieldElement::from_be_bytes_mod_order(&address.0)
+    }
+}
+
+/// Fails if the field element is not less than $2^{160}$
+impl TryFrom<FieldElement> for EthAddress {
+    type Error = Error;
+
+    fn try_from(x: FieldElement) -> Result<Self, Self::Error> {
+        let bytes: [u8; 32] = x.to_be_bytes().into();
+        let (high, low) = bytes.split_at(12);
+        if high.iter().any(|&b| b != 0) {
+            return Err(Error(Reason::OutOfRange));
+        }
+        let mut address = [0u8; 20];
+        address.copy_from_slice(low);
+        Ok(Self(address))
+    }
+}
+
+/// Parses address as decimal or `0x`-prefixed hex number less than $2^{160}$
+impl FromStr for EthAddress {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let x = FieldElement::from_str(s).map_err(|_| Error(Reason::Parse))?;
+        Self::try_from(x)
+    }
+}
+
+/// Formats address as `0x`-prefixed hex number with 40 digits
+impl fmt::Display for EthAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for EthAddress {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EthAddress {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::types::deserialize_from_str(deserializer, "Ethereum address")
+    }
+}
+
+/// Message sent from Ethereum to a Starknet contract
+#[derive(Clone, Copy, Debug)]
+pub struct L1ToL2Message<'a> {
+    /// Ethereum contract which sent the message
+    pub from_address: EthAddress,
+    /// Starknet contract handling the message
+    pub to_address: FieldElement,
+    /// Selector of the L1 handler
+    pub selector: FieldElement,
+    /// Calldata of the L1 handler
+    pub payload: &'a [FieldElement],
+    /// Nonce assigned to the message by the core contract
+    pub nonce: u64,
+}
+
+impl L1ToL2Message<'_> {
+    /// Computes the message hash
+    ///
+    /// $$
+    /// \text{keccak256}(\text{from\\_address}, \text{to\\_address}, \text{nonce}, \text{selector},
+    ///     \text{len}(\text{payload}), \text{payload}_1, \dots, \text{payload}_n)
+    /// $$
+    ///
+    /// where every value is encoded as a 32-byte big-endian word.
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.from_address.to_word());
+        hasher.update(self.to_address.to_be_bytes());
+        hasher.update(u64_word(self.nonce));
+        hasher.update(self.selector.to_be_bytes());
+        hash_payload(hasher, self.payload)
+    }
+}
+
+/// Message sent from a Starknet contract to Ethereum
+#[derive(Clone, Copy, Debug)]
+pub struct L2ToL1Message<'a> {
+    /// Starknet contract which sent the message
+    pub from_address: FieldElement,
+    /// Ethereum contract consuming the message
+    pub to_address: EthAddress,
+    /// Content of the message
+    pub payload: &'a [FieldElement],
+}
+
+impl L2ToL1Message<'_> {
+    /// Computes the message hash
+    ///
+    /// $$
+    /// \text{keccak256}(\text{from\\_address}, \text{to\\_address}, \text{len}(\text{payload}),
+    ///     \text{payload}_1, \dots, \text{payload}_n)
+    /// $$
+    ///
+    /// where every value is encoded as a 32-byte big-endian word.
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.from_address.to_be_bytes());
+        hasher.update(self.to_address.to_word());
+        hash_payload(hasher, self.payload)
+    }
+}
+
+/// Appends length of the payload and the payload to the hasher, returns the hash
+fn hash_payload(mut hasher: Keccak256, payload: &[FieldElement]) -> [u8; 32] {
+    hasher.update(u64_word(payload.len() as u64));
+    for x in payload {
+        hasher.update(x.to_be_bytes());
+    }
+    hasher.finalize().into()
+}
+
+fn u64_word(x: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&x.to_be_bytes());
+    word
+}
+
+/// Invalid Ethereum address
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error(Reason);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Reason {
+    Parse,
+    OutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Reason::Parse => f.write_str("address is not a valid integer"),
+            Reason::OutOfRange => f.write_str("address must be less than 2^160"),
+        }
+    }
+}
@@ -0,0 +1,392 @@
+//! MuSig2 multi-signatures
+//!
+//! Implements [MuSig2] $n$-out-of-$n$ multi-signature scheme following the structure of [BIP-327], adapted to
+//! Stark curve and [Schnorr signatures](crate::schnorr) defined in this crate. Signers aggregate their public keys
+//! into a single key $\tilde P$, and jointly produce a signature that is verifiable by
+//! [`VerifyingKey::verify`](crate::schnorr::VerifyingKey::verify) under $\tilde P$. Nobody learns secret keys of
+//! other signers.
+//!
+//! Protocol:
+//! 1. Signers agree on a list of public keys and construct [`KeyAggContext`]
+//! 2. Each signer generates a [`SecretNonce`] and sends corresponding [`PublicNonce`] to others. This
+//!    can be done before the message is known.
+//! 3. Public nonces are summed up into [`AggregatedNonce`], and each signer starts a [`Session`] for the message
+//! 4. Each signer produces a [`PartialSignature`] and sends it to others (or to the aggregator)
+//! 5. Partial signatures are aggregated into a [`Signature`]
+//!
+//! Key aggregation coefficients are derived via [Poseidon](crate::hash::poseidon) hash, so aggregated key can be
+//! cheaply computed in Cairo. Nonce coefficient is derived via [`MuSig2Hash`], which is implemented for challenges
+//! defined in [`schnorr`](crate::schnorr) module.
+//!
+//! ```rust
+//! use stark_curve::musig2::{AggregatedNonce, KeyAggContext, SecretNonce, Session};
+//! use stark_curve::schnorr::{PoseidonChallenge, SigningKey};
+//! use stark_curve::FieldElement;
+//!
+//! let mut rng = rand::rngs::OsRng;
+//! let alice = SigningKey::random(&mut rng);
+//! let bob = SigningKey::random(&mut rng);
+//! let message = [FieldElement::from(1337)];
+//!
+//! let ctx = KeyAggContext::new(&[*alice.verifying_key(), *bob.verifying_key()])?;
+//!
+//! let (alice_secnonce, alice_nonce) = SecretNonce::generate(&mut rng);
+//! let (bob_secnonce, bob_nonce) = SecretNonce::generate(&mut rng);
+//! let agg_nonce = AggregatedNonce::sum(&[alice_nonce, bob_nonce]);
+//!
+//! let session = Session::new::<PoseidonChallenge>(&ctx, &agg_nonce, &message)?;
+//! let alice_sig = session.partial_sign(alice_secnonce, &alice)?;
+//! let bob_sig = session.partial_sign(bob_secnonce, &bob)?;
+//!
+//! session.verify_partial(&bob_sig, &bob_nonce, bob.verifying_key())?;
+//! let signature = session.aggregate(&[alice_sig, bob_sig]);
+//!
+//! ctx.aggregated_key()
+//!     .verify::<PoseidonChallenge>(&message, &signature)
+//!     .unwrap();
+//! # Ok::<_, stark_curve::musig2::Error>(())
+//! ```
+//!
+//! [MuSig2]: https://eprint.iacr.org/2020/1261
+//! [BIP-327]: https://github.com/bitcoin/bips/blob/master/bip-0327.mediawiki
+
+use core::fmt;
+
+use subtle::ConstantTimeEq;
+
+use crate::elliptic_curve::{group::Group, rand_core::RngCore, Field, PrimeField};
+use crate::hash::poseidon;
+use crate::schnorr::{ChallengeHash, PoseidonChallenge, Signature, SigningKey, VerifyingKey};
+use crate::{AffinePoint, FieldElement, ProjectivePoint, Scalar};
+
+/// Hash functions used by MuSig2 on top of [`ChallengeHash`]
+pub trait MuSig2Hash: ChallengeHash {
+    /// Computes nonce coefficient $b = H_\text{non}(\tilde P, R_1, R_2, m)$
+    ///
+    /// Returns error if hash function cannot produce a scalar
+    fn nonce_coefficient(
+        aggregated_key: &AffinePoint,
+        r1: &AffinePoint,
+        r2: &AffinePoint,
+        message: &Self::Message,
+    ) -> Result<Scalar, Self::Error>;
+}
+
+/// $b = \text{poseidon}(\texttt{"musig2/noncecoef"}, \tilde P_x, \tilde P_y, R_{1,x}, R_{1,y}, R_{2,x}, R_{2,y}, m_1, \dots, m_k) \bmod n$
+///
+/// The tag is encoded as Cairo short string. Points at infinity are encoded as $(0, 0)$.
+impl MuSig2Hash for PoseidonChallenge {
+    fn nonce_coefficient(
+        aggregated_key: &AffinePoint,
+        r1: &AffinePoint,
+        r2: &AffinePoint,
+        message: &[FieldElement],
+    ) -> Result<Scalar, Self::Error> {
+        let hasher = poseidon::Hasher::new()
+            .chain(FieldElement::from_be_bytes_mod_order(b"musig2/noncecoef"))
+            .chain_point(aggregated_key)
+            .chain_point(r1)
+            .chain_point(r2);
+        let b = message.iter().fold(hasher, |h, m| h.chain(*m)).finalize();
+        Ok(Scalar::from_be_bytes_mod_order(&b.to_repr()))
+    }
+}
+
+/// $b = \text{hash\\_to\\_field}(\bar{\tilde P} \\| \bar R_1 \\| \bar R_2 \\| m)$ with domain separation tag
+/// `"stark-curve/musig2/noncecoef"`, where points are encoded in compressed SEC1 form
+#[cfg(feature = "hash2curve")]
+impl<X> MuSig2Hash for crate::schnorr::ExpandMsgChallenge<X>
+where
+    X: for<'a> crate::elliptic_curve::hash2curve::ExpandMsg<'a>,
+{
+    fn nonce_coefficient(
+        aggregated_key: &AffinePoint,
+        r1: &AffinePoint,
+        r2: &AffinePoint,
+        message: &[u8],
+    ) -> Result<Scalar, Self::Error> {
+        use crate::elliptic_curve::sec1::ToEncodedPoint;
+
+        let aggregated_key = aggregated_key.to_encoded_point(true);
+        let r1 = r1.to_encoded_point(true);
+        let r2 = r2.to_encoded_point(true);
+
+        let mut b = [Scalar::ZERO];
+        crate::elliptic_curve::hash2curve::hash_to_field::<X, _>(
+            &[
+                aggregated_key.as_bytes(),
+                r1.as_bytes(),
+                r2.as_bytes(),
+                message,
+            ],
+            &[b"stark-curve/musig2/noncecoef"],
+            &mut b,
+        )?;
+        Ok(b[0])
+    }
+}
+
+/// Key aggregation context
+///
+/// Aggregated key is $\tilde P = \sum_i a_i \cdot P_i$, where key aggregation coefficients are
+/// * $L = \text{poseidon}(\texttt{"musig2/keyagg\\_list"}, P_{1,x}, P_{1,y}, \dots, P_{k,x}, P_{k,y})$
+/// * $a_i = \text{poseidon}(\texttt{"musig2/keyagg\\_coef"}, L, P_{i,x}, P_{i,y}) \bmod n$
+///
+/// Order of the keys matters: all signers must use the same order. If the same key appears in the list
+/// several times, its owner needs to produce a partial signature for each occurrence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyAggContext {
+    keys_hash: FieldElement,
+    aggregated_key: VerifyingKey,
+}
+
+impl KeyAggContext {
+    /// Aggregates public keys
+    ///
+    /// Returns error if list is empty or aggregated key is a point at infinity
+    pub fn new(keys: &[VerifyingKey]) -> Result<Self, Error> {
+        if keys.is_empty() {
+            return Err(Error(Reason::NoKeys));
+        }
+
+        let hasher = poseidon::Hasher::new()
+            .chain(FieldElement::from_be_bytes_mod_order(b"musig2/keyagg_list"));
+        let keys_hash = keys
+            .iter()
+            .fold(hasher, |h, key| h.chain_point(key.as_point()))
+            .finalize();
+
+        let aggregated_key = keys.iter().fold(ProjectivePoint::IDENTITY, |sum, key| {
+            sum + *key.as_point() * coefficient(&keys_hash, key)
+        });
+        let aggregated_key = VerifyingKey::from_point(aggregated_key.to_affine())
+            .ok_or(Error(Reason::AggregatedKeyIsIdentity))?;
+
+        Ok(Self {
+            keys_hash,
+            aggregated_key,
+        })
+    }
+
+    /// Returns aggregated key $\tilde P$
+    pub fn aggregated_key(&self) -> &VerifyingKey {
+        &self.aggregated_key
+    }
+
+    /// Returns key aggregation coefficient $a_i$ of the key $P_i$
+    ///
+    /// Doesn't check that key was in the list of aggregated keys.
+    pub fn key_coefficient(&self, key: &VerifyingKey) -> Scalar {
+        coefficient(&self.keys_hash, key)
+    }
+}
+
+fn coefficient(keys_hash: &FieldElement, key: &VerifyingKey) -> Scalar {
+    let hasher = poseidon::Hasher::new()
+        .chain(FieldElement::from_be_bytes_mod_order(b"musig2/keyagg_coef"))
+        .chain(*keys_hash)
+        .chain_point(key.as_point());
+    let a = hasher.finalize();
+    Scalar::from_be_bytes_mod_order(&a.to_repr())
+}
+
+/// Secret nonce $(k_1, k_2)$
+///
+/// Nonce must never be reused, so it's consumed when producing a partial signature, cannot be cloned,
+/// and is zeroized on drop.
+pub struct SecretNonce {
+    k1: Scalar,
+    k2: Scalar,
+}
+
+impl SecretNonce {
+    /// Generates a random secret nonce, returns it along with corresponding public nonce
+    pub fn generate(rng: &mut impl RngCore) -> (Self, PublicNonce) {
+        let nonce = Self {
+            k1: Scalar::random(&mut *rng),
+            k2: Scalar::random(&mut *rng),
+        };
+        let public = nonce.public_nonce();
+        (nonce, public)
+    }
+
+    /// Returns corresponding public nonce
+    pub fn public_nonce(&self) -> PublicNonce {
+        PublicNonce {
+            r1: (ProjectivePoint::GENERATOR * self.k1).to_affine(),
+            r2: (ProjectivePoint::GENERATOR * self.k2).to_affine(),
+        }
+    }
+}
+
+impl fmt::Debug for SecretNonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretNonce").finish_non_exhaustive()
+    }
+}
+
+impl Drop for SecretNonce {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.k1);
+        zeroize::Zeroize::zeroize(&mut self.k2);
+    }
+}
+
+/// Public nonce $(R_1, R_2) = (k_1 \cdot G, k_2 \cdot G)$
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicNonce {
+    /// $R_1$
+    pub r1: AffinePoint,
+    /// $R_2$
+    pub r2: AffinePoint,
+}
+
+/// Sum of public nonces of all signers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AggregatedNonce {
+    /// $R_1 = \sum_i R_{1,i}$
+    pub r1: AffinePoint,
+    /// $R_2 = \sum_i R_{2,i}$
+    pub r2: AffinePoint,
+}
+
+impl AggregatedNonce {
+    /// Sums up public nonces of all signers
+    pub fn sum(nonces: &[PublicNonce]) -> Self {
+        let (r1, r2) = nonces.iter().fold(
+            (ProjectivePoint::IDENTITY, ProjectivePoint::IDENTITY),
+            |(r1, r2), nonce| (r1 + nonce.r1, r2 + nonce.r2),
+        );
+        Self {
+            r1: r1.to_affine(),
+            r2: r2.to_affine(),
+        }
+    }
+}
+
+/// Signing session for a specific message
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Session {
+    ctx: KeyAggContext,
+    b: Scalar,
+    r: AffinePoint,
+    e: Scalar,
+}
+
+impl Session {
+    /// Starts a signing session
+    ///
+    /// Computes nonce coefficient $b$, final nonce $R = R_1 + b \cdot R_2$, and challenge
+    /// $e = H(R, \tilde P, m)$. If $R$ is a point at infinity, it's replaced with generator, following
+    /// BIP-327 (this can only happen if some signers are malicious, and results into invalid signature).
+    ///
+    /// Returns error if hash function fails to produce a scalar.
+    pub fn new<H: MuSig2Hash>(
+        ctx: &KeyAggContext,
+        aggregated_nonce: &AggregatedNonce,
+        message: &H::Message,
+    ) -> Result<Self, Error> {
+        let aggregated_key = ctx.aggregated_key.as_point();
+        let b = H::nonce_coefficient(
+            aggregated_key,
+            &aggregated_nonce.r1,
+            &aggregated_nonce.r2,
+            message,
+        )
+        .map_err(|_| Error(Reason::HashFailed))?;
+        let r = ProjectivePoint::from(aggregated_nonce.r1) + aggregated_nonce.r2 * b;
+        let r = if bool::from(r.is_identity()) {
+            AffinePoint::GENERATOR
+        } else {
+            r.to_affine()
+        };
+        let e = H::challenge(&r, aggregated_key, message).map_err(|_| Error(Reason::HashFailed))?;
+
+        Ok(Self { ctx: *ctx, b, r, e })
+    }
+
+    /// Returns final nonce $R$
+    pub fn final_nonce(&self) -> &AffinePoint {
+        &self.r
+    }
+
+    /// Produces partial signature $s_i = k_1 + b \cdot k_2 + e \cdot a_i \cdot x_i$
+    ///
+    /// Verifies the partial signature before returning it, returns error if it's invalid
+    /// (which may indicate a fault during computation).
+    pub fn partial_sign(
+        &self,
+        nonce: SecretNonce,
+        signing_key: &SigningKey,
+    ) -> Result<PartialSignature, Error> {
+        let a = self.ctx.key_coefficient(signing_key.verifying_key());
+        let s = nonce.k1 + self.b * nonce.k2 + self.e * a * signing_key.as_scalar();
+        let partial_signature = PartialSignature(s);
+
+        self.verify_partial(
+            &partial_signature,
+            &nonce.public_nonce(),
+            signing_key.verifying_key(),
+        )?;
+        Ok(partial_signature)
+    }
+
+    /// Verifies partial signature of a signer with `public_nonce` and `public_key`
+    ///
+    /// Checks $s_i \cdot G = R_{1,i} + b \cdot R_{2,i} + e \cdot a_i \cdot P_i$
+    pub fn verify_partial(
+        &self,
+        partial_signature: &PartialSignature,
+        public_nonce: &PublicNonce,
+        public_key: &VerifyingKey,
+    ) -> Result<(), Error> {
+        let a = self.ctx.key_coefficient(public_key);
+        let lhs = ProjectivePoint::GENERATOR * partial_signature.0;
+        let rhs = ProjectivePoint::from(public_nonce.r1)
+            + public_nonce.r2 * self.b
+            + *public_key.as_point() * (self.e * a);
+        if bool::from(lhs.ct_eq(&rhs)) {
+            Ok(())
+        } else {
+            Err(Error(Reason::InvalidPartialSignature))
+        }
+    }
+
+    /// Aggregates partial signatures into signature $(R, \sum_i s_i)$
+    ///
+    /// Resulting signature is valid if all partial signatures are valid. Partial signatures
+    /// are not verified by this method, use [`Session::verify_partial`] to identify misbehaving signers.
+    pub fn aggregate(&self, partial_signatures: &[PartialSignature]) -> Signature {
+        Signature {
+            r: self.r,
+            s: partial_signatures.iter().map(|s| s.0).sum(),
+        }
+    }
+}
+
+/// Partial signature $s_i$
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartialSignature(pub Scalar);
+
+/// MuSig2 error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Error(Reason);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Reason {
+    NoKeys,
+    AggregatedKeyIsIdentity,
+    InvalidPartialSignature,
+    HashFailed,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Reason::NoKeys => f.write_str("list of keys to aggregate is empty"),
+            Reason::AggregatedKeyIsIdentity => f.write_str("aggregated key is point at infinity"),
+            Reason::InvalidPartialSignature => f.write_str("invalid partial signature"),
+            Reason::HashFailed => f.write_str("hash function failed to produce a scalar"),
+        }
+    }
+}
@@ -0,0 +1,327 @@
+//! Schnorr signatures
+//!
+//! Signature of message $m$ under public key $P = x \cdot G$ is a pair $(R, s)$, where $R = k \cdot G$ for random
+//! nonce $k$, $s = k + e \cdot x$, and $e = H(R, P, m)$ is a challenge. Signature is valid if
+//! $s \cdot G = R + e \cdot P$. The challenge is key-prefixed, i.e. it commits to the public key the
+//! signature is verified against.
+//!
+//! Challenge hash $H$ is configurable via [`ChallengeHash`] trait. [`PoseidonChallenge`] signs messages
+//! consisting of field elements and can be cheaply verified inside Cairo. [`ExpandMsgChallenge`] signs
+//! arbitrary bytes using a standard hash function, e.g. SHA-256.
+//!
+//! ```rust
+//! use stark_curve::schnorr::{PoseidonChallenge, SigningKey};
+//! use stark_curve::FieldElement;
+//!
+//! let mut rng = rand::rngs::OsRng;
+//! let signing_key = SigningKey::random(&mut rng);
+//!
+//! let message = [FieldElement::from(1), FieldElement::from(2)];
+//! let Ok(signature) = signing_key.sign::<PoseidonChallenge>(&message, &mut rng);
+//!
+//! signing_key
+//!     .verifying_key()
+//!     .verify::<PoseidonChallenge>(&message, &signature)?;
+//! # Ok::<_, stark_curve::schnorr::InvalidSignature>(())
+//! ```
+
+use core::fmt;
+
+use subtle::ConstantTimeEq;
+
+use crate::elliptic_curve::{
+    group::Group,
+    rand_core::RngCore,
+    sec1::{EncodedPoint, FromEncodedPoint, ToEncodedPoint},
+    Field, PrimeField,
+};
+use crate::hash::poseidon;
+use crate::{AffinePoint, FieldElement, ProjectivePoint, Scalar, StarkCurve};
+
+/// Challenge hash $e = H(R, P, m)$
+pub trait ChallengeHash {
+    /// Message being signed
+    type Message: ?Sized;
+    /// Error returned if hash function cannot produce a scalar (e.g. message expander rejects the
+    /// output length)
+    type Error;
+
+    /// Computes a challenge for nonce commitment `r`, `public_key`, and `message`
+    fn challenge(
+        r: &AffinePoint,
+        public_key: &AffinePoint,
+        message: &Self::Message,
+    ) -> Result<Scalar, Self::Error>;
+}
+
+/// Poseidon-based challenge
+///
+/// $e = \text{poseidon}(R_x, R_y, P_x, P_y, m_1, \dots, m_k) \bmod n$, where message $m$ is a list of field
+/// elements. Point at infinity is encoded as $(0, 0)$.
+///
+/// Field elements are reduced modulo $n$; since $p - n < 2^{127}$, the bias is negligible.
+#[derive(Clone, Copy, Debug)]
+pub struct PoseidonChallenge;
+
+impl ChallengeHash for PoseidonChallenge {
+    type Message = [FieldElement];
+    type Error = core::convert::Infallible;
+
+    fn challenge(
+        r: &AffinePoint,
+        public_key: &AffinePoint,
+        message: &[FieldElement],
+    ) -> Result<Scalar, Self::Error> {
+        let (r_x, r_y) = crate::affine_coordinates(r).unwrap_or_default();
+        let (p_x, p_y) = crate::affine_coordinates(public_key).unwrap_or_default();
+
+        let hasher = poseidon::Hasher::new()
+            .chain(r_x)
+            .chain(r_y)
+            .chain(p_x)
+            .chain(p_y);
+        let e = message.iter().fold(hasher, |h, m| h.chain(*m)).finalize();
+
+        Ok(Scalar::from_be_bytes_mod_order(&e.to_repr()))
+    }
+}
+
+/// Challenge based on [`hash_to_field`](crate::elliptic_curve::hash2curve::hash_to_field)
+///
+/// $e = \text{hash\\_to\\_field}(\bar R \\| \bar P \\| m)$, where $\bar R$ and $\bar P$ are compressed SEC1 encodings
+/// of the points, and message $m$ is arbitrary bytes. Message expander `X` is, for instance,
+/// [`ExpandMsgXmd<Sha256>`](crate::elliptic_curve::hash2curve::ExpandMsgXmd), domain separation tag is
+/// [`ExpandMsgChallenge::DST`].
+#[cfg(feature = "hash2curve")]
+#[derive(Clone, Copy, Debug)]
+pub struct ExpandMsgChallenge<X>(core::marker::PhantomData<X>);
+
+#[cfg(feature = "hash2curve")]
+impl<X> ExpandMsgChallenge<X> {
+    /// Domain separation tag
+    pub const DST: &'static [u8] = b"stark-curve/schnorr/challenge";
+}
+
+#[cfg(feature = "hash2curve")]
+impl<X> ChallengeHash for ExpandMsgChallenge<X>
+where
+    X: for<'a> crate::elliptic_curve::hash2curve::ExpandMsg<'a>,
+{
+    type Message = [u8];
+    type Error = crate::elliptic_curve::Error;
+
+    fn challenge(
+        r: &AffinePoint,
+        public_key: &AffinePoint,
+        message: &[u8],
+    ) -> Result<Scalar, Self::Error> {
+        let r = r.to_encoded_point(true);
+        let public_key = public_key.to_encoded_point(true);
+
+        let mut e = [Scalar::ZERO];
+        crate::elliptic_curve::hash2curve::hash_to_field::<X, _>(
+            &[r.as_bytes(), public_key.as_bytes(), message],
+            &[Self::DST],
+            &mut e,
+        )?;
+        Ok(e[0])
+    }
+}
+
+/// Schnorr signature $(R, s)$
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    /// Nonce commitment $R$
+    pub r: AffinePoint,
+    /// Response $s$
+    pub s: Scalar,
+}
+
+impl Signature {
+    /// Size of serialized signature in bytes
+    pub const SIZE: usize = 65;
+
+    /// Serializes signature as compressed SEC1 encoding of $R$ followed by big-endian encoding of $s$
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        let r = self.r.to_encoded_point(true);
+        // `R` is never a point at infinity in a valid signature, if it is, we leave bytes zeroed which
+        // cannot be parsed back
+        if r.as_bytes().len() == 33 {
+            bytes[..33].copy_from_slice(r.as_bytes());
+        }
+        bytes[33..].copy_from_slice(&self.s.to_repr());
+        bytes
+    }
+
+    /// Deserializes signature serialized via [`Signature::to_bytes`]
+    ///
+    /// Returns `None` if bytes do not encode a valid point and scalar
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Option<Self> {
+        // Only compressed encoding is allowed, but `0x05` (compact) tag would also be accepted by the parser
+        if !matches!(bytes[0], 0x02 | 0x03) {
+            return None;
+        }
+        let r = EncodedPoint::<StarkCurve>::from_bytes(&bytes[..33]).ok()?;
+        let r = Option::from(AffinePoint::from_encoded_point(&r))?;
+        let s = Option::from(Scalar::from_repr(bytes[33..].iter().copied().collect()))?;
+        Some(Self { r, s })
+    }
+}
+
+/// Secret key $x$ used to produce signatures
+///
+/// Secret is zeroized on drop.
+#[derive(Clone)]
+pub struct SigningKey {
+    secret: Scalar,
+    verifying_key: VerifyingKey,
+}
+
+impl SigningKey {
+    /// Generates a random signing key
+    pub fn random(rng: &mut impl RngCore) -> Self {
+        loop {
+            if let Some(key) = Self::from_scalar(Scalar::random(&mut *rng)) {
+                return key;
+            }
+        }
+    }
+
+    /// Constructs a signing key from a secret scalar
+    ///
+    /// Returns `None` if secret is zero
+    pub fn from_scalar(secret: Scalar) -> Option<Self> {
+        if bool::from(secret.is_zero()) {
+            return None;
+        }
+        let public_key = (ProjectivePoint::GENERATOR * secret).to_affine();
+        Some(Self {
+            secret,
+            verifying_key: VerifyingKey(public_key),
+        })
+    }
+
+    /// Returns secret scalar $x$
+    pub fn as_scalar(&self) -> &Scalar {
+        &self.secret
+    }
+
+    /// Returns corresponding public key $P = x \cdot G$
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Signs a message
+    ///
+    /// Returns error if challenge hash fails, see [`ChallengeHash::Error`]
+    pub fn sign<H: ChallengeHash>(
+        &self,
+        message: &H::Message,
+        rng: &mut impl RngCore,
+    ) -> Result<Signature, H::Error> {
+        let k = zeroize::Zeroizing::new(Scalar::random(rng));
+        let r = (ProjectivePoint::GENERATOR * *k).to_affine();
+        let e = H::challenge(&r, &self.verifying_key.0, message)?;
+        let s = *k + e * self.secret;
+        Ok(Signature { r, s })
+    }
+}
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("verifying_key", &self.verifying_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.secret)
+    }
+}
+
+/// Public key $P$ used to verify signatures
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifyingKey(AffinePoint);
+
+impl VerifyingKey {
+    /// Constructs a verifying key from a point
+    ///
+    /// Returns `None` if point is at infinity
+    pub fn from_point(point: AffinePoint) -> Option<Self> {
+        if bool::from(point.is_identity()) {
+            None
+        } else {
+            Some(Self(point))
+        }
+    }
+
+    /// Returns public key $P$
+    pub fn as_point(&self) -> &AffinePoint {
+        &self.0
+    }
+
+    /// Verifies a signature
+    pub fn verify<H: ChallengeHash>(
+        &self,
+        message: &H::Message,
+        signature: &Signature,
+    ) -> Result<(), InvalidSignature> {
+        let e = H::challenge(&signature.r, &self.0, message).map_err(|_| InvalidSignature)?;
+        let lhs = ProjectivePoint::GENERATOR * signature.s;
+        let rhs = ProjectivePoint::from(signature.r) + self.0 * e;
+        if bool::from(lhs.ct_eq(&rhs)) {
+            Ok(())
+        } else {
+            Err(InvalidSignature)
+        }
+    }
+}
+
+impl From<VerifyingKey> for AffinePoint {
+    fn from(key: VerifyingKey) -> Self {
+        key.0
+    }
+}
+
+/// Verifies a batch of signatures
+///
+/// Checks $\left(\sum_i z_i s_i\right) \cdot G = \sum_i z_i \cdot R_i + z_i e_i \cdot P_i$ for random $z_i$,
+/// which is faster than verifying signatures one by one. Returns error if at least one of the signatures
+/// is invalid, without telling which one.
+pub fn verify_batch<H: ChallengeHash>(
+    items: &[(&VerifyingKey, &H::Message, &Signature)],
+    rng: &mut impl RngCore,
+) -> Result<(), InvalidSignature> {
+    let mut s_sum = Scalar::ZERO;
+    let mut rhs = ProjectivePoint::IDENTITY;
+    for (i, (public_key, message, signature)) in items.iter().enumerate() {
+        let z = if i == 0 {
+            Scalar::ONE
+        } else {
+            Scalar::random(&mut *rng)
+        };
+        let e = H::challenge(&signature.r, &public_key.0, message).map_err(|_| InvalidSignature)?;
+
+        s_sum += z * signature.s;
+        rhs += signature.r * z + public_key.0 * (z * e);
+    }
+
+    if bool::from((ProjectivePoint::GENERATOR * s_sum - rhs).is_identity()) {
+        Ok(())
+    } else {
+        Err(InvalidSignature)
+    }
+}
+
+/// Signature is invalid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidSignature;
+
+impl fmt::Display for InvalidSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid signature")
+    }
+}
@@ -0,0 +1,374 @@
+//! Shamir secret sharing over [`Scalar`]
+//!
+//! Secret $s$ is shared among $n$ parties with threshold $t$ by sampling random polynomial $f(x)$ of degree
+//! $t - 1$ such that $f(0) = s$. Party with index $i \ne 0$ receives share $f(i)$. Any $t$ shares can be
+//! used to reconstruct the secret via Lagrange interpolation, while fewer shares reveal nothing about it.
+//!
+//! Sharing can be made verifiable, so that parties can check that their shares are consistent with each
+//! other without learning the secret:
+//! * [Feldman VSS](FeldmanCommitment) publishes $A_k = a_k \cdot G$ for every coefficient of $f(x)$. It
+//!   reveals $s \cdot G$, which is desired when the secret is a signing key.
+//! * [Pedersen VSS](PedersenCommitment) additionally samples a blinding polynomial $g(x)$ and publishes
+//!   $C_k = a_k \cdot G + b_k \cdot H$. It's perfectly hiding, i.e. reveals nothing about the secret.
+//!
+//! ```rust
+//! use stark_curve::secret_sharing::{self, FeldmanCommitment};
+//! use stark_curve::{elliptic_curve::Field, Scalar};
+//!
+//! let mut rng = rand::rngs::OsRng;
+//! let secret = Scalar::random(&mut rng);
+//! let (shares, polynomial) = secret_sharing::split(secret, 2, 3, &mut rng)?;
+//! let commitment = FeldmanCommitment::new(&polynomial);
+//!
+//! assert!(shares.iter().all(|share| commitment.verify_share(share, 2)));
+//! assert_eq!(secret_sharing::reconstruct(&shares[1..]), Some(secret));
+//! # Ok::<_, secret_sharing::InvalidThreshold>(())
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use subtle::ConstantTimeEq;
+
+use crate::commitment::CommitmentKey;
+use crate::elliptic_curve::{rand_core::RngCore, Field};
+use crate::{ProjectivePoint, Scalar};
+
+/// Polynomial $f(x) = a_0 + a_1 x + \dots + a_{t-1} x^{t-1}$ over scalars
+#[derive(Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    coefficients: Vec<Scalar>,
+}
+
+impl Polynomial {
+    /// Constructs polynomial from coefficients $a_0, \dots, a_{t-1}$
+    pub fn from_coefficients(coefficients: Vec<Scalar>) -> Self {
+        Self { coefficients }
+    }
+
+    /// Samples random polynomial of given `degree` with free coefficient $a_0 =$ `constant_term`
+    pub fn sample_with_constant_term(
+        degree: usize,
+        constant_term: Scalar,
+        rng: &mut impl RngCore,
+    ) -> Self {
+        let coefficients = core::iter::once(constant_term)
+            .chain((0..degree).map(|_| Scalar::random(&mut *rng)))
+            .collect();
+        Self { coefficients }
+    }
+
+    /// Returns coefficients $a_0, \dots, a_{t-1}$
+    pub fn coefficients(&self) -> &[Scalar] {
+        &self.coefficients
+    }
+
+    /// Evaluates polynomial at point $x$
+    pub fn evaluate(&self, x: &Scalar) -> Scalar {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Scalar::ZERO, |acc, a| acc * x + a)
+    }
+}
+
+impl fmt::Debug for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Polynomial")
+            .field("degree", &self.coefficients.len().saturating_sub(1))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for Polynomial {
+    fn drop(&mut self) {
+        self.coefficients
+            .iter_mut()
+            .for_each(zeroize::Zeroize::zeroize)
+    }
+}
+
+/// Share $(i, f(i))$ of the secret
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Share {
+    /// Index $i$ of the share, must be non-zero
+    pub index: Scalar,
+    /// Value $f(i)$
+    pub value: Scalar,
+}
+
+/// Splits `secret` into `n` shares with threshold `t`
+///
+/// Shares have indexes $1, \dots, n$. Returns the shares along with the polynomial used for sharing,
+/// which can be used to commit to the shares. Returns error if $t = 0$ or $t > n$.
+pub fn split(
+    secret: Scalar,
+    t: u16,
+    n: u16,
+    rng: &mut impl RngCore,
+) -> Result<(Vec<Share>, Polynomial), InvalidThreshold> {
+    if t == 0 || t > n {
+        return Err(InvalidThreshold { t, n });
+    }
+    let polynomial = Polynomial::sample_with_constant_term(usize::from(t) - 1, secret, rng);
+    let shares = (1..=n)
+        .map(|i| {
+            let index = Scalar::from(u64::from(i));
+            Share {
+                index,
+                value: polynomial.evaluate(&index),
+            }
+        })
+        .collect();
+    Ok((shares, polynomial))
+}
+
+/// Computes Lagrange coefficient $\lambda_i(x) = \prod_{j \ne i} \frac{x - j}{i - j}$
+///
+/// `indexes` is the set of indexes of interpolated shares, it must contain `i`. Returns `None` if
+/// `indexes` contain duplicates or don't contain `i`.
+pub fn lagrange_coefficient(x: &Scalar, i: &Scalar, indexes: &[Scalar]) -> Option<Scalar> {
+    let mut numerator = Scalar::ONE;
+    let mut denominator = Scalar::ONE;
+    let mut found = false;
+    for (k, j) in indexes.iter().enumerate() {
+        if j == i {
+            if found {
+                return None;
+            }
+            found = true;
+            continue;
+        }
+        if indexes[..k].contains(j) {
+            return None;
+        }
+        numerator *= *x - j;
+        denominator *= *i - j;
+    }
+    if !found {
+        return None;
+    }
+    Option::from(denominator.invert()).map(|inv: Scalar| numerator * inv)
+}
+
+/// Interpolates polynomial defined by `shares` at point $x$
+///
+/// Returns $f(x)$ where $f$ is the unique polynomial of degree less than number of shares passing
+/// through all the shares. Returns `None` if indexes of the shares are not distinct.
+pub fn interpolate(x: &Scalar, shares: &[Share]) -> Option<Scalar> {
+    let indexes: Vec<_> = shares.iter().map(|s| s.index).collect();
+    shares.iter().try_fold(Scalar::ZERO, |acc, share| {
+        let lambda = lagrange_coefficient(x, &share.index, &indexes)?;
+        Some(acc + lambda * share.value)
+    })
+}
+
+/// Reconstructs the secret $f(0)$ from shares
+///
+/// Returns `None` if `shares` is empty, indexes of the shares are not distinct, or some index is zero.
+/// Reconstruction is correct only if there are at least $t$ shares.
+pub fn reconstruct(shares: &[Share]) -> Option<Scalar> {
+    if shares.is_empty() || shares.iter().any(|s| bool::from(s.index.is_zero())) {
+        return None;
+    }
+    interpolate(&Scalar::ZERO, shares)
+}
+
+/// Feldman commitment $A_0, \dots, A_{t-1}$ to polynomial $f(x)$, where $A_k = a_k \cdot G$
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeldmanCommitment {
+    coefficients: Vec<ProjectivePoint>,
+}
+
+impl FeldmanCommitment {
+    /// Commits to the polynomial
+    pub fn new(polynomial: &Polynomial) -> Self {
+        let coefficients = polynomial
+            .coefficients()
+            .iter()
+            .map(|a| ProjectivePoint::GENERATOR * a)
+            .collect();
+        Self { coefficients }
+    }
+
+    /// Constructs commitment from points $A_0, \dots, A_{t-1}$, e.g. received from the dealer
+    pub fn from_points(coefficients: Vec<ProjectivePoint>) -> Self {
+        Self { coefficients }
+    }
+
+    /// Returns points $A_0, \dots, A_{t-1}$
+    pub fn points(&self) -> &[ProjectivePoint] {
+        &self.coefficients
+    }
+
+    /// Returns commitment to the secret $A_0 = s \cdot G$
+    ///
+    /// Returns point at infinity if commitment is empty
+    pub fn public_key(&self) -> ProjectivePoint {
+        self.coefficients
+            .first()
+            .copied()
+            .unwrap_or(ProjectivePoint::IDENTITY)
+    }
+
+    /// Evaluates committed polynomial in the exponent, i.e. returns $f(x) \cdot G$
+    pub fn evaluate(&self, x: &Scalar) -> ProjectivePoint {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(ProjectivePoint::IDENTITY, |acc, a| acc * x + a)
+    }
+
+    /// Checks that `share` lies on the committed polynomial of degree $t - 1$
+    ///
+    /// Returns `false` if commitment doesn't consist of exactly $t$ points, i.e. the dealer committed to
+    /// a polynomial of different degree.
+    pub fn verify_share(&self, share: &Share, t: u16) -> bool {
+        if self.coefficients.len() != usize::from(t) {
+            return false;
+        }
+        (ProjectivePoint::GENERATOR * share.value)
+            .ct_eq(&self.evaluate(&share.index))
+            .into()
+    }
+}
+
+/// Share $(i, f(i), g(i))$ produced by [Pedersen VSS](split_pedersen)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PedersenShare {
+    /// Index $i$ of the share, must be non-zero
+    pub index: Scalar,
+    /// Value $f(i)$
+    pub value: Scalar,
+    /// Value of blinding polynomial $g(i)$
+    pub blinding: Scalar,
+}
+
+impl PedersenShare {
+    /// Returns share of the secret $(i, f(i))$
+    pub fn share(&self) -> Share {
+        Share {
+            index: self.index,
+            value: self.value,
+        }
+    }
+}
+
+/// Pedersen commitment $C_0, \dots, C_{t-1}$ to polynomial $f(x)$ blinded by polynomial $g(x)$, where
+/// $C_k = a_k \cdot G + b_k \cdot H$
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PedersenCommitment {
+    key: CommitmentKey,
+    coefficients: Vec<ProjectivePoint>,
+}
+
+impl PedersenCommitment {
+    /// Commits to polynomial $f(x)$ using blinding polynomial $g(x)$
+    ///
+    /// If $g(x)$ has fewer coefficients than $f(x)$, the remaining ones are treated as zeroes and vice versa.
+    pub fn new(key: CommitmentKey, polynomial: &Polynomial, blinding: &Polynomial) -> Self {
+        let a = polynomial.coefficients();
+        let b = blinding.coefficients();
+        let coefficients = (0..a.len().max(b.len()))
+            .map(|k| {
+                let a_k = a.get(k).copied().unwrap_or(Scalar::ZERO);
+                let b_k = b.get(k).copied().unwrap_or(Scalar::ZERO);
+                *key.commit(&a_k, &b_k).as_point()
+            })
+            .collect();
+        Self { key, coefficients }
+    }
+
+    /// Constructs commitment from points $C_0, \dots, C_{t-1}$, e.g. received from the dealer
+    pub fn from_points(key: CommitmentKey, coefficients: Vec<ProjectivePoint>) -> Self {
+        Self { key, coefficients }
+    }
+
+    /// Returns commitment key $(G, H)$
+    pub fn key(&self) -> &CommitmentKey {
+        &self.key
+    }
+
+    /// Returns points $C_0, \dots, C_{t-1}$
+    pub fn points(&self) -> &[ProjectivePoint] {
+        &self.coefficients
+    }
+
+    /// Evaluates committed polynomials in the exponent, i.e. returns $f(x) \cdot G + g(x) \cdot H$
+    pub fn evaluate(&self, x: &Scalar) -> ProjectivePoint {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(ProjectivePoint::IDENTITY, |acc, c| acc * x + c)
+    }
+
+    /// Checks that `share` lies on the committed polynomials of degree $t - 1$
+    ///
+    /// Returns `false` if commitment doesn't consist of exactly $t$ points, i.e. the dealer committed to
+    /// polynomials of different degree.
+    pub fn verify_share(&self, share: &PedersenShare, t: u16) -> bool {
+        if self.coefficients.len() != usize::from(t) {
+            return false;
+        }
+        self.key
+            .commit(&share.value, &share.blinding)
+            .as_point()
+            .ct_eq(&self.evaluate(&share.index))
+            .into()
+    }
+}
+
+/// Splits `secret` into `n` shares with threshold `t` using Feldman VSS
+///
+/// Same as [`split`], but returns commitment to the polynomial instead of the polynomial itself.
+pub fn split_feldman(
+    secret: Scalar,
+    t: u16,
+    n: u16,
+    rng: &mut impl RngCore,
+) -> Result<(Vec<Share>, FeldmanCommitment), InvalidThreshold> {
+    let (shares, polynomial) = split(secret, t, n, rng)?;
+    Ok((shares, FeldmanCommitment::new(&polynomial)))
+}
+
+/// Splits `secret` into `n` shares with threshold `t` using Pedersen VSS
+///
+/// Shares have indexes $1, \dots, n$. Discrete logarithm of $H$ relative to $G$ in the commitment `key`
+/// must be unknown, otherwise the dealer can distribute inconsistent shares.
+pub fn split_pedersen(
+    secret: Scalar,
+    t: u16,
+    n: u16,
+    key: CommitmentKey,
+    rng: &mut impl RngCore,
+) -> Result<(Vec<PedersenShare>, PedersenCommitment), InvalidThreshold> {
+    let (shares, polynomial) = split(secret, t, n, rng)?;
+    let blinding =
+        Polynomial::sample_with_constant_term(usize::from(t) - 1, Scalar::random(&mut *rng), rng);
+    let shares = shares
+        .into_iter()
+        .map(|share| PedersenShare {
+            index: share.index,
+            value: share.value,
+            blinding: blinding.evaluate(&share.index),
+        })
+        .collect();
+    Ok((shares, PedersenCommitment::new(key, &polynomial, &blinding)))
+}
+
+/// Threshold must satisfy $0 < t \le n$
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidThreshold {
+    t: u16,
+    n: u16,
+}
+
+impl fmt::Display for InvalidThreshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid threshold: t = {}, n = {}, expected 0 < t <= n",
+            self.t, self.n
+        )
+    }
+}
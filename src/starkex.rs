@@ -0,0 +1,346 @@
+//! StarkEx messages
+//!
+//! StarkEx users sign the hash of each order, transfer, and withdrawal. Numeric fields of the message are
+//! packed into one or two field elements, which are then hashed with [Pedersen hash](pedersen::hash)
+//! together with the assets involved, as specified in [starkex-resources].
+//!
+//! Messages come in two layouts: the original one of StarkEx Spot v1 ([`LimitOrder`], [`Transfer`]) with
+//! 31-bit vault ids and 63-bit amounts, and the one with fees used since StarkEx Spot v2 and in StarkEx
+//! Perpetual ([`LimitOrderWithFee`], [`TransferWithFee`], [`Withdrawal`]) with 64-bit ids and amounts.
+//!
+//! ```rust
+//! use stark_curve::{starkex, FieldElement};
+//!
+//! # fn main() -> Result<(), starkex::Error> {
+//! let order = starkex::LimitOrder {
+//!     vault_sell: 21,
+//!     vault_buy: 27,
+//!     amount_sell: 2154686749748910716,
+//!     amount_buy: 1470242115489520459,
+//!     token_sell: "0x5fa3383597691ea9d827a79e1a4f0f7989c35ced18ca9619de8ab97e661020".parse().unwrap(),
+//!     token_buy: "0x774961c824a3b0fb3d2965f01471c9c7734bf8dbde659e0c08dca2ef18d56a".parse().unwrap(),
+//!     nonce: 0,
+//!     expiration_timestamp: 438953,
+//! };
+//! let msg_hash = order.hash()?;
+//! # Ok(()) }
+//! ```
+//!
+//! [starkex-resources]: https://github.com/starkware-libs/starkex-resources
+
+use core::fmt;
+
+use crate::bigint::U256;
+use crate::hash::pedersen;
+use crate::FieldElement;
+
+/// Type of instruction, the first packed field of a message
+#[derive(Clone, Copy)]
+enum Instruction {
+    LimitOrder = 0,
+    Transfer = 1,
+    ConditionalTransfer = 2,
+    LimitOrderWithFee = 3,
+    TransferWithFee = 4,
+    ConditionalTransferWithFee = 5,
+    Withdrawal = 6,
+}
+
+/// Packs unsigned integers into a field element, most significant first
+struct Packer(U256);
+
+impl Packer {
+    fn new(instruction: Instruction) -> Self {
+        Self(U256::from_u8(instruction as u8))
+    }
+
+    /// Appends `value` taking `bits` bits, returns an error if it doesn't fit
+    fn push(self, field: &'static str, value: impl Into<u64>, bits: usize) -> Result<Self, Error> {
+        let value = value.into();
+        if bits < 64 && value >> bits != 0 {
+            return Err(Error(Reason::OutOfRange { field, bits }));
+        }
+        Ok(self.pad(bits).or(value))
+    }
+
+    /// Appends `bits` zero bits
+    fn pad(self, bits: usize) -> Self {
+        Self(self.0 << bits)
+    }
+
+    fn or(self, value: u64) -> Self {
+        Self(self.0 | U256::from_u64(value))
+    }
+
+    fn finish(self) -> FieldElement {
+        FieldElement::from_uint_mod_order(&self.0)
+    }
+}
+
+/// Limit order (StarkEx Spot v1)
+///
+/// The user offers to sell `amount_sell` of `token_sell` for `amount_buy` of `token_buy`.
+#[derive(Clone, Copy, Debug)]
+pub struct LimitOrder {
+    /// Vault to take the sold tokens from, less than $2^{31}$
+    pub vault_sell: u32,
+    /// Vault to put the bought tokens into, less than $2^{31}$
+    pub vault_buy: u32,
+    /// Amount of sold tokens, less than $2^{63}$
+    pub amount_sell: u64,
+    /// Amount of bought tokens, less than $2^{63}$
+    pub amount_buy: u64,
+    /// Asset id of sold tokens
+    pub token_sell: FieldElement,
+    /// Asset id of bought tokens
+    pub token_buy: FieldElement,
+    /// Nonce, less than $2^{31}$
+    pub nonce: u32,
+    /// Expiration time in hours since the Unix epoch, less than $2^{22}$
+    pub expiration_timestamp: u32,
+}
+
+impl LimitOrder {
+    /// Computes the message hash
+    ///
+    /// Returns an error if any of the fields is out of range.
+    pub fn hash(&self) -> Result<FieldElement, Error> {
+        let points = pedersen::ConstantPoints::new();
+        let packed = Packer::new(Instruction::LimitOrder)
+            .push("vault_sell", self.vault_sell, 31)?
+            .push("vault_buy", self.vault_buy, 31)?
+            .push("amount_sell", self.amount_sell, 63)?
+            .push("amount_buy", self.amount_buy, 63)?
+            .push("nonce", self.nonce, 31)?
+            .push("expiration_timestamp", self.expiration_timestamp, 22)?
+            .finish();
+        Ok(points.hash(points.hash(self.token_sell, self.token_buy), packed))
+    }
+}
+
+/// Transfer (StarkEx Spot v1)
+///
+/// Conditional transfer is only valid if the `condition` (interpreted by the application, e.g. a fact
+/// registered on L1) holds.
+#[derive(Clone, Copy, Debug)]
+pub struct Transfer {
+    /// Amount of transferred tokens, less than $2^{63}$
+    pub amount: u64,
+    /// Nonce, less than $2^{31}$
+    pub nonce: u32,
+    /// Vault of the sender, less than $2^{31}$
+    pub sender_vault_id: u32,
+    /// Asset id of transferred tokens
+    pub token: FieldElement,
+    /// Vault of the receiver, less than $2^{31}$
+    pub receiver_vault_id: u32,
+    /// Stark public key of the receiver
+    pub receiver_public_key: FieldElement,
+    /// Expiration time in hours since the Unix epoch, less than $2^{22}$
+    pub expiration_timestamp: u32,
+    /// Condition of conditional transfer, `None` for a regular transfer
+    pub condition: Option<FieldElement>,
+}
+
+impl Transfer {
+    /// Computes the message hash
+    ///
+    /// Returns an error if any of the fields is out of range.
+    pub fn hash(&self) -> Result<FieldElement, Error> {
+        let points = pedersen::ConstantPoints::new();
+        let instruction = match self.condition {
+            Some(_) => Instruction::ConditionalTransfer,
+            None => Instruction::Transfer,
+        };
+        let packed = Packer::new(instruction)
+            .push("sender_vault_id", self.sender_vault_id, 31)?
+            .push("receiver_vault_id", self.receiver_vault_id, 31)?
+            .push("amount", self.amount, 63)?
+            .pad(63)
+            .push("nonce", self.nonce, 31)?
+            .push("expiration_timestamp", self.expiration_timestamp, 22)?
+            .finish();
+
+        let mut hash = points.hash(self.token, self.receiver_public_key);
+        if let Some(condition) = self.condition {
+            hash = points.hash(hash, condition);
+        }
+        Ok(points.hash(hash, packed))
+    }
+}
+
+/// Fee paid for executing a message
+#[derive(Clone, Copy, Debug)]
+pub struct Fee {
+    /// Asset id of the fee token
+    pub token: FieldElement,
+    /// Vault (or position) the fee is taken from
+    pub vault_id: u64,
+    /// Max amount of the fee
+    pub limit: u64,
+}
+
+/// Limit order with fee (StarkEx Spot v2 and later)
+#[derive(Clone, Copy, Debug)]
+pub struct LimitOrderWithFee {
+    /// Vault to take the sold tokens from
+    pub vault_sell: u64,
+    /// Vault to put the bought tokens into
+    pub vault_buy: u64,
+    /// Amount of sold tokens
+    pub amount_sell: u64,
+    /// Amount of bought tokens
+    pub amount_buy: u64,
+    /// Asset id of sold tokens
+    pub token_sell: FieldElement,
+    /// Asset id of bought tokens
+    pub token_buy: FieldElement,
+    /// Nonce
+    pub nonce: u32,
+    /// Expiration time in hours since the Unix epoch
+    pub expiration_timestamp: u32,
+    /// Fee of the order
+    pub fee: Fee,
+}
+
+impl LimitOrderWithFee {
+    /// Computes the message hash
+    pub fn hash(&self) -> FieldElement {
+        let points = pedersen::ConstantPoints::new();
+        let packed_0 = Packer(U256::from_u64(self.amount_sell))
+            .pad(64)
+            .or(self.amount_buy)
+            .pad(64)
+            .or(self.fee.limit)
+            .pad(32)
+            .or(self.nonce.into())
+            .finish();
+        let packed_1 = Packer::new(Instruction::LimitOrderWithFee)
+            .pad(64)
+            .or(self.fee.vault_id)
+            .pad(64)
+            .or(self.vault_sell)
+            .pad(64)
+            .or(self.vault_buy)
+            .pad(32)
+            .or(self.expiration_timestamp.into())
+            .pad(17)
+            .finish();
+
+        let hash = points.hash(self.token_sell, self.token_buy);
+        let hash = points.hash(hash, self.fee.token);
+        points.hash(points.hash(hash, packed_0), packed_1)
+    }
+}
+
+/// Transfer with fee (StarkEx Spot v2 and later, StarkEx Perpetual)
+///
+/// In StarkEx Perpetual, vault ids are position ids.
+#[derive(Clone, Copy, Debug)]
+pub struct TransferWithFee {
+    /// Amount of transferred tokens
+    pub amount: u64,
+    /// Nonce
+    pub nonce: u32,
+    /// Vault of the sender
+    pub sender_vault_id: u64,
+    /// Asset id of transferred tokens
+    pub token: FieldElement,
+    /// Vault of the receiver
+    pub receiver_vault_id: u64,
+    /// Stark public key of the receiver
+    pub receiver_public_key: FieldElement,
+    /// Expiration time in hours since the Unix epoch
+    pub expiration_timestamp: u32,
+    /// Condition of conditional transfer, `None` for a regular transfer
+    pub condition: Option<FieldElement>,
+    /// Fee of the transfer
+    pub fee: Fee,
+}
+
+impl TransferWithFee {
+    /// Computes the message hash
+    pub fn hash(&self) -> FieldElement {
+        let points = pedersen::ConstantPoints::new();
+        let instruction = match self.condition {
+            Some(_) => Instruction::ConditionalTransferWithFee,
+            None => Instruction::TransferWithFee,
+        };
+        let packed_0 = Packer(U256::from_u64(self.sender_vault_id))
+            .pad(64)
+            .or(self.receiver_vault_id)
+            .pad(64)
+            .or(self.fee.vault_id)
+            .pad(32)
+            .or(self.nonce.into())
+            .finish();
+        let packed_1 = Packer::new(instruction)
+            .pad(64)
+            .or(self.amount)
+            .pad(64)
+            .or(self.fee.limit)
+            .pad(32)
+            .or(self.expiration_timestamp.into())
+            .pad(81)
+            .finish();
+
+        let hash = points.hash(self.token, self.fee.token);
+        let mut hash = points.hash(hash, self.receiver_public_key);
+        if let Some(condition) = self.condition {
+            hash = points.hash(hash, condition);
+        }
+        points.hash(points.hash(hash, packed_0), packed_1)
+    }
+}
+
+/// Withdrawal of collateral from a position (StarkEx Perpetual)
+#[derive(Clone, Copy, Debug)]
+pub struct Withdrawal {
+    /// Asset id of the collateral
+    pub collateral_asset_id: FieldElement,
+    /// Position to withdraw from
+    pub position_id: u64,
+    /// Nonce
+    pub nonce: u32,
+    /// Expiration time in hours since the Unix epoch
+    pub expiration_timestamp: u32,
+    /// Withdrawn amount
+    pub amount: u64,
+}
+
+impl Withdrawal {
+    /// Computes the message hash
+    pub fn hash(&self) -> FieldElement {
+        let packed = Packer::new(Instruction::Withdrawal)
+            .pad(64)
+            .or(self.position_id)
+            .pad(32)
+            .or(self.nonce.into())
+            .pad(64)
+            .or(self.amount)
+            .pad(32)
+            .or(self.expiration_timestamp.into())
+            .pad(49)
+            .finish();
+        pedersen::hash(self.collateral_asset_id, packed)
+    }
+}
+
+/// Message field is out of range
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error(Reason);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Reason {
+    OutOfRange { field: &'static str, bits: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Reason::OutOfRange { field, bits } => {
+                write!(f, "`{field}` must be less than 2^{bits}")
+            }
+        }
+    }
+}
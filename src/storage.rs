@@ -0,0 +1,89 @@
+//! Addresses of contract storage variables
+//!
+//! Each storage variable has a base address derived from its name. Variables holding multiple values,
+//! such as maps, derive addresses of the values from the base address and the keys by chaining
+//! [Pedersen hash](pedersen::hash). The result is reduced modulo $2^{251} - 256$, so that values
+//! occupying several consecutive slots (e.g. `u256` takes two) don't overflow the address space.
+//!
+//! ```rust
+//! use stark_curve::{storage, FieldElement};
+//!
+//! # let owner = FieldElement::from(0x123);
+//! # let spender = FieldElement::from(0x456);
+//! // Cairo 0 `allowances(owner, spender)`, or Cairo 1 `LegacyMap`
+//! let slot = storage::storage_address("allowances", &[owner, spender]);
+//!
+//! // Cairo 1 `allowances: Map<(ContractAddress, ContractAddress), u256>`
+//! let path = storage::StoragePath::new("allowances").entry(&[owner, spender]);
+//! let (low, high) = (path.address(), path.offset(1));
+//! assert_eq!(low, slot);
+//! ```
+
+use crate::account::reduce_address;
+use crate::hash::{pedersen, sn_keccak};
+use crate::FieldElement;
+
+/// Computes address of a storage variable
+///
+/// $$
+/// H(\dots H(H(\text{sn\\_keccak}(\text{var\\_name}), \text{key}_1), \text{key}_2) \dots, \text{key}_k)
+///     \bmod (2^{251} - 256)
+/// $$
+///
+/// where $H$ is Pedersen hash. This is the layout of Cairo 0 storage variables and Cairo 1 `LegacyMap`.
+/// Keys taking multiple field elements (e.g. `u256`) contribute each element as a separate key.
+pub fn storage_address(var_name: &str, keys: &[FieldElement]) -> FieldElement {
+    keys.iter()
+        .fold(StoragePath::new(var_name), |path, key| path.entry(&[*key]))
+        .address()
+}
+
+/// Path to a value in Cairo 1 storage
+///
+/// Starts at a storage variable and follows map entries, vector elements, and members of storage
+/// nodes. Each step hashes the current path with the step's key, and the resulting address is reduced
+/// modulo $2^{251} - 256$ only at the end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StoragePath(FieldElement);
+
+impl StoragePath {
+    /// Starts a path at the storage variable `var_name`, whose base address is $\text{sn\\_keccak}(\text{var\\_name})$
+    pub fn new(var_name: &str) -> Self {
+        Self(sn_keccak(var_name.as_bytes()))
+    }
+
+    /// Follows the entry of `Map` at `key`
+    ///
+    /// `key` is the serialized key, e.g. two elements for `u256` or a tuple of two addresses. Each element
+    /// is hashed into the path. Nested maps are followed by calling this method once per map.
+    pub fn entry(self, key: &[FieldElement]) -> Self {
+        Self(key.iter().fold(self.0, |path, k| pedersen::hash(path, *k)))
+    }
+
+    /// Follows the element of storage `Vec` at `index`
+    ///
+    /// Length of the vector is stored at the address of the vector itself.
+    pub fn at(self, index: u64) -> Self {
+        Self(pedersen::hash(self.0, FieldElement::from(index)))
+    }
+
+    /// Follows the member of a storage node (struct annotated with `#[starknet::storage_node]`)
+    ///
+    /// Members of regular structs stored as a single value are found by [`offset`](Self::offset)
+    /// instead.
+    pub fn member(self, name: &str) -> Self {
+        Self(pedersen::hash(self.0, sn_keccak(name.as_bytes())))
+    }
+
+    /// Returns storage address of the value at the path
+    pub fn address(&self) -> FieldElement {
+        reduce_address(self.0)
+    }
+
+    /// Returns storage address of `offset`-th field element of the value at the path
+    ///
+    /// Values are serialized into consecutive slots, e.g. `u256` occupies offsets $0$ (low) and $1$ (high).
+    pub fn offset(&self, offset: u8) -> FieldElement {
+        self.address() + FieldElement::from(u64::from(offset))
+    }
+}
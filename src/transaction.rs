@@ -0,0 +1,406 @@
+//! Starknet transaction hashes
+//!
+//! Transaction hash is what the account signs. It commits to all fields of the transaction, the
+//! chain id, and the transaction version. Version 1 (and version 2 of `DECLARE`) transactions are hashed
+//! with [Pedersen array hash](pedersen::hash_array), version 3 transactions are hashed with
+//! [Poseidon](poseidon::hash_many) and pay fees in resources rather than `max_fee`.
+//!
+//! ```rust
+//! use stark_curve::{cairo, transaction, FieldElement};
+//!
+//! let chain_id = cairo::short_string("SN_SEPOLIA").unwrap();
+//! # let sender_address = FieldElement::from(0x123);
+//! let calldata = [FieldElement::from(1), FieldElement::from(2)];
+//! let tx = transaction::InvokeV3 {
+//!     fields: transaction::V3Fields {
+//!         sender_address,
+//!         chain_id,
+//!         nonce: FieldElement::ZERO,
+//!         tip: 0,
+//!         resource_bounds: transaction::ResourceBoundsMapping {
+//!             l1_gas: transaction::ResourceBounds { max_amount: 0, max_price_per_unit: 1000 },
+//!             l2_gas: transaction::ResourceBounds { max_amount: 10_000, max_price_per_unit: 10 },
+//!             l1_data_gas: Some(transaction::ResourceBounds { max_amount: 100, max_price_per_unit: 100 }),
+//!         },
+//!         paymaster_data: &[],
+//!         nonce_data_availability_mode: transaction::DataAvailabilityMode::L1,
+//!         fee_data_availability_mode: transaction::DataAvailabilityMode::L1,
+//!         query: false,
+//!     },
+//!     account_deployment_data: &[],
+//!     calldata: &calldata,
+//! };
+//! let tx_hash = tx.hash();
+//! ```
+
+use crate::hash::{pedersen, poseidon};
+use crate::FieldElement;
+
+/// Prefix of `INVOKE` transaction hash, short string `'invoke'`
+const INVOKE_PREFIX: &[u8] = b"invoke";
+/// Prefix of `DECLARE` transaction hash, short string `'declare'`
+const DECLARE_PREFIX: &[u8] = b"declare";
+/// Prefix of `DEPLOY_ACCOUNT` transaction hash, short string `'deploy_account'`
+const DEPLOY_ACCOUNT_PREFIX: &[u8] = b"deploy_account";
+
+/// Returns transaction version, offset by $2^{128}$ for query transactions
+///
+/// Query transactions are only valid for fee estimation and simulation. Their hash differs from the hash of
+/// the same transaction submitted for execution, so the signature can't be replayed.
+fn version(version: u64, query: bool) -> FieldElement {
+    let mut bytes = [0u8; 32];
+    if query {
+        bytes[15] = 1;
+    }
+    bytes[24..].copy_from_slice(&version.to_be_bytes());
+    FieldElement::from_be_bytes_mod_order(&bytes)
+}
+
+/// Computes the v1 transaction hash
+///
+/// $$
+/// H(\text{prefix}, \text{version}, \text{address}, 0, \text{data\\_hash}, \text{max\\_fee},
+///     \text{chain\\_id}, \text{nonce}, \text{extra}\dots)
+/// $$
+#[allow(clippy::too_many_arguments)]
+fn v1_hash(
+    prefix: &[u8],
+    version: FieldElement,
+    address: FieldElement,
+    data_hash: FieldElement,
+    max_fee: FieldElement,
+    chain_id: FieldElement,
+    nonce: FieldElement,
+    extra: Option<FieldElement>,
+) -> FieldElement {
+    let fields = [
+        FieldElement::from_be_bytes_mod_order(prefix),
+        version,
+        address,
+        FieldElement::ZERO,
+        data_hash,
+        max_fee,
+        chain_id,
+        nonce,
+    ];
+    pedersen::hash_array(fields.iter().chain(&extra))
+}
+
+/// `INVOKE` transaction v1
+#[derive(Clone, Copy, Debug)]
+pub struct InvokeV1<'a> {
+    /// Address of the account sending the transaction
+    pub sender_address: FieldElement,
+    /// Calldata of the account's `__execute__` entry point
+    pub calldata: &'a [FieldElement],
+    /// Max fee the sender is willing to pay, in Wei
+    pub max_fee: FieldElement,
+    /// Chain id, e.g. short string `'SN_MAIN'`
+    pub chain_id: FieldElement,
+    /// Nonce of the account
+    pub nonce: FieldElement,
+    /// Whether it's a query transaction (used for fee estimation and simulation)
+    pub query: bool,
+}
+
+impl InvokeV1<'_> {
+    /// Computes the transaction hash
+    pub fn hash(&self) -> FieldElement {
+        v1_hash(
+            INVOKE_PREFIX,
+            version(1, self.query),
+            self.sender_address,
+            pedersen::hash_array(self.calldata),
+            self.max_fee,
+            self.chain_id,
+            self.nonce,
+            None,
+        )
+    }
+}
+
+/// `DECLARE` transaction v1, declares Cairo 0 class
+#[derive(Clone, Copy, Debug)]
+pub struct DeclareV1 {
+    /// Address of the account sending the transaction
+    pub sender_address: FieldElement,
+    /// Hash of the declared class
+    pub class_hash: FieldElement,
+    /// Max fee the sender is willing to pay, in Wei
+    pub max_fee: FieldElement,
+    /// Chain id, e.g. short string `'SN_MAIN'`
+    pub chain_id: FieldElement,
+    /// Nonce of the account
+    pub nonce: FieldElement,
+    /// Whether it's a query transaction (used for fee estimation and simulation)
+    pub query: bool,
+}
+
+impl DeclareV1 {
+    /// Computes the transaction hash
+    pub fn hash(&self) -> FieldElement {
+        v1_hash(
+            DECLARE_PREFIX,
+            version(1, self.query),
+            self.sender_address,
+            pedersen::hash_array(&[self.class_hash]),
+            self.max_fee,
+            self.chain_id,
+            self.nonce,
+            None,
+        )
+    }
+}
+
+/// `DECLARE` transaction v2, declares Sierra class
+#[derive(Clone, Copy, Debug)]
+pub struct DeclareV2 {
+    /// Address of the account sending the transaction
+    pub sender_address: FieldElement,
+    /// Hash of the declared Sierra class
+    pub class_hash: FieldElement,
+    /// Hash of the class compiled to CASM
+    pub compiled_class_hash: FieldElement,
+    /// Max fee the sender is willing to pay, in Wei
+    pub max_fee: FieldElement,
+    /// Chain id, e.g. short string `'SN_MAIN'`
+    pub chain_id: FieldElement,
+    /// Nonce of the account
+    pub nonce: FieldElement,
+    /// Whether it's a query transaction (used for fee estimation and simulation)
+    pub query: bool,
+}
+
+impl DeclareV2 {
+    /// Computes the transaction hash
+    ///
+    /// Same as v1 hash with the compiled class hash appended.
+    pub fn hash(&self) -> FieldElement {
+        v1_hash(
+            DECLARE_PREFIX,
+            version(2, self.query),
+            self.sender_address,
+            pedersen::hash_array(&[self.class_hash]),
+            self.max_fee,
+            self.chain_id,
+            self.nonce,
+            Some(self.compiled_class_hash),
+        )
+    }
+}
+
+/// `DEPLOY_ACCOUNT` transaction v1
+#[derive(Clone, Copy, Debug)]
+pub struct DeployAccountV1<'a> {
+    /// Address of the deployed account, see [`account::contract_address`](crate::account::contract_address)
+    pub contract_address: FieldElement,
+    /// Class hash of the account
+    pub class_hash: FieldElement,
+    /// Salt of the contract address
+    pub contract_address_salt: FieldElement,
+    /// Calldata of the account's constructor
+    pub constructor_calldata: &'a [FieldElement],
+    /// Max fee the sender is willing to pay, in Wei
+    pub max_fee: FieldElement,
+    /// Chain id, e.g. short string `'SN_MAIN'`
+    pub chain_id: FieldElement,
+    /// Nonce of the account, normally zero
+    pub nonce: FieldElement,
+    /// Whether it's a query transaction (used for fee estimation and simulation)
+    pub query: bool,
+}
+
+impl DeployAccountV1<'_> {
+    /// Computes the transaction hash
+    ///
+    /// Hashed data is the class hash, salt, and constructor calldata.
+    pub fn hash(&self) -> FieldElement {
+        let data = [self.class_hash, self.contract_address_salt];
+        v1_hash(
+            DEPLOY_ACCOUNT_PREFIX,
+            version(1, self.query),
+            self.contract_address,
+            pedersen::hash_array(data.iter().chain(self.constructor_calldata)),
+            self.max_fee,
+            self.chain_id,
+            self.nonce,
+            None,
+        )
+    }
+}
+
+/// Max amount and max price of a resource the sender is willing to pay for
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResourceBounds {
+    /// Max amount of the resource that can be used
+    pub max_amount: u64,
+    /// Max price per unit of the resource, in Fri
+    pub max_price_per_unit: u128,
+}
+
+impl ResourceBounds {
+    /// Encodes bounds as $\text{name} \cdot 2^{192} + \text{max\\_amount} \cdot 2^{128} + \text{max\\_price\\_per\\_unit}$
+    ///
+    /// `name` is a short string of at most 8 characters.
+    fn encode(&self, name: &[u8]) -> FieldElement {
+        let mut bytes = [0u8; 32];
+        bytes[8 - name.len()..8].copy_from_slice(name);
+        bytes[8..16].copy_from_slice(&self.max_amount.to_be_bytes());
+        bytes[16..].copy_from_slice(&self.max_price_per_unit.to_be_bytes());
+        FieldElement::from_be_bytes_mod_order(&bytes)
+    }
+}
+
+/// Resource bounds of v3 transaction
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResourceBoundsMapping {
+    /// Bounds of L1 gas
+    pub l1_gas: ResourceBounds,
+    /// Bounds of L2 gas
+    pub l2_gas: ResourceBounds,
+    /// Bounds of L1 data gas
+    ///
+    /// Introduced in Starknet v0.13.4. Set to `None` to compute the hash as it was defined before,
+    /// when L1 data gas wasn't part of the hash.
+    pub l1_data_gas: Option<ResourceBounds>,
+}
+
+/// Data availability mode of nonce or fee
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DataAvailabilityMode {
+    /// Data is posted to L1
+    #[default]
+    L1 = 0,
+    /// Data is posted to L2
+    L2 = 1,
+}
+
+/// Fields shared by all v3 transactions
+#[derive(Clone, Copy, Debug)]
+pub struct V3Fields<'a> {
+    /// Address of the account sending the transaction
+    ///
+    /// For `DEPLOY_ACCOUNT` transaction, it's the address of the deployed account.
+    pub sender_address: FieldElement,
+    /// Chain id, e.g. short string `'SN_MAIN'`
+    pub chain_id: FieldElement,
+    /// Nonce of the account
+    pub nonce: FieldElement,
+    /// Tip for prioritizing the transaction
+    pub tip: u64,
+    /// Max amounts and prices of resources the sender is willing to pay for
+    pub resource_bounds: ResourceBoundsMapping,
+    /// Data passed to the paymaster, empty if there's no paymaster
+    pub paymaster_data: &'a [FieldElement],
+    /// Data availability mode of the nonce
+    pub nonce_data_availability_mode: DataAvailabilityMode,
+    /// Data availability mode of the fee
+    pub fee_data_availability_mode: DataAvailabilityMode,
+    /// Whether it's a query transaction (used for fee estimation and simulation)
+    pub query: bool,
+}
+
+impl V3Fields<'_> {
+    /// Starts hashing a v3 transaction
+    ///
+    /// Absorbs prefix, version, sender address, hash of tip and resource bounds, hash of paymaster data,
+    /// chain id, nonce, and data availability modes $\text{nonce\\_mode} \cdot 2^{32} + \text{fee\\_mode}$.
+    fn hasher(&self, prefix: &[u8]) -> poseidon::Hasher {
+        let bounds = &self.resource_bounds;
+        let fee_hash = poseidon::hash_many(
+            [
+                FieldElement::from(self.tip),
+                bounds.l1_gas.encode(b"L1_GAS"),
+                bounds.l2_gas.encode(b"L2_GAS"),
+            ]
+            .iter()
+            .chain(&bounds.l1_data_gas.map(|b| b.encode(b"L1_DATA"))),
+        );
+        let da_modes = (self.nonce_data_availability_mode as u64) << 32
+            | self.fee_data_availability_mode as u64;
+
+        poseidon::Hasher::new()
+            .chain(FieldElement::from_be_bytes_mod_order(prefix))
+            .chain(version(3, self.query))
+            .chain(self.sender_address)
+            .chain(fee_hash)
+            .chain(poseidon::hash_many(self.paymaster_data))
+            .chain(self.chain_id)
+            .chain(self.nonce)
+            .chain(FieldElement::from(da_modes))
+    }
+}
+
+/// `INVOKE` transaction v3
+#[derive(Clone, Copy, Debug)]
+pub struct InvokeV3<'a> {
+    /// Fields shared by all v3 transactions
+    pub fields: V3Fields<'a>,
+    /// Data for deploying the account along with the transaction, currently must be empty
+    pub account_deployment_data: &'a [FieldElement],
+    /// Calldata of the account's `__execute__` entry point
+    pub calldata: &'a [FieldElement],
+}
+
+impl InvokeV3<'_> {
+    /// Computes the transaction hash
+    pub fn hash(&self) -> FieldElement {
+        self.fields
+            .hasher(INVOKE_PREFIX)
+            .chain(poseidon::hash_many(self.account_deployment_data))
+            .chain(poseidon::hash_many(self.calldata))
+            .finalize()
+    }
+}
+
+/// `DECLARE` transaction v3
+#[derive(Clone, Copy, Debug)]
+pub struct DeclareV3<'a> {
+    /// Fields shared by all v3 transactions
+    pub fields: V3Fields<'a>,
+    /// Data for deploying the account along with the transaction, currently must be empty
+    pub account_deployment_data: &'a [FieldElement],
+    /// Hash of the declared Sierra class
+    pub class_hash: FieldElement,
+    /// Hash of the class compiled to CASM
+    pub compiled_class_hash: FieldElement,
+}
+
+impl DeclareV3<'_> {
+    /// Computes the transaction hash
+    pub fn hash(&self) -> FieldElement {
+        self.fields
+            .hasher(DECLARE_PREFIX)
+            .chain(poseidon::hash_many(self.account_deployment_data))
+            .chain(self.class_hash)
+            .chain(self.compiled_class_hash)
+            .finalize()
+    }
+}
+
+/// `DEPLOY_ACCOUNT` transaction v3
+///
+/// [`V3Fields::sender_address`] is the address of the deployed account.
+#[derive(Clone, Copy, Debug)]
+pub struct DeployAccountV3<'a> {
+    /// Fields shared by all v3 transactions
+    pub fields: V3Fields<'a>,
+    /// Class hash of the account
+    pub class_hash: FieldElement,
+    /// Salt of the contract address
+    pub contract_address_salt: FieldElement,
+    /// Calldata of the account's constructor
+    pub constructor_calldata: &'a [FieldElement],
+}
+
+impl DeployAccountV3<'_> {
+    /// Computes the transaction hash
+    pub fn hash(&self) -> FieldElement {
+        self.fields
+            .hasher(DEPLOY_ACCOUNT_PREFIX)
+            .chain(poseidon::hash_many(self.constructor_calldata))
+            .chain(self.class_hash)
+            .chain(self.contract_address_salt)
+            .finalize()
+    }
+}
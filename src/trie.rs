@@ -0,0 +1,306 @@
+//! Starknet Merkle-Patricia trie
+//!
+//! Starknet state is committed to by binary Merkle-Patricia tries of height $251$: contract storage and
+//! contracts tries are hashed with [Pedersen](pedersen), classes trie is hashed with [Poseidon](poseidon).
+//! A key is a path from the root to a leaf, most significant bit first, and chains of nodes having a
+//! single child are compressed into edge nodes. Keys which aren't in the trie have value zero.
+//!
+//! Leaves of contracts trie are [contract state hashes](contract_state_hash) keyed by contract address,
+//! leaves of classes trie are [class leaf hashes](class_leaf_hash) keyed by class hash. Roots of both tries
+//! are combined into the global [state root](state_root).
+//!
+//! Proofs returned by `starknet_getStorageProof` are lists of nodes along with their hashes, which can
+//! be checked with [`verify_proof`]:
+//!
+//! ```rust
+//! use stark_curve::trie::{self, MerkleNode, TrieHash};
+//! use stark_curve::FieldElement;
+//!
+//! # fn main() -> Result<(), trie::Error> {
+//! // Trie with a single leaf
+//! let key = FieldElement::from(0x1234);
+//! let value = FieldElement::from(42);
+//! let node = MerkleNode::Edge { child: value, path: key, length: 251 };
+//! let root = node.hash(TrieHash::Pedersen);
+//!
+//! let proof = [(root, node)];
+//! assert_eq!(trie::verify_proof(root, key, &proof, TrieHash::Pedersen)?, value);
+//! # Ok(()) }
+//! ```
+
+use core::fmt;
+
+use crate::bigint::U256;
+use crate::hash::{pedersen, poseidon};
+use crate::FieldElement;
+
+/// Height of the tries, keys are less than $2^{251}$
+pub const HEIGHT: u8 = 251;
+
+/// Hash function of a trie
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrieHash {
+    /// Pedersen hash, used by contract storage and contracts tries
+    Pedersen,
+    /// Poseidon hash, used by classes trie
+    Poseidon,
+}
+
+impl TrieHash {
+    fn hash(self, x: FieldElement, y: FieldElement) -> FieldElement {
+        match self {
+            Self::Pedersen => pedersen::hash(x, y),
+            Self::Poseidon => poseidon::hash(x, y),
+        }
+    }
+}
+
+/// Inner node of a trie
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MerkleNode {
+    /// Node with two children
+    Binary {
+        /// Hash of the left child (next bit of the key is zero)
+        left: FieldElement,
+        /// Hash of the right child (next bit of the key is one)
+        right: FieldElement,
+    },
+    /// Compressed path to a single child
+    Edge {
+        /// Hash of the child, or the value if the edge leads to a leaf
+        child: FieldElement,
+        /// Bits of the path, the last bit of the path is the least significant bit
+        path: FieldElement,
+        /// Length of the path in bits
+        length: u8,
+    },
+}
+
+impl MerkleNode {
+    /// Computes hash of the node
+    ///
+    /// Hash of binary node is $H(\text{left}, \text{right})$, hash of edge node is
+    /// $H(\text{child}, \text{path}) + \text{length}$.
+    pub fn hash(&self, hash: TrieHash) -> FieldElement {
+        match *self {
+            Self::Binary { left, right } => hash.hash(left, right),
+            Self::Edge {
+                child,
+                path,
+                length,
+            } => hash.hash(child, path) + FieldElement::from(u64::from(length)),
+        }
+    }
+}
+
+/// Verifies a proof of the value at `key` in the trie with given `root`
+///
+/// `proof` contains nodes along the path from the root to the key, in any order, each paired with its hash
+/// as returned by `starknet_getStorageProof`. Claimed hashes are only used for finding nodes, hash of
+/// every visited node is recomputed.
+///
+/// Returns the value at `key`. If `key` is not in the trie, the proof must show that the path diverges from
+/// `key`, and the returned value is zero.
+pub fn verify_proof(
+    root: FieldElement,
+    key: FieldElement,
+    proof: &[(FieldElement, MerkleNode)],
+    hash: TrieHash,
+) -> Result<FieldElement, Error> {
+    let key = key.to_uint();
+    if key.bits() > usize::from(HEIGHT) {
+        return Err(Error(Reason::KeyOutOfRange));
+    }
+
+    let mut node_hash = root;
+    let mut height = 0u8;
+    while height < HEIGHT {
+        if node_hash == FieldElement::ZERO {
+            // Empty trie or subtree
+            return Ok(FieldElement::ZERO);
+        }
+        let node = proof
+            .iter()
+            .find(|(claimed_hash, _)| *claimed_hash == node_hash)
+            .map(|(_, node)| node)
+            .ok_or(Error(Reason::MissingNode(node_hash)))?;
+        if node.hash(hash) != node_hash {
+            return Err(Error(Reason::HashMismatch(node_hash)));
+        }
+
+        match *node {
+            MerkleNode::Binary { left, right } => {
+                node_hash = if key_bits(&key, height, 1, HEIGHT) == U256::ONE {
+                    right
+                } else {
+                    left
+                };
+                height += 1;
+            }
+            MerkleNode::Edge {
+                child,
+                path,
+                length,
+            } => {
+                if length == 0 || length > HEIGHT - height {
+                    return Err(Error(Reason::InvalidEdge(node_hash)));
+                }
+                let path = path.to_uint();
+                if path.bits() > usize::from(length) {
+                    return Err(Error(Reason::InvalidEdge(node_hash)));
+                }
+                if key_bits(&key, height, length, HEIGHT) != path {
+                    // Path diverges from the key, so the key is not in the trie
+                    return Ok(FieldElement::ZERO);
+                }
+                node_hash = child;
+                height += length;
+            }
+        }
+    }
+    Ok(node_hash)
+}
+
+/// Computes root of the trie of given `height` containing `leaves`
+///
+/// `leaves` are pairs of key and value. Keys must be less than $2^\text{height}$ and sorted in ascending
+/// order without duplicates, values must be nonzero. Root of the empty trie is zero.
+///
+/// Besides the state tries, Starknet uses tries of height $64$ keyed by index for block commitments.
+pub fn root(
+    height: u8,
+    leaves: &[(FieldElement, FieldElement)],
+    hash: TrieHash,
+) -> Result<FieldElement, Error> {
+    let mut prev_key = None;
+    for (key, value) in leaves {
+        let key = key.to_uint();
+        if key.bits() > usize::from(height) {
+            return Err(Error(Reason::KeyOutOfRange));
+        }
+        if prev_key.is_some_and(|prev| prev >= key) {
+            return Err(Error(Reason::UnsortedKeys));
+        }
+        if *value == FieldElement::ZERO {
+            return Err(Error(Reason::ZeroValue));
+        }
+        prev_key = Some(key);
+    }
+
+    if leaves.is_empty() {
+        Ok(FieldElement::ZERO)
+    } else {
+        Ok(subtree_root(leaves, 0, height, hash))
+    }
+}
+
+/// Computes root of subtrie at `depth` containing non-empty `leaves`
+fn subtree_root(
+    leaves: &[(FieldElement, FieldElement)],
+    depth: u8,
+    height: u8,
+    hash: TrieHash,
+) -> FieldElement {
+    if depth == height {
+        return leaves[0].1;
+    }
+
+    let first = leaves[0].0.to_uint();
+    let last = leaves[leaves.len() - 1].0.to_uint();
+    let remaining = height - depth;
+    // Keys agree on bits above `depth`, so they differ within the remaining bits
+    let common = remaining - (first ^ last).bits() as u8;
+
+    let node = if common > 0 {
+        MerkleNode::Edge {
+            child: subtree_root(leaves, depth + common, height, hash),
+            path: FieldElement::from_uint_mod_order(&key_bits(&first, depth, common, height)),
+            length: common,
+        }
+    } else {
+        let split = leaves
+            .partition_point(|(key, _)| key_bits(&key.to_uint(), depth, 1, height) == U256::ZERO);
+        MerkleNode::Binary {
+            left: subtree_root(&leaves[..split], depth + 1, height, hash),
+            right: subtree_root(&leaves[split..], depth + 1, height, hash),
+        }
+    };
+    node.hash(hash)
+}
+
+/// Returns `length` bits of the key starting at `depth` (counting from the most significant bit of a
+/// `height`-bit key)
+fn key_bits(key: &U256, depth: u8, length: u8, height: u8) -> U256 {
+    let shift = usize::from(height - depth - length);
+    let mask = U256::MAX.shr_vartime(256 - usize::from(length));
+    key.shr_vartime(shift) & mask
+}
+
+/// Computes hash of a contract, the leaf of the contracts trie
+///
+/// $$
+/// H(H(H(\text{class\\_hash}, \text{storage\\_root}), \text{nonce}), 0)
+/// $$
+///
+/// where $H$ is Pedersen hash and `storage_root` is the root of the contract storage trie.
+pub fn contract_state_hash(
+    class_hash: FieldElement,
+    storage_root: FieldElement,
+    nonce: FieldElement,
+) -> FieldElement {
+    let hash = pedersen::hash(pedersen::hash(class_hash, storage_root), nonce);
+    pedersen::hash(hash, FieldElement::ZERO)
+}
+
+/// Computes leaf of the classes trie: $H(\texttt{"CONTRACT\\_CLASS\\_LEAF\\_V0"}, \text{compiled\\_class\\_hash})$
+///
+/// where $H$ is Poseidon hash. Classes trie is keyed by (Sierra) class hash.
+pub fn class_leaf_hash(compiled_class_hash: FieldElement) -> FieldElement {
+    let prefix = FieldElement::from_be_bytes_mod_order(b"CONTRACT_CLASS_LEAF_V0");
+    poseidon::hash(prefix, compiled_class_hash)
+}
+
+/// Computes global state root from the roots of contracts and classes tries
+///
+/// $$
+/// H(\texttt{"STARKNET\\_STATE\\_V0"}, \text{contracts\\_root}, \text{classes\\_root})
+/// $$
+///
+/// where $H$ is [Poseidon hash](poseidon::hash_many). If classes trie is empty (before Starknet v0.11.0),
+/// state root is the root of contracts trie.
+pub fn state_root(contracts_root: FieldElement, classes_root: FieldElement) -> FieldElement {
+    if classes_root == FieldElement::ZERO {
+        return contracts_root;
+    }
+    let prefix = FieldElement::from_be_bytes_mod_order(b"STARKNET_STATE_V0");
+    poseidon::hash_many(&[prefix, contracts_root, classes_root])
+}
+
+/// Proof is invalid, or trie cannot be built
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error(Reason);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Reason {
+    KeyOutOfRange,
+    MissingNode(FieldElement),
+    HashMismatch(FieldElement),
+    InvalidEdge(FieldElement),
+    UnsortedKeys,
+    ZeroValue,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Reason::KeyOutOfRange => f.write_str("key is out of range"),
+            Reason::MissingNode(hash) => write!(f, "proof doesn't contain node {hash:#x}"),
+            Reason::HashMismatch(hash) => write!(f, "hash of node {hash:#x} doesn't match"),
+            Reason::InvalidEdge(hash) => write!(f, "edge node {hash:#x} is malformed"),
+            Reason::UnsortedKeys => {
+                f.write_str("keys must be sorted in ascending order without duplicates")
+            }
+            Reason::ZeroValue => f.write_str("values of leaves must be nonzero"),
+        }
+    }
+}
@@ -0,0 +1,872 @@
+//! SNIP-12 typed structured data
+//!
+//! [SNIP-12] is Starknet's counterpart of EIP-712: it defines how to hash a structured message, so it can be
+//! signed off-chain and verified by an account contract. Revision 0 uses [Pedersen](crate::hash::pedersen)
+//! hash, revision 1 uses [Poseidon](crate::hash::poseidon) hash and adds more basic types, enums, and
+//! preset types `u256`, `TokenAmount`, `NftId`.
+//!
+//! Types and values mirror SNIP-12 JSON representation: type definition is a list of [`Member`]s with
+//! types given as strings (e.g. `"felt"`, `"u128*"`, `"(u128,bool)"` for enum variants), and a struct
+//! value is a [`Value::Object`] mapping field names to values. Hashing follows `starknet.js`, which is
+//! the de-facto standard and deviates from the SNIP-12 text in a few places (enum type hash is not
+//! hashed into enum value, variants are encoded as `"name":(...)`, `felt` accepts short strings).
+//!
+//! ```rust
+//! use stark_curve::typed_data::{Domain, Member, Revision, TypedData, Value};
+//! use stark_curve::{cairo, FieldElement};
+//!
+//! let types = [(
+//!     "Order".into(),
+//!     vec![
+//!         Member::new("market", "shortstring"),
+//!         Member::new("amount", "u128"),
+//!         Member::new("expiration", "timestamp"),
+//!     ],
+//! )]
+//! .into_iter()
+//! .collect();
+//! let domain = Domain {
+//!     name: cairo::short_string("My Exchange").unwrap(),
+//!     version: FieldElement::from(1),
+//!     chain_id: cairo::short_string("SN_MAIN").unwrap(),
+//!     revision: Revision::V1,
+//! };
+//! let message = Value::object([
+//!     ("market", Value::from("ETH-USD")),
+//!     ("amount", Value::from(1_000_000u128)),
+//!     ("expiration", Value::from(1_700_000_000u128)),
+//! ]);
+//!
+//! let typed_data = TypedData::new(types, domain, "Order", message);
+//! # let account_address = FieldElement::from(0x1234);
+//! let hash = typed_data.message_hash(account_address)?;
+//! # Ok::<_, stark_curve::typed_data::Error>(())
+//! ```
+//!
+//! [SNIP-12]: https://github.com/starknet-io/SNIPs/blob/main/SNIPS/snip-12.md
+
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::core::{field_element::FieldElementCore, W};
+use crate::elliptic_curve::PrimeField;
+use crate::hash::{pedersen, poseidon, sn_keccak};
+use crate::{cairo, FieldElement};
+
+/// `'StarkNet Message'` encoded as Cairo short string, prefix of the message hash
+///
+/// See the test below which ensures that internal repr matches the short string
+const STARKNET_MESSAGE: FieldElement = W::new(FieldElementCore::from_internal_repr([
+    16156019428408348868,
+    10480951322775611302,
+    18446744073709551605,
+    257012186512350467,
+]));
+
+/// SNIP-12 revision
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Revision {
+    /// Legacy revision based on Pedersen hash
+    V0,
+    /// Active revision based on Poseidon hash
+    V1,
+}
+
+/// Domain separator
+///
+/// Binds the signature to a specific application and network. Fields are usually
+/// [short strings](cairo::short_string).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Domain {
+    /// Name of the application
+    pub name: FieldElement,
+    /// Version of the application
+    pub version: FieldElement,
+    /// Chain ID, e.g. `'SN_MAIN'`
+    pub chain_id: FieldElement,
+    /// SNIP-12 revision
+    pub revision: Revision,
+}
+
+impl Domain {
+    /// Computes hash of the domain
+    pub fn hash(&self) -> FieldElement {
+        let mut hasher = Hasher::new(self.revision);
+        match self.revision {
+            Revision::V0 => {
+                hasher.update(sn_keccak(
+                    b"StarkNetDomain(name:felt,version:felt,chainId:felt)",
+                ));
+                hasher.update(self.name);
+                hasher.update(self.version);
+                hasher.update(self.chain_id);
+            }
+            Revision::V1 => {
+                hasher.update(sn_keccak(
+                    br#""StarknetDomain"("name":"shortstring","version":"shortstring","chainId":"shortstring","revision":"shortstring")"#,
+                ));
+                hasher.update(self.name);
+                hasher.update(self.version);
+                hasher.update(self.chain_id);
+                hasher.update(FieldElement::ONE);
+            }
+        }
+        hasher.finalize()
+    }
+}
+
+/// Member of type definition: either a struct field or an enum variant
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    /// Name of the field or variant
+    pub name: String,
+    /// Type of the field, e.g. `"felt"`, `"u128*"`, `"MyStruct"`, or tuple of types of enum variant,
+    /// e.g. `"()"`, `"(u128,bool)"`
+    pub r#type: String,
+    /// Type contained by `enum` and `merkletree` fields
+    pub contains: Option<String>,
+}
+
+impl Member {
+    /// Constructs a member of given type
+    pub fn new(name: impl Into<String>, r#type: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            r#type: r#type.into(),
+            contains: None,
+        }
+    }
+
+    /// Sets type contained by `enum` or `merkletree` field
+    pub fn with_contains(mut self, contains: impl Into<String>) -> Self {
+        self.contains = Some(contains.into());
+        self
+    }
+}
+
+/// Value of typed data
+///
+/// Apart from [`Value::Felt`], variants correspond to JSON values. A value is accepted by the following
+/// types:
+///
+/// | Type | Accepted values |
+/// |---|---|
+/// | `felt`, `shortstring` | `Felt`, `UnsignedInteger`, `String` with hex or decimal number or short string |
+/// | `ContractAddress`, `ClassHash` | `Felt`, `String` with hex or decimal number |
+/// | `u128`, `timestamp` | `UnsignedInteger`, `Felt` or `String` with number that fit into 128 bits |
+/// | `i128` | `SignedInteger`, `UnsignedInteger` that fit into `i128` |
+/// | `bool` | `Boolean` |
+/// | `string` | `String` |
+/// | `selector` | `String` with name of the function, or `Felt` with the selector itself |
+/// | arrays, `merkletree` | `Array` |
+/// | structs, `u256`, `TokenAmount`, `NftId` | `Object` with all the fields |
+/// | enums | `Object` with single entry mapping name of variant to `Array` of its values |
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    /// Field element
+    Felt(FieldElement),
+    /// String
+    String(String),
+    /// Unsigned integer
+    UnsignedInteger(u128),
+    /// Signed integer
+    SignedInteger(i128),
+    /// Boolean
+    Boolean(bool),
+    /// Array of values
+    Array(Vec<Value>),
+    /// Object, i.e. list of named values
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Constructs an object from named values
+    pub fn object<K: Into<String>>(entries: impl IntoIterator<Item = (K, Value)>) -> Self {
+        Self::Object(entries.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+
+    /// Constructs an enum value, i.e. an object with single entry mapping `variant` to `values`
+    pub fn variant(variant: impl Into<String>, values: Vec<Value>) -> Self {
+        Self::Object(alloc::vec![(variant.into(), Self::Array(values))])
+    }
+}
+
+impl From<FieldElement> for Value {
+    fn from(x: FieldElement) -> Self {
+        Self::Felt(x)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Self::String(s.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Self::String(s)
+    }
+}
+
+impl From<u128> for Value {
+    fn from(x: u128) -> Self {
+        Self::UnsignedInteger(x)
+    }
+}
+
+impl From<i128> for Value {
+    fn from(x: i128) -> Self {
+        Self::SignedInteger(x)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(x: bool) -> Self {
+        Self::Boolean(x)
+    }
+}
+
+impl From<Vec<Value>> for Value {
+    fn from(x: Vec<Value>) -> Self {
+        Self::Array(x)
+    }
+}
+
+/// Typed data: type definitions, domain, and message of the primary type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypedData {
+    types: BTreeMap<String, Vec<Member>>,
+    domain: Domain,
+    primary_type: String,
+    message: Value,
+}
+
+impl TypedData {
+    /// Constructs typed data
+    ///
+    /// `types` may or may not contain definition of the domain type (`StarkNetDomain` in revision 0,
+    /// `StarknetDomain` in revision 1). If present, it must match the one used by [`Domain::hash`],
+    /// otherwise hashing the message fails. Types are validated when the data is hashed.
+    pub fn new(
+        types: BTreeMap<String, Vec<Member>>,
+        domain: Domain,
+        primary_type: impl Into<String>,
+        message: Value,
+    ) -> Self {
+        Self {
+            types,
+            domain,
+            primary_type: primary_type.into(),
+            message,
+        }
+    }
+
+    /// Returns the domain
+    pub fn domain(&self) -> &Domain {
+        &self.domain
+    }
+
+    /// Returns name of the primary type
+    pub fn primary_type(&self) -> &str {
+        &self.primary_type
+    }
+
+    /// Returns the message
+    pub fn message(&self) -> &Value {
+        &self.message
+    }
+
+    /// Computes message hash which is signed by the account
+    ///
+    /// $H(\text{'StarkNet Message'}, H(\text{domain}), \text{account\\_address}, H(\text{message}))$
+    pub fn message_hash(&self, account_address: FieldElement) -> Result<FieldElement, Error> {
+        self.check_domain_type()?;
+        let mut hasher = Hasher::new(self.domain.revision);
+        hasher.update(STARKNET_MESSAGE);
+        hasher.update(self.domain.hash());
+        hasher.update(account_address);
+        hasher.update(self.struct_hash(&self.primary_type, &self.message)?);
+        Ok(hasher.finalize())
+    }
+
+    /// Computes hash of `value` of struct type `name`
+    pub fn struct_hash(&self, name: &str, value: &Value) -> Result<FieldElement, Error> {
+        self.encode_value(
+            &TypeRef::Custom {
+                name,
+                expected: Some(Kind::Struct),
+            },
+            value,
+        )
+    }
+
+    /// Returns type encoding of struct or enum `name`, i.e. encoding of the type followed by encodings
+    /// of all types it references, sorted by name
+    pub fn encode_type(&self, name: &str) -> Result<String, Error> {
+        let definition = self.definition(name)?;
+        let mut dependencies = BTreeSet::new();
+        self.collect_dependencies(&definition, &mut dependencies)?;
+
+        let mut encoding = String::new();
+        self.write_type(name, &definition, &mut encoding);
+        for dependency in dependencies {
+            let definition = self.definition(dependency)?;
+            self.write_type(dependency, &definition, &mut encoding);
+        }
+        Ok(encoding)
+    }
+
+    /// Returns type hash of struct or enum `name`, i.e. [`sn_keccak`] of its [encoding](Self::encode_type)
+    pub fn type_hash(&self, name: &str) -> Result<FieldElement, Error> {
+        Ok(sn_keccak(self.encode_type(name)?.as_bytes()))
+    }
+
+    fn revision(&self) -> Revision {
+        self.domain.revision
+    }
+
+    /// Checks that definition of the domain type in `types`, if there is one, matches the one
+    /// used by [`Domain::hash`]
+    fn check_domain_type(&self) -> Result<(), Error> {
+        let (name, expected): (_, &[(&str, &str)]) = match self.revision() {
+            Revision::V0 => ("StarkNetDomain", DOMAIN_TYPE_V0),
+            Revision::V1 => ("StarknetDomain", DOMAIN_TYPE_V1),
+        };
+        match self.types.get(name) {
+            Some(members)
+                if members.len() != expected.len()
+                    || members.iter().zip(expected).any(|(member, (name, ty))| {
+                        member.name != *name || member.r#type != *ty || member.contains.is_some()
+                    }) =>
+            {
+                Err(Error(Reason::InvalidDomainType))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn definition<'a>(&'a self, name: &'a str) -> Result<Definition<'a>, Error> {
+        let members: Vec<(&str, &str, Option<&str>)> = match self.types.get(name) {
+            Some(members) => members
+                .iter()
+                .map(|m| (m.name.as_str(), m.r#type.as_str(), m.contains.as_deref()))
+                .collect(),
+            None => PRESET_TYPES
+                .iter()
+                .filter(|_| self.revision() == Revision::V1)
+                .find(|(preset, _)| *preset == name)
+                .ok_or_else(|| Error(Reason::UnknownType(name.to_owned())))?
+                .1
+                .iter()
+                .map(|(name, ty)| (*name, *ty, None))
+                .collect(),
+        };
+
+        let is_enum = !members.is_empty() && members.iter().all(|(_, ty, _)| ty.starts_with('('));
+        if !is_enum {
+            let fields = members
+                .into_iter()
+                .map(|(name, ty, contains)| Ok((name, TypeRef::parse_field(ty, contains)?)))
+                .collect::<Result<_, Error>>()?;
+            return Ok(Definition::Struct(fields));
+        }
+
+        let variants = members
+            .into_iter()
+            .map(|(name, ty, contains)| {
+                let tuple = ty
+                    .strip_prefix('(')
+                    .and_then(|ty| ty.strip_suffix(')'))
+                    .filter(|_| contains.is_none())
+                    .ok_or_else(|| Error(Reason::InvalidType(ty.to_owned())))?;
+                let types = tuple
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|ty| !ty.is_empty())
+                    .map(TypeRef::parse_inline)
+                    .collect::<Result<_, Error>>()?;
+                Ok((name, types))
+            })
+            .collect::<Result<_, Error>>()?;
+        Ok(Definition::Enum(variants))
+    }
+
+    fn collect_dependencies<'a>(
+        &'a self,
+        definition: &Definition<'a>,
+        dependencies: &mut BTreeSet<&'a str>,
+    ) -> Result<(), Error> {
+        let type_refs: Vec<&TypeRef<'a>> = match definition {
+            Definition::Struct(fields) => fields.iter().map(|(_, ty)| ty).collect(),
+            Definition::Enum(variants) => variants.iter().flat_map(|(_, types)| types).collect(),
+        };
+        for type_ref in type_refs {
+            let name = match type_ref {
+                TypeRef::Custom { name, .. } => *name,
+                TypeRef::Array(ty) => match TypeRef::parse_array_element(ty)? {
+                    TypeRef::Custom { name, .. } => name,
+                    _ => continue,
+                },
+                // Following `starknet.js`, leaf type of merkle tree is not included
+                _ => continue,
+            };
+            if dependencies.insert(name) {
+                self.collect_dependencies(&self.definition(name)?, dependencies)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_type(&self, name: &str, definition: &Definition<'_>, out: &mut String) {
+        let revision = self.revision();
+        write_name(name, revision, out);
+        out.push('(');
+        match definition {
+            Definition::Struct(fields) => {
+                for (i, (field, ty)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_name(field, revision, out);
+                    out.push(':');
+                    write_name(ty.as_str(), revision, out);
+                }
+            }
+            Definition::Enum(variants) => {
+                for (i, (variant, types)) in variants.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_name(variant, revision, out);
+                    out.push_str(":(");
+                    for (j, ty) in types.iter().enumerate() {
+                        if j > 0 {
+                            out.push(',');
+                        }
+                        write_name(ty.as_str(), revision, out);
+                    }
+                    out.push(')');
+                }
+            }
+        }
+        out.push(')');
+    }
+
+    fn encode_value(&self, type_ref: &TypeRef<'_>, value: &Value) -> Result<FieldElement, Error> {
+        let unexpected = || {
+            Err(Error(Reason::UnexpectedValue {
+                r#type: type_ref.as_str().to_owned(),
+            }))
+        };
+        match (type_ref, value) {
+            (TypeRef::Custom { name, expected }, Value::Object(entries)) => {
+                match (self.definition(name)?, expected) {
+                    (Definition::Struct(fields), None | Some(Kind::Struct)) => {
+                        self.encode_struct(name, &fields, entries)
+                    }
+                    (Definition::Enum(variants), None | Some(Kind::Enum)) => {
+                        self.encode_enum(&variants, entries)
+                    }
+                    _ => unexpected(),
+                }
+            }
+            (TypeRef::Array(ty), Value::Array(elements)) => {
+                let element = TypeRef::parse_array_element(ty)?;
+                let mut hasher = Hasher::new(self.revision());
+                for value in elements {
+                    hasher.update(self.encode_value(&element, value)?);
+                }
+                Ok(hasher.finalize())
+            }
+            (TypeRef::MerkleTree(leaf), Value::Array(leaves)) => {
+                let leaf = TypeRef::parse_inline(leaf)?;
+                let leaves = leaves
+                    .iter()
+                    .map(|value| self.encode_value(&leaf, value))
+                    .collect::<Result<Vec<_>, _>>()?;
+                merkle_root(self.revision(), leaves)
+            }
+            // Following `starknet.js`, strings which look like a number are parsed as a number
+            (TypeRef::Felt | TypeRef::ShortString, Value::String(s)) => s
+                .parse()
+                .ok()
+                .filter(|_| !s.starts_with("0X"))
+                .or_else(|| cairo::short_string(s))
+                .ok_or_else(|| Error(Reason::InvalidShortString(s.clone()))),
+            (TypeRef::Felt | TypeRef::ShortString, Value::UnsignedInteger(x)) => Ok(felt_u128(*x)),
+            (
+                TypeRef::Felt
+                | TypeRef::ShortString
+                | TypeRef::ContractAddress
+                | TypeRef::ClassHash
+                | TypeRef::Selector,
+                Value::Felt(x),
+            ) => Ok(*x),
+            (TypeRef::ContractAddress | TypeRef::ClassHash, Value::String(s)) => s
+                .parse()
+                .map_err(|_| Error(Reason::InvalidNumber(s.clone()))),
+            (TypeRef::Bool, Value::Boolean(b)) => Ok(FieldElement::from(u64::from(*b))),
+            (TypeRef::String, Value::String(s)) => match self.revision() {
+                Revision::V0 => cairo::short_string(s)
+                    .ok_or_else(|| Error(Reason::InvalidShortString(s.clone()))),
+                Revision::V1 => Ok(poseidon::hash_many(&cairo::byte_array(s.as_bytes()))),
+            },
+            (TypeRef::Selector, Value::String(name)) => Ok(sn_keccak(name.as_bytes())),
+            (TypeRef::U128 | TypeRef::Timestamp, Value::UnsignedInteger(x)) => Ok(felt_u128(*x)),
+            (TypeRef::U128 | TypeRef::Timestamp, Value::String(s)) => s
+                .parse::<FieldElement>()
+                .ok()
+                .filter(fits_u128)
+                .ok_or_else(|| Error(Reason::InvalidNumber(s.clone()))),
+            (TypeRef::U128 | TypeRef::Timestamp, Value::Felt(x)) if fits_u128(x) => Ok(*x),
+            (TypeRef::I128, Value::SignedInteger(x)) => Ok(felt_i128(*x)),
+            (TypeRef::I128, Value::UnsignedInteger(x)) => match i128::try_from(*x) {
+                Ok(x) => Ok(felt_i128(x)),
+                Err(_) => unexpected(),
+            },
+            _ => unexpected(),
+        }
+    }
+
+    fn encode_struct(
+        &self,
+        name: &str,
+        fields: &[(&str, TypeRef<'_>)],
+        entries: &[(String, Value)],
+    ) -> Result<FieldElement, Error> {
+        if fields.len() != entries.len() {
+            return Err(Error(Reason::FieldCountMismatch {
+                r#type: name.to_owned(),
+            }));
+        }
+        let mut hasher = Hasher::new(self.revision());
+        hasher.update(self.type_hash(name)?);
+        for (field, type_ref) in fields {
+            let value = entries
+                .iter()
+                .find(|(name, _)| name == field)
+                .map(|(_, value)| value)
+                .ok_or_else(|| Error(Reason::FieldNotFound((*field).to_owned())))?;
+            hasher.update(self.encode_value(type_ref, value)?);
+        }
+        Ok(hasher.finalize())
+    }
+
+    fn encode_enum(
+        &self,
+        variants: &[(&str, Vec<TypeRef<'_>>)],
+        entries: &[(String, Value)],
+    ) -> Result<FieldElement, Error> {
+        let [(variant, Value::Array(values))] = entries else {
+            return Err(Error(Reason::InvalidEnumValue));
+        };
+        let (index, (_, types)) = variants
+            .iter()
+            .enumerate()
+            .find(|(_, (name, _))| name == variant)
+            .ok_or_else(|| Error(Reason::VariantNotFound(variant.clone())))?;
+        if types.len() != values.len() {
+            return Err(Error(Reason::InvalidEnumValue));
+        }
+
+        // Following `starknet.js`, type hash of the enum is not hashed
+        let mut hasher = Hasher::new(self.revision());
+        hasher.update(FieldElement::from(index as u64));
+        for (type_ref, value) in types.iter().zip(values) {
+            hasher.update(self.encode_value(type_ref, value)?);
+        }
+        Ok(hasher.finalize())
+    }
+}
+
+/// Fields of `StarkNetDomain` type of revision 0
+const DOMAIN_TYPE_V0: &[(&str, &str)] =
+    &[("name", "felt"), ("version", "felt"), ("chainId", "felt")];
+
+/// Fields of `StarknetDomain` type of revision 1
+const DOMAIN_TYPE_V1: &[(&str, &str)] = &[
+    ("name", "shortstring"),
+    ("version", "shortstring"),
+    ("chainId", "shortstring"),
+    ("revision", "shortstring"),
+];
+
+/// Preset types of revision 1, not available in revision 0
+const PRESET_TYPES: &[(&str, &[(&str, &str)])] = &[
+    ("u256", &[("low", "u128"), ("high", "u128")]),
+    (
+        "TokenAmount",
+        &[("token_address", "ContractAddress"), ("amount", "u256")],
+    ),
+    (
+        "NftId",
+        &[
+            ("collection_address", "ContractAddress"),
+            ("token_id", "u256"),
+        ],
+    ),
+];
+
+enum Definition<'a> {
+    Struct(Vec<(&'a str, TypeRef<'a>)>),
+    Enum(Vec<(&'a str, Vec<TypeRef<'a>>)>),
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Struct,
+    Enum,
+}
+
+enum TypeRef<'a> {
+    Felt,
+    ShortString,
+    Bool,
+    String,
+    Selector,
+    U128,
+    I128,
+    Timestamp,
+    ContractAddress,
+    ClassHash,
+    /// Array type `"{element}*"`
+    Array(&'a str),
+    /// Merkle tree with given leaf type
+    MerkleTree(&'a str),
+    Custom {
+        name: &'a str,
+        expected: Option<Kind>,
+    },
+}
+
+impl<'a> TypeRef<'a> {
+    /// Parses type of struct field
+    fn parse_field(ty: &'a str, contains: Option<&'a str>) -> Result<Self, Error> {
+        match (ty, contains) {
+            ("enum", Some(name)) => Ok(Self::Custom {
+                name,
+                expected: Some(Kind::Enum),
+            }),
+            ("merkletree", Some(leaf)) => Ok(Self::MerkleTree(leaf)),
+            (_, Some(_)) | ("enum" | "merkletree", None) => {
+                Err(Error(Reason::InvalidType(ty.to_owned())))
+            }
+            (_, None) => match Self::parse_inline(ty)? {
+                Self::Custom { name, .. } => Ok(Self::Custom {
+                    name,
+                    expected: Some(Kind::Struct),
+                }),
+                type_ref => Ok(type_ref),
+            },
+        }
+    }
+
+    /// Parses type of array element or enum variant
+    fn parse_inline(ty: &'a str) -> Result<Self, Error> {
+        Ok(match ty {
+            "felt" => Self::Felt,
+            "shortstring" => Self::ShortString,
+            "bool" => Self::Bool,
+            "string" => Self::String,
+            "selector" => Self::Selector,
+            "u128" => Self::U128,
+            "i128" => Self::I128,
+            "timestamp" => Self::Timestamp,
+            "ContractAddress" => Self::ContractAddress,
+            "ClassHash" => Self::ClassHash,
+            "" | "enum" | "merkletree" => return Err(Error(Reason::InvalidType(ty.to_owned()))),
+            _ if ty.ends_with('*') => Self::Array(ty),
+            _ => Self::Custom {
+                name: ty,
+                expected: None,
+            },
+        })
+    }
+
+    /// Parses element type of array type `"{element}*"`
+    fn parse_array_element(ty: &'a str) -> Result<Self, Error> {
+        let element = ty.strip_suffix('*').unwrap_or(ty);
+        Self::parse_inline(element)
+    }
+
+    /// Returns type as it appears in type encoding
+    fn as_str(&self) -> &'a str {
+        match self {
+            Self::Felt => "felt",
+            Self::ShortString => "shortstring",
+            Self::Bool => "bool",
+            Self::String => "string",
+            Self::Selector => "selector",
+            Self::U128 => "u128",
+            Self::I128 => "i128",
+            Self::Timestamp => "timestamp",
+            Self::ContractAddress => "ContractAddress",
+            Self::ClassHash => "ClassHash",
+            Self::MerkleTree(_) => "merkletree",
+            Self::Array(ty) => ty,
+            Self::Custom { name, .. } => name,
+        }
+    }
+}
+
+/// Writes name to type encoding, escaping it as JSON string in revision 1
+fn write_name(name: &str, revision: Revision, out: &mut String) {
+    match revision {
+        Revision::V0 => out.push_str(name),
+        Revision::V1 => {
+            out.push('"');
+            for c in name.chars() {
+                match c {
+                    '"' => out.push_str("\\\""),
+                    '\\' => out.push_str("\\\\"),
+                    '\n' => out.push_str("\\n"),
+                    '\r' => out.push_str("\\r"),
+                    '\t' => out.push_str("\\t"),
+                    c if c.is_control() => {
+                        let _ = fmt::Write::write_fmt(out, format_args!("\\u{:04x}", c as u32));
+                    }
+                    c => out.push(c),
+                }
+            }
+            out.push('"');
+        }
+    }
+}
+
+fn merkle_root(revision: Revision, mut layer: Vec<FieldElement>) -> Result<FieldElement, Error> {
+    if layer.is_empty() {
+        return Err(Error(Reason::EmptyMerkleTree));
+    }
+    let hash = |a, b| match revision {
+        Revision::V0 => pedersen::hash(a, b),
+        Revision::V1 => poseidon::hash(a, b),
+    };
+    while layer.len() > 1 {
+        layer = layer
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] if a.to_repr() <= b.to_repr() => hash(*a, *b),
+                [a, b] => hash(*b, *a),
+                [a] => hash(FieldElement::ZERO, *a),
+                _ => unreachable!("chunks are non-empty"),
+            })
+            .collect();
+    }
+    Ok(layer[0])
+}
+
+fn felt_u128(x: u128) -> FieldElement {
+    FieldElement::from_be_bytes_mod_order(&x.to_be_bytes())
+}
+
+fn felt_i128(x: i128) -> FieldElement {
+    let abs = felt_u128(x.unsigned_abs());
+    if x < 0 {
+        -abs
+    } else {
+        abs
+    }
+}
+
+fn fits_u128(x: &FieldElement) -> bool {
+    x.to_repr()[..16].iter().all(|b| *b == 0)
+}
+
+/// Typed data hasher: Pedersen array hash in revision 0, Poseidon hash in revision 1
+enum Hasher {
+    Pedersen { acc: FieldElement, len: u64 },
+    Poseidon(poseidon::Hasher),
+}
+
+impl Hasher {
+    fn new(revision: Revision) -> Self {
+        match revision {
+            Revision::V0 => Self::Pedersen {
+                acc: FieldElement::ZERO,
+                len: 0,
+            },
+            Revision::V1 => Self::Poseidon(poseidon::Hasher::new()),
+        }
+    }
+
+    fn update(&mut self, x: FieldElement) {
+        match self {
+            Self::Pedersen { acc, len } => {
+                *acc = pedersen::hash(*acc, x);
+                *len += 1;
+            }
+            Self::Poseidon(hasher) => hasher.update(x),
+        }
+    }
+
+    fn finalize(self) -> FieldElement {
+        match self {
+            Self::Pedersen { acc, len } => pedersen::hash(acc, FieldElement::from(len)),
+            Self::Poseidon(hasher) => hasher.finalize(),
+        }
+    }
+}
+
+/// Typed data cannot be hashed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error(Reason);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Reason {
+    UnknownType(String),
+    InvalidType(String),
+    UnexpectedValue { r#type: String },
+    FieldNotFound(String),
+    FieldCountMismatch { r#type: String },
+    VariantNotFound(String),
+    InvalidEnumValue,
+    InvalidShortString(String),
+    InvalidNumber(String),
+    EmptyMerkleTree,
+    InvalidDomainType,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Reason::UnknownType(name) => write!(f, "type `{name}` is not defined"),
+            Reason::InvalidType(ty) => write!(f, "invalid type `{ty}`"),
+            Reason::UnexpectedValue { r#type } => {
+                write!(f, "value doesn't match type `{type}`")
+            }
+            Reason::FieldNotFound(name) => write!(f, "value of field `{name}` is missing"),
+            Reason::FieldCountMismatch { r#type } => {
+                write!(f, "number of fields doesn't match definition of `{type}`")
+            }
+            Reason::VariantNotFound(name) => write!(f, "enum variant `{name}` is not defined"),
+            Reason::InvalidEnumValue => {
+                f.write_str("enum value must map a single variant to the array of its values")
+            }
+            Reason::InvalidShortString(s) => write!(f, "`{s}` is not a valid short string"),
+            Reason::InvalidNumber(s) => write!(f, "`{s}` is not a valid number"),
+            Reason::EmptyMerkleTree => f.write_str("merkle tree must have at least one leaf"),
+            Reason::InvalidDomainType => {
+                f.write_str("definition of domain type doesn't match the revision")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cairo;
+
+    #[test]
+    fn starknet_message_prefix_is_short_string() {
+        assert_eq!(
+            Some(super::STARKNET_MESSAGE),
+            cairo::short_string("StarkNet Message")
+        );
+    }
+}
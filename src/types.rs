@@ -0,0 +1,242 @@
+//! Validated Starknet values
+//!
+//! Addresses, class hashes, and storage keys are all field elements, and it's easy to pass one where
+//! another is expected. Types of this module wrap [`FieldElement`] and check its domain once, when the
+//! value is constructed or parsed:
+//!
+//! * [`ContractAddress`] is less than $2^{251} - 256$ ([`ADDRESS_UPPER_BOUND`])
+//! * [`StorageKey`] is less than $2^{251}$
+//! * [`ClassHash`] is any field element
+//!
+//! All types are formatted and parsed like [`FieldElement`]. With `serde` feature enabled, they're
+//! serialized as `0x`-prefixed hex strings, and deserialization fails if the value is out of range.
+//!
+//! ```rust
+//! use stark_curve::types::{ContractAddress, StorageKey};
+//!
+//! let address: ContractAddress = "0x49d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7"
+//!     .parse()
+//!     .unwrap();
+//! let key = "0x800000000000000000000000000000000000000000000000000000000000000";
+//! assert!(key.parse::<StorageKey>().is_err());
+//! println!("{address:#x}");
+//! ```
+//!
+//! [`ADDRESS_UPPER_BOUND`]: crate::account::ADDRESS_UPPER_BOUND
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::account::{reduce_address, ADDRESS_UPPER_BOUND};
+use crate::bigint::U256;
+use crate::FieldElement;
+
+/// Storage keys are less than $2^{251}$
+const STORAGE_KEY_UPPER_BOUND: U256 =
+    U256::from_be_hex("0800000000000000000000000000000000000000000000000000000000000000");
+
+/// Implements conversions, hashing, formatting, parsing, and serde for a wrapper of `FieldElement` with
+/// `TryFrom<FieldElement>` impl
+macro_rules! impl_felt_wrapper {
+    ($ty:ident) => {
+        impl From<$ty> for FieldElement {
+            fn from(x: $ty) -> Self {
+                x.0
+            }
+        }
+
+        impl AsRef<FieldElement> for $ty {
+            fn as_ref(&self) -> &FieldElement {
+                &self.0
+            }
+        }
+
+        impl core::hash::Hash for $ty {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.to_be_bytes().as_ref().hash(state)
+            }
+        }
+
+        impl fmt::LowerHex for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::UpperHex for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::UpperHex::fmt(&self.0, f)
+            }
+        }
+
+        /// Parses decimal or `0x`-prefixed hex number, fails if it's out of range
+        impl FromStr for $ty {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let x = FieldElement::from_str(s).map_err(|_| Error(Reason::Parse))?;
+                Self::try_from(x).map_err(Error::from)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(&format_args!("{:#x}", self.0))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_from_str(deserializer, stringify!($ty))
+            }
+        }
+    };
+}
+
+/// Address of a contract, less than $2^{251} - 256$
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ContractAddress(FieldElement);
+
+impl ContractAddress {
+    /// Checks that `address` is less than $2^{251} - 256$
+    pub fn new(address: FieldElement) -> Result<Self, Error> {
+        if address.to_uint() >= ADDRESS_UPPER_BOUND {
+            return Err(Error(Reason::OutOfRange {
+                what: "contract address",
+                bound: "2^251 - 256",
+            }));
+        }
+        Ok(Self(address))
+    }
+
+    /// Reduces `address` modulo $2^{251} - 256$, the same way Starknet reduces computed addresses
+    pub fn reduce(address: FieldElement) -> Self {
+        Self(reduce_address(address))
+    }
+}
+
+impl TryFrom<FieldElement> for ContractAddress {
+    type Error = Error;
+
+    fn try_from(address: FieldElement) -> Result<Self, Self::Error> {
+        Self::new(address)
+    }
+}
+
+impl_felt_wrapper!(ContractAddress);
+
+/// Hash of a class
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClassHash(FieldElement);
+
+impl ClassHash {
+    /// Wraps `hash`, any field element is a valid class hash
+    pub const fn new(hash: FieldElement) -> Self {
+        Self(hash)
+    }
+}
+
+impl From<FieldElement> for ClassHash {
+    fn from(hash: FieldElement) -> Self {
+        Self(hash)
+    }
+}
+
+impl_felt_wrapper!(ClassHash);
+
+/// Key of contract storage, less than $2^{251}$
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StorageKey(FieldElement);
+
+impl StorageKey {
+    /// Checks that `key` is less than $2^{251}$
+    pub fn new(key: FieldElement) -> Result<Self, Error> {
+        if key.to_uint() >= STORAGE_KEY_UPPER_BOUND {
+            return Err(Error(Reason::OutOfRange {
+                what: "storage key",
+                bound: "2^251",
+            }));
+        }
+        Ok(Self(key))
+    }
+}
+
+impl TryFrom<FieldElement> for StorageKey {
+    type Error = Error;
+
+    fn try_from(key: FieldElement) -> Result<Self, Self::Error> {
+        Self::new(key)
+    }
+}
+
+impl_felt_wrapper!(StorageKey);
+
+/// Deserializes a string with [`FromStr`] impl of `T`
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_from_str<'de, T, D>(
+    deserializer: D,
+    expecting: &'static str,
+) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    D: serde::Deserializer<'de>,
+{
+    struct Visitor<T> {
+        expecting: &'static str,
+        _ty: core::marker::PhantomData<T>,
+    }
+
+    impl<T> serde::de::Visitor<'_> for Visitor<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} as a hex or decimal string", self.expecting)
+        }
+
+        fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<T, E> {
+            s.parse().map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(Visitor {
+        expecting,
+        _ty: core::marker::PhantomData,
+    })
+}
+
+/// Value is not a valid integer, or out of range
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error(Reason);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Reason {
+    Parse,
+    OutOfRange {
+        what: &'static str,
+        bound: &'static str,
+    },
+}
+
+impl From<core::convert::Infallible> for Error {
+    fn from(never: core::convert::Infallible) -> Self {
+        match never {}
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Reason::Parse => f.write_str(
+                "invalid integer: expected decimal or 0x-prefixed hex number less than modulus",
+            ),
+            Reason::OutOfRange { what, bound } => write!(f, "{what} must be less than {bound}"),
+        }
+    }
+}
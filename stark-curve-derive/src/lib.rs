@@ -0,0 +1,179 @@
+//! Derive macros for [stark-curve](https://docs.rs/stark-curve)
+//!
+//! Use them through `stark_curve::cairo::serde` with `derive` feature enabled, rather than depending on
+//! this crate directly.
+
+#![deny(missing_docs)]
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, Generics, Ident};
+
+/// Derives `CairoSerialize` for a struct or an enum
+///
+/// Struct is serialized as the concatenation of its fields. Enum is serialized as the index of the
+/// variant, in declaration order, followed by the fields of the variant.
+#[proc_macro_derive(CairoSerialize)]
+pub fn derive_cairo_serialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_serialize(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `CairoDeserialize` for a struct or an enum
+///
+/// Layout is the same as in `CairoSerialize` derive.
+#[proc_macro_derive(CairoDeserialize)]
+pub fn derive_cairo_deserialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_deserialize(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_serialize(input: DeriveInput) -> syn::Result<TokenStream> {
+    let serde = serde_path();
+    let name = &input.ident;
+    let generics = add_bounds(input.generics.clone(), quote!(#serde::CairoSerialize));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, bindings) = destructure(&data.fields);
+            quote! {
+                let Self #pattern = self;
+                #(#serde::CairoSerialize::serialize(#bindings, output);)*
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().enumerate().map(|(index, variant)| {
+                let index = index as u32;
+                let variant_name = &variant.ident;
+                let (pattern, bindings) = destructure(&variant.fields);
+                quote! {
+                    Self::#variant_name #pattern => {
+                        #serde::CairoSerialize::serialize(&#index, output);
+                        #(#serde::CairoSerialize::serialize(#bindings, output);)*
+                    }
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "unions cannot be serialized",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #serde::CairoSerialize for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn serialize(&self, output: &mut #serde::__private::Vec<::stark_curve::FieldElement>) {
+                #body
+            }
+        }
+    })
+}
+
+fn expand_deserialize(input: DeriveInput) -> syn::Result<TokenStream> {
+    let serde = serde_path();
+    let name = &input.ident;
+    let generics = add_bounds(input.generics.clone(), quote!(#serde::CairoDeserialize));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let construct = construct(&data.fields, &serde);
+            quote! { ::core::result::Result::Ok(Self #construct) }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().enumerate().map(|(index, variant)| {
+                let index = index as u32;
+                let variant_name = &variant.ident;
+                let construct = construct(&variant.fields, &serde);
+                quote! {
+                    #index => ::core::result::Result::Ok(Self::#variant_name #construct),
+                }
+            });
+            let name = name.to_string();
+            quote! {
+                match <u32 as #serde::CairoDeserialize>::deserialize(input)? {
+                    #(#arms)*
+                    index => ::core::result::Result::Err(#serde::Error::unknown_variant(#name, index)),
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "unions cannot be deserialized",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #serde::CairoDeserialize for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn deserialize(
+                input: &mut &[::stark_curve::FieldElement],
+            ) -> ::core::result::Result<Self, #serde::Error> {
+                #body
+            }
+        }
+    })
+}
+
+fn serde_path() -> TokenStream {
+    quote!(::stark_curve::cairo::serde)
+}
+
+/// Adds `bound` to every type parameter
+fn add_bounds(mut generics: Generics, bound: TokenStream) -> Generics {
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+    generics
+}
+
+/// Returns a pattern binding all the fields, and the names of bindings in order of fields
+fn destructure(fields: &Fields) -> (TokenStream, Vec<Ident>) {
+    let bindings: Vec<_> = (0..fields.len())
+        .map(|i| format_ident!("__field{}", i, span = Span::call_site()))
+        .collect();
+    let pattern = match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!({ #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(( #(#bindings),* )),
+        Fields::Unit => quote!(),
+    };
+    (pattern, bindings)
+}
+
+/// Returns an expression constructing the fields in order, to be appended to the path of a struct or
+/// a variant
+fn construct(fields: &Fields, serde: &TokenStream) -> TokenStream {
+    let values = fields
+        .iter()
+        .map(|field| {
+            let ty = &field.ty;
+            quote!(<#ty as #serde::CairoDeserialize>::deserialize(input)?)
+        })
+        .collect::<Vec<_>>();
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!({ #(#names: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(( #(#values),* )),
+        Fields::Unit => quote!(),
+    }
+}
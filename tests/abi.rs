@@ -0,0 +1,257 @@
+#![cfg(feature = "abi")]
+
+use stark_curve::bigint::U256;
+use stark_curve::cairo::abi::{Abi, EventKind, Value};
+use stark_curve::cairo::serde::to_felts;
+use stark_curve::FieldElement;
+
+const ABI: &str = include_str!("data/abi.json");
+
+#[test]
+fn parse_abi() {
+    let abi = Abi::from_json(ABI).unwrap();
+    let mut functions: Vec<_> = abi.functions.iter().map(|f| f.name.as_str()).collect();
+    functions.sort();
+    assert_eq!(
+        functions,
+        ["constructor", "deposit", "find_order", "transfer"]
+    );
+    assert_eq!(abi.structs.len(), 2);
+    assert_eq!(abi.enums.len(), 2);
+    assert!(matches!(
+        abi.event("example::Event").unwrap().kind,
+        EventKind::Enum { .. }
+    ));
+
+    // Sierra classes returned by RPC contain ABI as a string
+    let quoted = serde_json::to_string(ABI).unwrap();
+    assert_eq!(Abi::from_json(&quoted).unwrap(), abi);
+
+    assert!(Abi::from_json("{").is_err());
+}
+
+#[test]
+fn function_calldata() {
+    let abi = Abi::from_json(ABI).unwrap();
+
+    let recipient = FieldElement::from(0x1234);
+    let amount =
+        U256::from_be_hex("00000000000000000000000000000002ffffffffffffffffffffffffffffffff");
+    let args = [Value::Felt(recipient), Value::UnsignedInteger(amount)];
+    let calldata = abi.encode_inputs("transfer", &args).unwrap();
+    assert_eq!(calldata, to_felts(&(recipient, amount)));
+    assert_eq!(abi.decode_inputs("transfer", &calldata).unwrap(), args);
+
+    assert_eq!(
+        abi.decode_outputs("transfer", &[FieldElement::ZERO])
+            .unwrap(),
+        [Value::Boolean(false)]
+    );
+    assert!(abi
+        .decode_outputs("transfer", &[FieldElement::from(2)])
+        .is_err());
+
+    let ids = [1u64, 2].map(FieldElement::from);
+    let args = [
+        Value::Array(ids.iter().copied().map(Value::Felt).collect()),
+        Value::Felt(FieldElement::from(0xabcd)),
+    ];
+    let calldata = abi.encode_inputs("find_order", &args).unwrap();
+    assert_eq!(
+        calldata,
+        to_felts(&(ids.to_vec(), FieldElement::from(0xabcd)))
+    );
+    assert_eq!(abi.decode_inputs("find_order", &calldata).unwrap(), args);
+
+    let calldata = abi
+        .encode_inputs("deposit", &[FieldElement::from(1).into(), u128::MAX.into()])
+        .unwrap();
+    assert_eq!(calldata, to_felts(&(FieldElement::from(1), u128::MAX)));
+}
+
+#[test]
+fn nested_types() {
+    let abi = Abi::from_json(ABI).unwrap();
+    let order = Value::structure([
+        ("maker", Value::Felt(FieldElement::from(0x42))),
+        (
+            "amounts",
+            Value::Array(vec![U256::MAX.into(), 7u128.into()]),
+        ),
+        (
+            "fee",
+            Value::Tuple(vec![3u128.into(), Value::SignedInteger(-1)]),
+        ),
+        ("note", "limit order".into()),
+    ]);
+    let some = Value::variant("Some", order);
+
+    let expected = to_felts(&Some((
+        FieldElement::from(0x42),
+        vec![U256::MAX, U256::from_u8(7)],
+        (3u8, -1i64),
+        String::from("limit order"),
+    )));
+
+    let mut encoded = vec![];
+    abi.encode(
+        "core::option::Option::<example::Order>",
+        &some,
+        &mut encoded,
+    )
+    .unwrap();
+    assert_eq!(encoded, expected);
+    assert_eq!(abi.decode_outputs("find_order", &expected).unwrap(), [some]);
+    assert_eq!(
+        abi.decode_outputs("find_order", &[FieldElement::ONE])
+            .unwrap(),
+        [Value::unit_variant("None")]
+    );
+}
+
+#[test]
+fn struct_event() {
+    let abi = Abi::from_json(ABI).unwrap();
+    let keys = [FieldElement::from(1), FieldElement::from(2)];
+    let data = to_felts(&U256::from_u64(100));
+    assert_eq!(
+        abi.decode_event("example::Transfer", &keys, &data).unwrap(),
+        transfer(keys[0], keys[1], 100)
+    );
+    assert!(abi
+        .decode_event("example::Transfer", &keys[..1], &data)
+        .is_err());
+    assert!(abi
+        .decode_event("example::Transfer", &keys, &to_felts(&[1u8; 3]))
+        .is_err());
+}
+
+#[test]
+fn contract_event() {
+    let abi = Abi::from_json(ABI).unwrap();
+    assert_eq!(abi.contract_event().unwrap().name, "example::Event");
+
+    let [alice, bob] = [0xa11ce, 0xb0b].map(FieldElement::from);
+
+    // Nested variant is preceded by the selector of its name
+    let keys = [selector("Transfer"), alice, bob];
+    let data = to_felts(&U256::from_u64(100));
+    assert_eq!(
+        abi.decode_contract_event(&keys, &data).unwrap(),
+        Value::variant("Transfer", transfer(alice, bob, 100))
+    );
+
+    let keys = [selector("Approval"), alice];
+    let data = to_felts(&(bob, U256::MAX));
+    assert_eq!(
+        abi.decode_contract_event(&keys, &data).unwrap(),
+        Value::variant(
+            "Approval",
+            Value::structure([
+                ("owner", alice.into()),
+                ("spender", bob.into()),
+                ("value", U256::MAX.into()),
+            ])
+        )
+    );
+
+    // Flat variant doesn't have a selector of its own, the component event is matched by its variants
+    let keys = [selector("OwnershipTransferred"), alice, bob];
+    assert_eq!(
+        abi.decode_contract_event(&keys, &[]).unwrap(),
+        Value::variant(
+            "OwnableEvent",
+            Value::variant(
+                "OwnershipTransferred",
+                Value::structure([("previous_owner", alice.into()), ("new_owner", bob.into())])
+            )
+        )
+    );
+
+    // Unknown selector
+    assert!(abi
+        .decode_contract_event(&[selector("Mint"), alice, bob], &data)
+        .is_err());
+    assert!(abi.decode_contract_event(&[], &[]).is_err());
+    // Extra keys
+    assert!(abi
+        .decode_contract_event(&[selector("OwnershipTransferred"), alice, bob, bob], &[])
+        .is_err());
+}
+
+fn transfer(from: FieldElement, to: FieldElement, value: u128) -> Value {
+    Value::structure([
+        ("from", from.into()),
+        ("to", to.into()),
+        ("value", value.into()),
+    ])
+}
+
+fn selector(name: &str) -> FieldElement {
+    let selector = starknet_core::utils::get_selector_from_name(name).unwrap();
+    FieldElement::from_be_bytes(selector.to_bytes_be().into()).unwrap()
+}
+
+#[test]
+fn invalid_values() {
+    let abi = Abi::from_json(ABI).unwrap();
+    let too_big_address = "0x7ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff00";
+
+    let cases = [
+        // Address out of range
+        vec![
+            Value::Felt(too_big_address.parse().unwrap()),
+            Value::UnsignedInteger(U256::ONE),
+        ],
+        // Wrong type
+        vec![Value::Felt(FieldElement::ONE), Value::Boolean(true)],
+        // Wrong number of arguments
+        vec![Value::Felt(FieldElement::ONE)],
+    ];
+    for args in cases {
+        assert!(abi.encode_inputs("transfer", &args).is_err(), "{args:?}");
+    }
+
+    // `NonZero` and integer range
+    let constructor = |decimals: u128| {
+        abi.encode_inputs("constructor", &[FieldElement::ONE.into(), decimals.into()])
+    };
+    assert!(constructor(18).is_ok());
+    assert!(constructor(0).is_err());
+    assert!(constructor(256).is_err());
+
+    assert!(abi.encode_inputs("unknown", &[]).is_err());
+    assert!(abi
+        .decode_inputs("deposit", &[FieldElement::ONE; 3])
+        .is_err());
+
+    let mut output = vec![];
+    for ty in ["example::Unknown", "(core::felt252", "[core::felt252; x]"] {
+        assert!(abi
+            .encode(ty, &Value::Felt(FieldElement::ONE), &mut output)
+            .is_err());
+    }
+    assert!(abi
+        .encode(
+            "core::integer::i8",
+            &Value::SignedInteger(-129),
+            &mut output
+        )
+        .is_err());
+    abi.encode(
+        "[core::integer::i8; 2]",
+        &vec![Value::SignedInteger(-128); 2].into(),
+        &mut output,
+    )
+    .unwrap();
+    assert_eq!(output, to_felts(&[-128i8; 2]));
+}
+
+#[test]
+fn recursive_types_are_rejected() {
+    let abi = Abi::from_json(
+        r#"[{"type": "struct", "name": "Loop", "members": [{"name": "x", "type": "Loop"}]}]"#,
+    )
+    .unwrap();
+    assert!(abi.decode("Loop", &mut &[][..]).is_err());
+}
@@ -0,0 +1,64 @@
+use rand_dev::DevRng;
+use stark_curve::elliptic_curve::Field;
+use stark_curve::{account, FieldElement, Scalar};
+use starknet_crypto::Felt;
+
+mod common;
+use common::{from_felt, to_felt};
+
+#[test]
+fn public_key_matches_starknet_crypto() {
+    let mut rng = DevRng::new();
+
+    for _ in 0..10 {
+        let secret = Scalar::random(&mut rng);
+        let expected =
+            starknet_crypto::get_public_key(&Felt::from_bytes_be(&secret.to_be_bytes().into()));
+        assert_eq!(account::public_key(&secret), from_felt(expected));
+    }
+    assert_eq!(account::public_key(&Scalar::ZERO), FieldElement::ZERO);
+}
+
+#[test]
+fn contract_address_matches_starknet_core() {
+    let mut rng = DevRng::new();
+
+    for len in 0..4 {
+        let deployer = FieldElement::random(&mut rng);
+        let salt = FieldElement::random(&mut rng);
+        let class_hash = FieldElement::random(&mut rng);
+        let calldata: Vec<_> = (0..len).map(|_| FieldElement::random(&mut rng)).collect();
+
+        let expected = starknet_core::utils::get_contract_address(
+            to_felt(salt),
+            to_felt(class_hash),
+            &calldata.iter().copied().map(to_felt).collect::<Vec<_>>(),
+            to_felt(deployer),
+        );
+        let address = account::contract_address(deployer, salt, class_hash, &calldata);
+        assert_eq!(address, from_felt(expected));
+        assert!(address.to_uint() < account::ADDRESS_UPPER_BOUND);
+    }
+}
+
+#[test]
+fn account_addresses() {
+    let mut rng = DevRng::new();
+    let class_hash = FieldElement::random(&mut rng);
+    let public_key = account::public_key(&Scalar::random(&mut rng));
+    let guardian = FieldElement::random(&mut rng);
+
+    let zero = FieldElement::ZERO;
+    assert_eq!(
+        account::openzeppelin_address(class_hash, public_key),
+        account::contract_address(zero, public_key, class_hash, &[public_key])
+    );
+    assert_eq!(
+        account::braavos_address(class_hash, public_key),
+        account::contract_address(zero, public_key, class_hash, &[public_key])
+    );
+    assert_eq!(
+        account::argent_address(class_hash, public_key, guardian),
+        account::contract_address(zero, public_key, class_hash, &[public_key, guardian])
+    );
+}
@@ -0,0 +1,323 @@
+#![cfg(feature = "alloc")]
+
+use rand_dev::DevRng;
+use stark_curve::block::{
+    self, BlockHashVersion, BlockHeader, Event, GasPrices, L1DataAvailabilityMode, MessageToL1,
+    Receipt, StateDiff, TransactionData,
+};
+use stark_curve::elliptic_curve::Field;
+use stark_curve::FieldElement;
+use starknet_core::crypto::compute_hash_on_elements;
+use starknet_core::utils::{cairo_short_string_to_felt, starknet_keccak};
+use starknet_crypto::{pedersen_hash, poseidon_hash, poseidon_hash_many, Felt};
+
+mod common;
+use common::{random_felts, to_felt, to_felts};
+
+#[test]
+fn transaction_commitment() {
+    let mut rng = DevRng::new();
+    let signed = TransactionData {
+        hash: FieldElement::random(&mut rng),
+        signature: random_felts(&mut rng, 2),
+    };
+    let unsigned = TransactionData {
+        hash: FieldElement::random(&mut rng),
+        signature: vec![],
+    };
+
+    let leaf = signed.leaf(BlockHashVersion::Legacy);
+    let expected = pedersen_hash(
+        &to_felt(signed.hash),
+        &compute_hash_on_elements(&to_felts(&signed.signature)),
+    );
+    assert_eq!(to_felt(leaf), expected);
+
+    let leaf = signed.leaf(BlockHashVersion::V0_13_2);
+    let mut expected = vec![to_felt(signed.hash)];
+    expected.extend(to_felts(&signed.signature));
+    assert_eq!(to_felt(leaf), poseidon_hash_many(&expected));
+
+    let leaf = unsigned.leaf(BlockHashVersion::V0_13_2);
+    let expected = poseidon_hash_many(&[to_felt(unsigned.hash), Felt::ZERO]);
+    assert_eq!(to_felt(leaf), expected);
+
+    // Commitment to a single leaf is an edge of length 64 with zero path
+    let commitment =
+        block::transaction_commitment(std::slice::from_ref(&signed), BlockHashVersion::V0_13_2)
+            .unwrap();
+    let expected =
+        poseidon_hash(to_felt(signed.leaf(BlockHashVersion::V0_13_2)), Felt::ZERO) + Felt::from(64);
+    assert_eq!(to_felt(commitment), expected);
+
+    // Leaves 0 and 1 are children of a binary node at the bottom
+    let commitment = block::transaction_commitment(
+        &[signed.clone(), unsigned.clone()],
+        BlockHashVersion::Legacy,
+    )
+    .unwrap();
+    let binary = pedersen_hash(
+        &to_felt(signed.leaf(BlockHashVersion::Legacy)),
+        &to_felt(unsigned.leaf(BlockHashVersion::Legacy)),
+    );
+    let expected = pedersen_hash(&binary, &Felt::ZERO) + Felt::from(63);
+    assert_eq!(to_felt(commitment), expected);
+
+    assert_eq!(
+        block::transaction_commitment(&[], BlockHashVersion::V0_13_2),
+        Ok(FieldElement::ZERO)
+    );
+}
+
+#[test]
+fn event_and_receipt_leaves() {
+    let mut rng = DevRng::new();
+    let event = Event {
+        from_address: FieldElement::random(&mut rng),
+        transaction_hash: FieldElement::random(&mut rng),
+        keys: random_felts(&mut rng, 2),
+        data: random_felts(&mut rng, 3),
+    };
+
+    let expected = compute_hash_on_elements(&[
+        to_felt(event.from_address),
+        compute_hash_on_elements(&to_felts(&event.keys)),
+        compute_hash_on_elements(&to_felts(&event.data)),
+    ]);
+    assert_eq!(to_felt(event.leaf(BlockHashVersion::Legacy)), expected);
+
+    let mut expected = vec![
+        to_felt(event.from_address),
+        to_felt(event.transaction_hash),
+        Felt::from(2),
+    ];
+    expected.extend(to_felts(&event.keys));
+    expected.push(Felt::from(3));
+    expected.extend(to_felts(&event.data));
+    assert_eq!(
+        to_felt(event.leaf(BlockHashVersion::V0_13_2)),
+        poseidon_hash_many(&expected)
+    );
+
+    let message = MessageToL1 {
+        from_address: FieldElement::random(&mut rng),
+        to_address: FieldElement::random(&mut rng),
+        payload: random_felts(&mut rng, 2),
+    };
+    let receipt = Receipt {
+        transaction_hash: FieldElement::random(&mut rng),
+        actual_fee: FieldElement::from(1000),
+        messages_sent: vec![message.clone()],
+        revert_reason: Some("Error in the called contract".to_owned()),
+        l1_gas: FieldElement::from(20),
+        l1_data_gas: FieldElement::from(30),
+    };
+    let mut messages = vec![
+        Felt::ONE,
+        to_felt(message.from_address),
+        to_felt(message.to_address),
+        Felt::TWO,
+    ];
+    messages.extend(to_felts(&message.payload));
+    let expected = poseidon_hash_many(&[
+        to_felt(receipt.transaction_hash),
+        Felt::from(1000),
+        poseidon_hash_many(&messages),
+        starknet_keccak(b"Error in the called contract"),
+        Felt::ZERO,
+        Felt::from(20),
+        Felt::from(30),
+    ]);
+    assert_eq!(to_felt(receipt.leaf()), expected);
+
+    let succeeded = Receipt {
+        revert_reason: None,
+        messages_sent: vec![],
+        ..receipt.clone()
+    };
+    let expected = poseidon_hash_many(&[
+        to_felt(receipt.transaction_hash),
+        Felt::from(1000),
+        poseidon_hash_many(&[Felt::ZERO]),
+        Felt::ZERO,
+        Felt::ZERO,
+        Felt::from(20),
+        Felt::from(30),
+    ]);
+    assert_eq!(to_felt(succeeded.leaf()), expected);
+}
+
+#[test]
+fn state_diff_commitment() {
+    let f = |x: u64| FieldElement::from(x);
+    let state_diff = StateDiff {
+        deployed_contracts: vec![(f(20), f(200)), (f(10), f(100))],
+        declared_classes: vec![(f(1), f(2))],
+        deprecated_declared_classes: vec![f(9), f(8)],
+        storage_diffs: vec![
+            (f(30), vec![(f(5), f(50)), (f(4), f(40))]),
+            (f(40), vec![]),
+            (f(25), vec![(f(1), f(2))]),
+        ],
+        nonces: vec![(f(10), f(1))],
+    };
+    assert_eq!(state_diff.len(), 9);
+
+    let expected = poseidon_hash_many(&[
+        to_felt(FieldElement::from_be_bytes_mod_order(
+            b"STARKNET_STATE_DIFF0",
+        )),
+        Felt::from(2),
+        Felt::from(10),
+        Felt::from(100),
+        Felt::from(20),
+        Felt::from(200),
+        Felt::from(1),
+        Felt::from(1),
+        Felt::from(2),
+        Felt::from(2),
+        Felt::from(8),
+        Felt::from(9),
+        Felt::ONE,
+        Felt::ZERO,
+        Felt::from(2),
+        Felt::from(25),
+        Felt::from(1),
+        Felt::from(1),
+        Felt::from(2),
+        Felt::from(30),
+        Felt::from(2),
+        Felt::from(4),
+        Felt::from(40),
+        Felt::from(5),
+        Felt::from(50),
+        Felt::from(1),
+        Felt::from(10),
+        Felt::from(1),
+    ]);
+    assert_eq!(to_felt(state_diff.commitment()), expected);
+
+    // Order of entries doesn't matter
+    let mut reordered = state_diff.clone();
+    reordered.deployed_contracts.reverse();
+    reordered.storage_diffs.reverse();
+    reordered.storage_diffs[2].1.reverse();
+    assert_eq!(reordered.commitment(), state_diff.commitment());
+
+    // Keys are sorted as integers
+    let big = -FieldElement::ONE;
+    let state_diff = StateDiff {
+        nonces: vec![(big, f(1)), (f(1), f(2))],
+        ..Default::default()
+    };
+    let expected = poseidon_hash_many(&[
+        to_felt(FieldElement::from_be_bytes_mod_order(
+            b"STARKNET_STATE_DIFF0",
+        )),
+        Felt::ZERO,
+        Felt::ZERO,
+        Felt::ZERO,
+        Felt::ONE,
+        Felt::ZERO,
+        Felt::ZERO,
+        Felt::TWO,
+        Felt::ONE,
+        Felt::TWO,
+        to_felt(big),
+        Felt::ONE,
+    ]);
+    assert_eq!(to_felt(state_diff.commitment()), expected);
+}
+
+#[test]
+fn block_hash() {
+    let mut rng = DevRng::new();
+    let mut header = BlockHeader {
+        parent_hash: FieldElement::random(&mut rng),
+        block_number: 700_000,
+        state_root: FieldElement::random(&mut rng),
+        sequencer_address: FieldElement::random(&mut rng),
+        timestamp: 1_720_000_000,
+        transaction_count: 12,
+        event_count: 34,
+        state_diff_length: 56,
+        transaction_commitment: FieldElement::random(&mut rng),
+        event_commitment: FieldElement::random(&mut rng),
+        receipt_commitment: FieldElement::random(&mut rng),
+        state_diff_commitment: FieldElement::random(&mut rng),
+        gas_prices: GasPrices {
+            l1_gas_price_wei: FieldElement::from(1),
+            l1_gas_price_fri: FieldElement::from(2),
+            l1_data_gas_price_wei: FieldElement::from(3),
+            l1_data_gas_price_fri: FieldElement::from(4),
+            l2_gas_price_wei: FieldElement::from(5),
+            l2_gas_price_fri: FieldElement::from(6),
+        },
+        l1_da_mode: L1DataAvailabilityMode::Blob,
+        protocol_version: FieldElement::from_be_bytes_mod_order(b"0.13.2"),
+    };
+
+    let expected = compute_hash_on_elements(&[
+        Felt::from(700_000),
+        to_felt(header.state_root),
+        to_felt(header.sequencer_address),
+        Felt::from(1_720_000_000),
+        Felt::from(12),
+        to_felt(header.transaction_commitment),
+        Felt::from(34),
+        to_felt(header.event_commitment),
+        Felt::ZERO,
+        Felt::ZERO,
+        to_felt(header.parent_hash),
+    ]);
+    assert_eq!(to_felt(header.hash(BlockHashVersion::Legacy)), expected);
+
+    let counts = |da_mode_bit: u64| {
+        let shift = |bits: u32| Felt::TWO.pow(bits);
+        Felt::from(12) * shift(192)
+            + Felt::from(34) * shift(128)
+            + Felt::from(56) * shift(64)
+            + Felt::from(da_mode_bit) * shift(63)
+    };
+    let header_fields = |prefix: &str, counts: Felt, prices: &[Felt]| {
+        let mut fields = vec![
+            cairo_short_string_to_felt(prefix).unwrap(),
+            Felt::from(700_000),
+            to_felt(header.state_root),
+            to_felt(header.sequencer_address),
+            Felt::from(1_720_000_000),
+            counts,
+            to_felt(header.state_diff_commitment),
+            to_felt(header.transaction_commitment),
+            to_felt(header.event_commitment),
+            to_felt(header.receipt_commitment),
+        ];
+        fields.extend(prices);
+        fields.extend([
+            cairo_short_string_to_felt("0.13.2").unwrap(),
+            Felt::ZERO,
+            to_felt(header.parent_hash),
+        ]);
+        poseidon_hash_many(&fields)
+    };
+
+    let l1_prices = [1, 2, 3, 4].map(Felt::from);
+    let expected = header_fields("STARKNET_BLOCK_HASH0", counts(1), &l1_prices);
+    assert_eq!(to_felt(header.hash(BlockHashVersion::V0_13_2)), expected);
+
+    let prices_hash = poseidon_hash_many(&[
+        cairo_short_string_to_felt("STARKNET_GAS_PRICES0").unwrap(),
+        Felt::from(1),
+        Felt::from(2),
+        Felt::from(3),
+        Felt::from(4),
+        Felt::from(5),
+        Felt::from(6),
+    ]);
+    let expected = header_fields("STARKNET_BLOCK_HASH1", counts(1), &[prices_hash]);
+    assert_eq!(to_felt(header.hash(BlockHashVersion::V0_13_4)), expected);
+
+    header.l1_da_mode = L1DataAvailabilityMode::Calldata;
+    let expected = header_fields("STARKNET_BLOCK_HASH0", counts(0), &l1_prices);
+    assert_eq!(to_felt(header.hash(BlockHashVersion::V0_13_2)), expected);
+}
@@ -0,0 +1,52 @@
+use stark_curve::{cairo, hash, FieldElement};
+
+mod common;
+use common::to_felt;
+
+#[test]
+fn short_string() {
+    assert_eq!(
+        cairo::short_string("SN_MAIN"),
+        Some(FieldElement::from(0x534e5f4d41494e))
+    );
+    assert_eq!(cairo::short_string(""), Some(FieldElement::ZERO));
+
+    let max = "a".repeat(cairo::SHORT_STRING_MAX_LEN);
+    assert_eq!(
+        cairo::short_string(&max).map(to_felt),
+        starknet_core::utils::cairo_short_string_to_felt(&max).ok()
+    );
+    assert_eq!(cairo::short_string(&format!("{max}a")), None);
+    assert_eq!(cairo::short_string("ÿ"), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn byte_array() {
+    use starknet_core::codec::Encode;
+
+    for len in [0, 1, 30, 31, 32, 62, 100] {
+        let s: String = (0..len).map(|i| char::from(b'a' + i % 26)).collect();
+
+        let mut expected = vec![];
+        starknet_core::types::ByteArray::from(s.as_str())
+            .encode(&mut expected)
+            .unwrap();
+
+        let actual: Vec<_> = cairo::byte_array(s.as_bytes())
+            .into_iter()
+            .map(to_felt)
+            .collect();
+        assert_eq!(actual, expected, "len: {len}");
+    }
+}
+
+#[test]
+fn sn_keccak() {
+    for name in ["transfer", "balanceOf", ""] {
+        assert_eq!(
+            to_felt(hash::sn_keccak(name.as_bytes())),
+            starknet_core::utils::starknet_keccak(name.as_bytes())
+        );
+    }
+}
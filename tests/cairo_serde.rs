@@ -0,0 +1,170 @@
+#![cfg(feature = "alloc")]
+
+use stark_curve::bigint::U256;
+use stark_curve::cairo::serde::{from_felts, to_felts, CairoDeserialize, CairoSerialize};
+use stark_curve::FieldElement;
+use starknet_core::codec::Encode;
+
+mod common;
+use common::to_felt;
+
+#[test]
+fn primitives_match_reference() {
+    let felt = FieldElement::from(0x1234);
+    let u256 =
+        U256::from_be_hex("000000000000000000000000000000ff00000000000000000000000000000001");
+    let options = [Some(vec![1u32, 2, 3]), None];
+    let array = [7u16, 8, 9];
+
+    let actual = to_felts(&(
+        (felt, true, u8::MAX, u64::MAX, u128::MAX),
+        (u256, -5i128, i128::MIN, options, array),
+    ));
+
+    let mut expected = vec![];
+    to_felt(felt).encode(&mut expected).unwrap();
+    true.encode(&mut expected).unwrap();
+    u8::MAX.encode(&mut expected).unwrap();
+    u64::MAX.encode(&mut expected).unwrap();
+    u128::MAX.encode(&mut expected).unwrap();
+    starknet_core::types::U256::from_words(1, 0xff)
+        .encode(&mut expected)
+        .unwrap();
+    (-5i128).encode(&mut expected).unwrap();
+    i128::MIN.encode(&mut expected).unwrap();
+    for option in [Some(vec![1u32, 2, 3]), None] {
+        option.encode(&mut expected).unwrap();
+    }
+    // Fixed-size arrays aren't prefixed with length, unlike in `starknet_core`
+    for x in array {
+        x.encode(&mut expected).unwrap();
+    }
+
+    assert_eq!(
+        actual.into_iter().map(to_felt).collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[test]
+fn roundtrip() {
+    fn check<T: CairoSerialize + CairoDeserialize + PartialEq + core::fmt::Debug>(value: T) {
+        let serialized = to_felts(&value);
+        assert_eq!(from_felts::<T>(&serialized).unwrap(), value);
+    }
+
+    check(FieldElement::from(42));
+    check((false, 0u8, i8::MIN, i16::MAX, i32::MIN, i64::MAX, i128::MIN));
+    check(U256::MAX);
+    check(vec![Some(1u64), None]);
+    check(Vec::<Vec<u8>>::new());
+    check::<Result<u32, (bool, i64)>>(Ok(1));
+    check::<Result<u32, (bool, i64)>>(Err((true, -1)));
+    for len in [0, 1, 30, 31, 32, 62, 100] {
+        check("ÿ".repeat(len));
+    }
+    check(Box::new([[1u128; 2]; 3]));
+    check(());
+}
+
+#[test]
+fn malformed_input() {
+    let felts = |xs: &[u64]| {
+        xs.iter()
+            .copied()
+            .map(FieldElement::from)
+            .collect::<Vec<_>>()
+    };
+
+    assert!(from_felts::<u8>(&felts(&[256])).is_err());
+    assert!(from_felts::<bool>(&felts(&[2])).is_err());
+    assert!(from_felts::<i8>(&[-FieldElement::from(129)]).is_err());
+    assert_eq!(from_felts::<i8>(&[-FieldElement::from(128)]), Ok(i8::MIN));
+    assert!(from_felts::<i128>(&[FieldElement::from_uint_mod_order(
+        &U256::ONE.shl_vartime(127)
+    )])
+    .is_err());
+    assert!(from_felts::<u64>(&[]).is_err());
+    assert!(from_felts::<u64>(&felts(&[1, 2])).is_err());
+    assert!(from_felts::<Vec<u64>>(&felts(&[3, 1, 2])).is_err());
+    assert!(from_felts::<Option<u64>>(&felts(&[2])).is_err());
+    // Pending word is longer than its declared length
+    assert!(from_felts::<String>(&felts(&[0, 0x6162, 1])).is_err());
+    // Invalid UTF-8
+    assert!(from_felts::<String>(&felts(&[0, 0xff, 1])).is_err());
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use stark_curve::cairo::serde::{from_felts, to_felts, CairoDeserialize, CairoSerialize};
+    use stark_curve::types::ContractAddress;
+    use stark_curve::FieldElement;
+
+    #[derive(CairoSerialize, CairoDeserialize, Debug, PartialEq)]
+    struct Transfer {
+        recipient: ContractAddress,
+        amount: u128,
+        memo: Option<String>,
+    }
+
+    #[derive(CairoSerialize, CairoDeserialize, Debug, PartialEq)]
+    struct Pair<T>(T, T);
+
+    #[derive(CairoSerialize, CairoDeserialize, Debug, PartialEq)]
+    struct Empty;
+
+    #[derive(CairoSerialize, CairoDeserialize, Debug, PartialEq)]
+    enum Action {
+        Noop,
+        Transfer(Transfer),
+        Swap { from: Pair<u8>, to: Pair<u8> },
+        Marker(Empty),
+    }
+
+    #[test]
+    fn struct_layout() {
+        let transfer = Transfer {
+            recipient: "0x1234".parse().unwrap(),
+            amount: 100,
+            memo: None,
+        };
+        let serialized = to_felts(&transfer);
+        assert_eq!(
+            serialized,
+            [0x1234, 100, 1].map(FieldElement::from).to_vec()
+        );
+        assert_eq!(from_felts::<Transfer>(&serialized), Ok(transfer));
+
+        assert_eq!(
+            to_felts(&Pair(1u8, 2u8)),
+            [1, 2].map(FieldElement::from).to_vec()
+        );
+        assert!(to_felts(&Empty).is_empty());
+        assert_eq!(from_felts::<Empty>(&[]), Ok(Empty));
+    }
+
+    #[test]
+    fn enum_layout() {
+        assert_eq!(to_felts(&Action::Noop), [FieldElement::from(0)].to_vec());
+
+        let swap = Action::Swap {
+            from: Pair(1, 2),
+            to: Pair(3, 4),
+        };
+        let serialized = to_felts(&swap);
+        assert_eq!(serialized, [2, 1, 2, 3, 4].map(FieldElement::from).to_vec());
+        assert_eq!(from_felts::<Action>(&serialized), Ok(swap));
+
+        let transfer = Action::Transfer(Transfer {
+            recipient: "0x1".parse().unwrap(),
+            amount: u128::MAX,
+            memo: Some("hello".into()),
+        });
+        assert_eq!(from_felts::<Action>(&to_felts(&transfer)), Ok(transfer));
+        assert_eq!(
+            from_felts::<Action>(&[FieldElement::from(3)]),
+            Ok(Action::Marker(Empty))
+        );
+        assert!(from_felts::<Action>(&[FieldElement::from(4)]).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use rand_dev::DevRng;
+use stark_curve::commitment::{CommitmentKey, VectorCommitmentKey};
+use stark_curve::elliptic_curve::Field;
+use stark_curve::{generators, ProjectivePoint, Scalar};
+
+fn key() -> CommitmentKey {
+    let h = generators::from_pi_digits(3).unwrap();
+    CommitmentKey::new(ProjectivePoint::GENERATOR, h.into())
+}
+
+fn vector_key() -> VectorCommitmentKey<[ProjectivePoint; 3]> {
+    let gs = [3, 4, 5].map(|i| ProjectivePoint::from(generators::from_pi_digits(i).unwrap()));
+    let h = generators::from_pi_digits(6).unwrap();
+    VectorCommitmentKey::new(gs, h.into())
+}
+
+#[test]
+fn commitment_opens() {
+    let mut rng = DevRng::new();
+    let key = key();
+
+    let value = Scalar::random(&mut rng);
+    let (c, r) = key.commit_random(&value, &mut rng);
+
+    assert!(key.verify(&c, &value, &r));
+    assert!(!key.verify(&c, &(value + Scalar::ONE), &r));
+    assert!(!key.verify(&c, &value, &(r + Scalar::ONE)));
+    assert_eq!(
+        *c.as_point(),
+        ProjectivePoint::GENERATOR * value + *key.h() * r
+    );
+}
+
+#[test]
+fn commitment_is_homomorphic() {
+    let mut rng = DevRng::new();
+    let key = key();
+
+    let (v1, v2, k) = (
+        Scalar::random(&mut rng),
+        Scalar::random(&mut rng),
+        Scalar::random(&mut rng),
+    );
+    let (c1, r1) = key.commit_random(&v1, &mut rng);
+    let (c2, r2) = key.commit_random(&v2, &mut rng);
+
+    assert!(key.verify(&(c1 + c2), &(v1 + v2), &(r1 + r2)));
+    assert!(key.verify(&(c1 - c2), &(v1 - v2), &(r1 - r2)));
+    assert!(key.verify(&-c1, &-v1, &-r1));
+    assert!(key.verify(&(c1 * k), &(v1 * k), &(r1 * k)));
+
+    let mut c = c1;
+    c += c2;
+    c -= c1;
+    assert_eq!(c, c2);
+}
+
+#[test]
+fn vector_commitment_opens() {
+    let mut rng = DevRng::new();
+    let key = vector_key();
+
+    let values = [
+        Scalar::random(&mut rng),
+        Scalar::random(&mut rng),
+        Scalar::random(&mut rng),
+    ];
+    let (c, r) = key.commit_random(&values, &mut rng).unwrap();
+
+    let expected =
+        key.gs()[0] * values[0] + key.gs()[1] * values[1] + key.gs()[2] * values[2] + *key.h() * r;
+    assert_eq!(*c.as_point(), expected);
+    assert!(key.verify(&c, &values, &r));
+
+    let mut wrong = values;
+    wrong[1] += Scalar::ONE;
+    assert!(!key.verify(&c, &wrong, &r));
+
+    // Missing values are treated as zeroes
+    let (c, r) = key.commit_random(&values[..2], &mut rng).unwrap();
+    assert!(key.verify(&c, &[values[0], values[1], Scalar::ZERO], &r));
+}
+
+#[test]
+fn vector_commitment_is_homomorphic() {
+    let mut rng = DevRng::new();
+    let key = vector_key();
+
+    let a = [Scalar::random(&mut rng), Scalar::random(&mut rng)];
+    let b = [Scalar::random(&mut rng), Scalar::random(&mut rng)];
+    let (ca, ra) = key.commit_random(&a, &mut rng).unwrap();
+    let (cb, rb) = key.commit_random(&b, &mut rng).unwrap();
+
+    assert!(key.verify(&(ca + cb), &[a[0] + b[0], a[1] + b[1]], &(ra + rb)));
+}
+
+#[test]
+fn vector_commitment_rejects_too_many_values() {
+    let key = vector_key();
+    let values = [Scalar::ONE; 4];
+
+    let err = key.commit(&values, &Scalar::ONE).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "cannot commit to 4 values using 3 generators"
+    );
+
+    let (c, _) = key.commit_random(&values[..3], &mut DevRng::new()).unwrap();
+    assert!(!key.verify(&c, &values, &Scalar::ONE));
+}
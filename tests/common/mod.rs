@@ -0,0 +1,35 @@
+//! Helpers shared by integration tests
+//!
+//! Each test crate uses only some of them.
+#![allow(dead_code)]
+
+use rand_dev::DevRng;
+use stark_curve::elliptic_curve::Field;
+use stark_curve::FieldElement;
+use starknet_crypto::Felt;
+
+/// Parses field element from 64 hex digits without `0x` prefix
+pub fn felt(hex: &str) -> FieldElement {
+    let bytes: [u8; 32] = hex::decode(hex).unwrap().try_into().unwrap();
+    FieldElement::from_be_bytes(bytes.into()).unwrap()
+}
+
+/// Converts field element to `starknet-crypto` felt
+pub fn to_felt(x: FieldElement) -> Felt {
+    Felt::from_bytes_be(&x.to_be_bytes().into())
+}
+
+/// Converts field elements to `starknet-crypto` felts
+pub fn to_felts(xs: &[FieldElement]) -> Vec<Felt> {
+    xs.iter().copied().map(to_felt).collect()
+}
+
+/// Converts `starknet-crypto` felt to field element
+pub fn from_felt(x: Felt) -> FieldElement {
+    FieldElement::from_be_bytes(x.to_bytes_be().into()).unwrap()
+}
+
+/// Samples `n` random field elements
+pub fn random_felts(rng: &mut DevRng, n: usize) -> Vec<FieldElement> {
+    (0..n).map(|_| FieldElement::random(&mut *rng)).collect()
+}
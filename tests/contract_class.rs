@@ -0,0 +1,214 @@
+#![cfg(feature = "alloc")]
+
+use rand_dev::DevRng;
+use stark_curve::contract_class::{
+    BytecodeSegmentLengths, CompiledClass, CompiledEntryPoint, EntryPointsByType, SierraClass,
+    SierraEntryPoint,
+};
+use stark_curve::elliptic_curve::Field;
+use stark_curve::FieldElement;
+use starknet_core::types::contract::{
+    CompiledClassEntrypoint, CompiledClassEntrypointList, IntOrList,
+};
+
+mod common;
+use common::{random_felts, to_felt};
+
+#[test]
+fn sierra_class_hash_matches_starknet_core() {
+    let mut rng = DevRng::new();
+
+    let mut entry_point = |i| SierraEntryPoint {
+        selector: FieldElement::random(&mut rng),
+        function_idx: i,
+    };
+    let entry_points_by_type = EntryPointsByType {
+        external: vec![entry_point(0), entry_point(2)],
+        l1_handler: vec![entry_point(1)],
+        constructor: vec![],
+    };
+    let class = SierraClass {
+        sierra_program: random_felts(&mut rng, 20),
+        entry_points_by_type,
+        abi: r#"[{"type": "function", "name": "foo", "inputs": [], "outputs": [], "state_mutability": "view"}]"#.to_owned(),
+    };
+
+    let convert = |entry_points: &[SierraEntryPoint]| {
+        entry_points
+            .iter()
+            .map(|e| starknet_core::types::SierraEntryPoint {
+                selector: to_felt(e.selector),
+                function_idx: e.function_idx,
+            })
+            .collect()
+    };
+    let expected = starknet_core::types::FlattenedSierraClass {
+        sierra_program: class.sierra_program.iter().copied().map(to_felt).collect(),
+        contract_class_version: "0.1.0".to_owned(),
+        entry_points_by_type: starknet_core::types::EntryPointsByType {
+            constructor: convert(&class.entry_points_by_type.constructor),
+            external: convert(&class.entry_points_by_type.external),
+            l1_handler: convert(&class.entry_points_by_type.l1_handler),
+        },
+        abi: class.abi.clone(),
+    }
+    .class_hash();
+    assert_eq!(to_felt(class.class_hash()), expected);
+}
+
+#[test]
+fn compiled_class_hash_matches_starknet_core() {
+    let mut rng = DevRng::new();
+
+    let mut entry_point = |offset, builtins: &[&str]| CompiledEntryPoint {
+        selector: FieldElement::random(&mut rng),
+        offset,
+        builtins: builtins.iter().map(|b| b.to_string()).collect(),
+    };
+    let entry_points_by_type = EntryPointsByType {
+        external: vec![
+            entry_point(0, &["pedersen", "range_check"]),
+            entry_point(10, &[]),
+        ],
+        l1_handler: vec![],
+        constructor: vec![entry_point(20, &["range_check", "poseidon"])],
+    };
+
+    let segmentations = [
+        None,
+        Some(BytecodeSegmentLengths::Leaf(30)),
+        Some(BytecodeSegmentLengths::Node(vec![
+            BytecodeSegmentLengths::Leaf(10),
+            BytecodeSegmentLengths::Leaf(5),
+            BytecodeSegmentLengths::Leaf(15),
+        ])),
+    ];
+    for bytecode_segment_lengths in segmentations {
+        let class = CompiledClass {
+            bytecode: random_felts(&mut rng, 30),
+            bytecode_segment_lengths,
+            entry_points_by_type: entry_points_by_type.clone(),
+        };
+        assert_eq!(
+            to_felt(class.class_hash().unwrap()),
+            reference_compiled_class(&class).class_hash().unwrap()
+        );
+    }
+}
+
+#[test]
+fn nested_bytecode_segments() {
+    use stark_curve::hash::poseidon::hash_many;
+
+    let mut rng = DevRng::new();
+    let bytecode = random_felts(&mut rng, 10);
+    let class = CompiledClass {
+        bytecode: bytecode.clone(),
+        bytecode_segment_lengths: Some(BytecodeSegmentLengths::Node(vec![
+            BytecodeSegmentLengths::Leaf(3),
+            BytecodeSegmentLengths::Node(vec![
+                BytecodeSegmentLengths::Leaf(2),
+                BytecodeSegmentLengths::Leaf(4),
+            ]),
+            BytecodeSegmentLengths::Leaf(1),
+        ])),
+        entry_points_by_type: EntryPointsByType::default(),
+    };
+
+    let node = |children: &[(u64, FieldElement)]| {
+        let flat: Vec<_> = children
+            .iter()
+            .flat_map(|(len, hash)| [FieldElement::from(*len), *hash])
+            .collect();
+        hash_many(&flat) + FieldElement::ONE
+    };
+    let inner = node(&[
+        (2, hash_many(&bytecode[3..5])),
+        (4, hash_many(&bytecode[5..9])),
+    ]);
+    let bytecode_hash = node(&[
+        (3, hash_many(&bytecode[..3])),
+        (6, inner),
+        (1, hash_many(&bytecode[9..])),
+    ]);
+    let empty = hash_many(&[]);
+    let prefix = FieldElement::from_be_bytes_mod_order(b"COMPILED_CLASS_V1");
+    let expected = hash_many(&[prefix, empty, empty, empty, bytecode_hash]);
+    assert_eq!(class.class_hash().unwrap(), expected);
+}
+
+#[test]
+fn invalid_compiled_class() {
+    let class = CompiledClass {
+        bytecode: vec![FieldElement::ONE; 10],
+        bytecode_segment_lengths: Some(BytecodeSegmentLengths::Node(vec![
+            BytecodeSegmentLengths::Leaf(5),
+            BytecodeSegmentLengths::Leaf(4),
+        ])),
+        entry_points_by_type: EntryPointsByType::default(),
+    };
+    assert_eq!(
+        class.class_hash().unwrap_err().to_string(),
+        "bytecode segments cover 9 elements, but bytecode length is 10"
+    );
+
+    let class = CompiledClass {
+        bytecode_segment_lengths: Some(BytecodeSegmentLengths::Leaf(11)),
+        ..class
+    };
+    assert!(class.class_hash().is_err());
+
+    let class = CompiledClass {
+        bytecode_segment_lengths: None,
+        entry_points_by_type: EntryPointsByType {
+            external: vec![CompiledEntryPoint {
+                selector: FieldElement::ONE,
+                offset: 0,
+                builtins: vec!["segment_arena_builtin_with_a_very_long_name".to_owned()],
+            }],
+            ..Default::default()
+        },
+        ..class
+    };
+    assert_eq!(
+        class.class_hash().unwrap_err().to_string(),
+        "builtin name `segment_arena_builtin_with_a_very_long_name` is not a valid short string"
+    );
+}
+
+fn reference_compiled_class(
+    class: &CompiledClass,
+) -> starknet_core::types::contract::CompiledClass {
+    fn convert_lengths(lengths: &BytecodeSegmentLengths) -> IntOrList {
+        match lengths {
+            BytecodeSegmentLengths::Leaf(len) => IntOrList::Int(*len),
+            BytecodeSegmentLengths::Node(inner) => {
+                IntOrList::List(inner.iter().map(convert_lengths).collect())
+            }
+        }
+    }
+    let convert = |entry_points: &[CompiledEntryPoint]| {
+        entry_points
+            .iter()
+            .map(|e| CompiledClassEntrypoint {
+                selector: to_felt(e.selector),
+                offset: e.offset,
+                builtins: e.builtins.clone(),
+            })
+            .collect()
+    };
+
+    starknet_core::types::contract::CompiledClass {
+        prime: "0x800000000000011000000000000000000000000000000000000000000000001".to_owned(),
+        compiler_version: "2.6.0".to_owned(),
+        bytecode: class.bytecode.iter().copied().map(to_felt).collect(),
+        bytecode_segment_lengths: class.bytecode_segment_lengths.as_ref().map(convert_lengths),
+        hints: vec![],
+        pythonic_hints: None,
+        entry_points_by_type: CompiledClassEntrypointList {
+            external: convert(&class.entry_points_by_type.external),
+            l1_handler: convert(&class.entry_points_by_type.l1_handler),
+            constructor: convert(&class.entry_points_by_type.constructor),
+        },
+    }
+}
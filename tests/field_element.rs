@@ -0,0 +1,246 @@
+use rand::Rng;
+use rand_dev::DevRng;
+use stark_curve::bigint::{Encoding, U256};
+use stark_curve::ff::{Field, PrimeField};
+use stark_curve::{FieldElement, Scalar};
+use subtle::{ConstantTimeGreater, ConstantTimeLess};
+
+mod common;
+use common::to_felt;
+
+fn random_felt(rng: &mut DevRng, bits: usize) -> FieldElement {
+    let bytes: [u8; 32] = rng.gen();
+    FieldElement::from_uint_mod_order(&(U256::from_be_bytes(bytes) >> (256 - bits)))
+}
+
+fn to_starknet_ff(x: FieldElement) -> starknet_ff::FieldElement {
+    starknet_ff::FieldElement::from_bytes_be(&x.to_be_bytes().into()).unwrap()
+}
+
+fn from_starknet_ff(x: starknet_ff::FieldElement) -> FieldElement {
+    FieldElement::from_be_bytes(x.to_bytes_be().into()).unwrap()
+}
+
+#[test]
+fn hex_formatting() {
+    assert_eq!(format!("{:x}", FieldElement::ZERO), "0");
+    assert_eq!(format!("{:#x}", FieldElement::ZERO), "0x0");
+    assert_eq!(format!("{:#x}", FieldElement::from(0xabcdef)), "0xabcdef");
+    assert_eq!(format!("{:#X}", FieldElement::from(0xabcdef)), "0xABCDEF");
+    assert_eq!(format!("{:#010x}", FieldElement::from(0xab)), "0x000000ab");
+    assert_eq!(
+        format!("{:#x}", -FieldElement::ONE),
+        "0x800000000000011000000000000000000000000000000000000000000000000"
+    );
+
+    let mut rng = DevRng::new();
+    let x = FieldElement::random(&mut rng);
+    assert_eq!(format!("{x:#x}"), format!("{:#x}", to_felt(x)));
+}
+
+#[test]
+fn parse_integers() {
+    assert_eq!("0x1a2B".parse(), Ok(FieldElement::from(0x1a2b)));
+    assert_eq!("0X10".parse(), Ok(FieldElement::from(16)));
+    assert_eq!("6699".parse(), Ok(FieldElement::from(6699)));
+    assert_eq!("0".parse(), Ok(FieldElement::ZERO));
+    assert_eq!(
+        "0x800000000000011000000000000000000000000000000000000000000000000".parse(),
+        Ok(-FieldElement::ONE)
+    );
+    assert_eq!(
+        "3618502788666131213697322783095070105526743751716087489154079457884512865582".parse(),
+        Ok(-Scalar::ONE)
+    );
+
+    for invalid in [
+        "",
+        "0x",
+        "-1",
+        "12a",
+        "0x0g",
+        " 1",
+        "0x800000000000011000000000000000000000000000000000000000000000001",
+        "0x10000000000000000000000000000000000000000000000000000000000000000",
+    ] {
+        assert!(invalid.parse::<FieldElement>().is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn integer_division() {
+    let mut rng = DevRng::new();
+
+    for _ in 0..100 {
+        let bits = rng.gen_range(1..=252);
+        let x = random_felt(&mut rng, 252);
+        let y = random_felt(&mut rng, bits);
+        if y == FieldElement::ZERO {
+            continue;
+        }
+
+        let (quotient, remainder) = x.div_rem(&y).unwrap();
+        assert_eq!(quotient * y + remainder, x);
+        assert!(remainder.to_uint() < y.to_uint());
+        assert_eq!(x.floor_div(&y), Some(quotient));
+        assert_eq!(x % y, remainder);
+
+        let (x_ff, y_ff) = (to_starknet_ff(x), to_starknet_ff(y));
+        assert_eq!(from_starknet_ff(x_ff.floor_div(y_ff)), quotient);
+        assert_eq!(from_starknet_ff(x_ff % y_ff), remainder);
+    }
+
+    let max = -FieldElement::ONE;
+    assert_eq!(
+        max.floor_div(&FieldElement::from(2)),
+        Some(FieldElement::TWO_INV - FieldElement::ONE)
+    );
+    assert_eq!(max % FieldElement::from(2), FieldElement::ZERO);
+    assert_eq!(
+        FieldElement::from(7).div_rem(&max),
+        Some((FieldElement::ZERO, FieldElement::from(7)))
+    );
+    assert_eq!(max.div_rem(&FieldElement::ZERO), None);
+    assert_eq!(max.floor_div(&FieldElement::ZERO), None);
+}
+
+#[test]
+#[should_panic]
+fn remainder_by_zero() {
+    let _ = FieldElement::ONE % FieldElement::ZERO;
+}
+
+#[test]
+fn bitwise() {
+    let mut rng = DevRng::new();
+
+    for _ in 0..100 {
+        let x = random_felt(&mut rng, 252);
+        let y = random_felt(&mut rng, 252);
+        let expected = x.to_uint() & y.to_uint();
+        assert_eq!((x & y).to_uint(), expected);
+        assert_eq!(
+            from_starknet_ff(to_starknet_ff(x) & to_starknet_ff(y)),
+            x & y
+        );
+
+        // Results of OR and XOR always fit into the field if operands are less than 2^251
+        let x = random_felt(&mut rng, 251);
+        let y = random_felt(&mut rng, 251);
+        assert_eq!((x | y).to_uint(), x.to_uint() | y.to_uint());
+        assert_eq!((x ^ y).to_uint(), x.to_uint() ^ y.to_uint());
+        assert_eq!(
+            from_starknet_ff(to_starknet_ff(x) | to_starknet_ff(y)),
+            x | y
+        );
+    }
+
+    // 2^251 | 2^251 - 1 = 2^252 - 1 is greater than p
+    let high = FieldElement::ONE << 251;
+    let low = high - FieldElement::ONE;
+    assert_eq!(high.checked_bitor(&low), None);
+    assert_eq!(high.checked_bitxor(&low), None);
+    assert_eq!(high.checked_bitxor(&high), Some(FieldElement::ZERO));
+
+    let mut x = FieldElement::from(0b1100);
+    x &= FieldElement::from(0b1010);
+    assert_eq!(x, FieldElement::from(0b1000));
+    x |= FieldElement::from(0b0011);
+    assert_eq!(x, FieldElement::from(0b1011));
+    x ^= FieldElement::from(0b0110);
+    assert_eq!(x, FieldElement::from(0b1101));
+}
+
+#[test]
+fn bitwise_not() {
+    let max = -FieldElement::ONE;
+    let not_max = (U256::MAX >> 4).wrapping_sub(&max.to_uint());
+    assert_eq!(
+        max.checked_not(),
+        Some(FieldElement::from_uint_mod_order(&not_max))
+    );
+    assert_eq!(max.checked_not().unwrap().checked_not(), Some(max));
+    assert_eq!(FieldElement::ZERO.checked_not(), None);
+    assert_eq!(FieldElement::ONE.checked_not(), None);
+}
+
+#[test]
+fn shifts() {
+    let mut rng = DevRng::new();
+
+    for _ in 0..100 {
+        let x = random_felt(&mut rng, 252);
+        let n = rng.gen_range(0..300);
+        assert_eq!((x >> n).to_uint(), x.to_uint() >> n);
+
+        let mut y = x;
+        y >>= n;
+        assert_eq!(y, x >> n);
+    }
+
+    let x = FieldElement::from(0b101);
+    assert_eq!(x << 3, FieldElement::from(0b101000));
+    assert_eq!((x << 248) >> 248, x);
+    assert_eq!(x.checked_shl(249), None);
+    assert_eq!(x.checked_shl(250), None);
+    assert_eq!(x.checked_shl(255), None);
+    assert_eq!(x.checked_shl(1000), None);
+    assert_eq!(
+        FieldElement::ZERO.checked_shl(1000),
+        Some(FieldElement::ZERO)
+    );
+    assert_eq!(
+        FieldElement::ONE << 251,
+        FieldElement::from(2).pow_vartime([251])
+    );
+    // 2^251 * 2 is greater than p, it must not be reduced
+    assert_eq!((FieldElement::ONE << 251).checked_shl(1), None);
+    assert_eq!((-FieldElement::ONE) >> 252, FieldElement::ZERO);
+
+    let mut y = x;
+    y <<= 2;
+    assert_eq!(y, FieldElement::from(0b10100));
+}
+
+#[test]
+#[should_panic]
+fn shift_left_overflow() {
+    let _ = FieldElement::from(2) << 251;
+}
+
+#[test]
+fn integer_ordering() {
+    let mut rng = DevRng::new();
+
+    for _ in 0..100 {
+        let (x_bits, y_bits) = (rng.gen_range(1..=252), rng.gen_range(1..=252));
+        let x = random_felt(&mut rng, x_bits);
+        let y = random_felt(&mut rng, y_bits);
+        let expected = x.to_uint().cmp(&y.to_uint());
+        assert_eq!(x.cmp(&y), expected);
+        assert_eq!(x.partial_cmp(&y), Some(expected));
+        assert_eq!(bool::from(x.ct_gt(&y)), expected.is_gt());
+        assert_eq!(bool::from(x.ct_lt(&y)), expected.is_lt());
+
+        let s = Scalar::random(&mut rng);
+        let t = Scalar::random(&mut rng);
+        assert_eq!(s.cmp(&t), s.to_uint().cmp(&t.to_uint()));
+        assert_eq!(bool::from(s.ct_lt(&t)), s.to_uint() < t.to_uint());
+    }
+
+    let max = -FieldElement::ONE;
+    assert!(FieldElement::ZERO < FieldElement::ONE);
+    assert!(FieldElement::from(u64::MAX) < max);
+    assert!(bool::from(max.ct_gt(&FieldElement::from(u64::MAX))));
+    assert!(!bool::from(max.ct_gt(&max)));
+    assert!(!bool::from(max.ct_lt(&max)));
+
+    let mut xs: Vec<_> = (0..100)
+        .map(|_| {
+            let bits = rng.gen_range(1..=252);
+            random_felt(&mut rng, bits)
+        })
+        .collect();
+    xs.sort();
+    assert!(xs.windows(2).all(|w| w[0].to_uint() <= w[1].to_uint()));
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "alloc")]
+
+use rand_dev::DevRng;
+use stark_curve::elliptic_curve::Field;
+use stark_curve::frost::{
+    self, FrostHash, Identifier, KeyPackage, PublicKeyPackage, Session, SignatureShare,
+};
+use stark_curve::schnorr::{PoseidonChallenge, Signature};
+use stark_curve::secret_sharing::{self, Share};
+use stark_curve::{FieldElement, ProjectivePoint, Scalar};
+
+#[test]
+fn any_t_participants_can_sign() {
+    let mut rng = DevRng::new();
+    let (key_packages, public_key_package) = frost::keygen_with_dealer(2, 3, &mut rng).unwrap();
+    let message = [FieldElement::random(&mut rng)];
+
+    for signers in [[0, 1], [0, 2], [1, 2], [2, 0]] {
+        println!("signers: {signers:?}");
+        let signers: Vec<_> = signers.iter().map(|i| &key_packages[*i]).collect();
+        let signature =
+            sign::<PoseidonChallenge>(&mut rng, &signers, &public_key_package, &message);
+        public_key_package
+            .group_key()
+            .verify::<PoseidonChallenge>(&message, &signature)
+            .unwrap();
+    }
+
+    // More than t signers is fine too
+    let signers: Vec<_> = key_packages.iter().collect();
+    let signature = sign::<PoseidonChallenge>(&mut rng, &signers, &public_key_package, &message);
+    public_key_package
+        .group_key()
+        .verify::<PoseidonChallenge>(&message, &signature)
+        .unwrap();
+}
+
+#[cfg(feature = "hash2curve")]
+#[test]
+fn sha256_threshold_signing() {
+    type Sha256Challenge = stark_curve::schnorr::ExpandMsgChallenge<
+        primeorder::elliptic_curve::hash2curve::ExpandMsgXmd<sha2::Sha256>,
+    >;
+
+    let mut rng = DevRng::new();
+    let (key_packages, public_key_package) = frost::keygen_with_dealer(3, 5, &mut rng).unwrap();
+    let signers = [&key_packages[4], &key_packages[1], &key_packages[2]];
+
+    let signature = sign::<Sha256Challenge>(&mut rng, &signers, &public_key_package, b"withdraw");
+    public_key_package
+        .group_key()
+        .verify::<Sha256Challenge>(b"withdraw", &signature)
+        .unwrap();
+}
+
+#[test]
+fn shares_reconstruct_group_key() {
+    let mut rng = DevRng::new();
+    let (key_packages, public_key_package) = frost::keygen_with_dealer(3, 4, &mut rng).unwrap();
+
+    let shares: Vec<_> = key_packages[1..]
+        .iter()
+        .map(|key| Share {
+            index: *key.identifier().as_scalar(),
+            value: *key.signing_share(),
+        })
+        .collect();
+    let secret = secret_sharing::reconstruct(&shares).unwrap();
+    assert_eq!(
+        (ProjectivePoint::GENERATOR * secret).to_affine(),
+        *public_key_package.group_key().as_point()
+    );
+}
+
+#[test]
+fn invalid_share_identifies_culprit() {
+    let mut rng = DevRng::new();
+    let (key_packages, public_key_package) = frost::keygen_with_dealer(2, 3, &mut rng).unwrap();
+    let message = [FieldElement::ONE];
+    let signers = [&key_packages[0], &key_packages[1]];
+
+    let (nonces, commitments) = round_one(&mut rng, &signers);
+    let session =
+        Session::new::<PoseidonChallenge>(public_key_package.group_key(), &commitments, &message)
+            .unwrap();
+    let mut shares: Vec<_> = signers
+        .iter()
+        .zip(nonces)
+        .map(|(key, nonces)| (key.identifier(), session.sign(nonces, key).unwrap()))
+        .collect();
+
+    shares[1].1 = SignatureShare(shares[1].1 .0 + Scalar::ONE);
+    let err = session.aggregate(&shares, &public_key_package).unwrap_err();
+    assert_eq!(err.culprit(), Some(key_packages[1].identifier()));
+
+    let err = session
+        .aggregate(&shares[..1], &public_key_package)
+        .unwrap_err();
+    assert_eq!(err.culprit(), None);
+}
+
+#[test]
+fn session_validation() {
+    let mut rng = DevRng::new();
+    let (key_packages, public_key_package) = frost::keygen_with_dealer(2, 3, &mut rng).unwrap();
+    let message = [FieldElement::ONE];
+    let group_key = public_key_package.group_key();
+
+    // Not enough signers
+    let (mut nonces, commitments) = round_one(&mut rng, &[&key_packages[0]]);
+    let session = Session::new::<PoseidonChallenge>(group_key, &commitments, &message).unwrap();
+    let err = session
+        .sign(nonces.pop().unwrap(), &key_packages[0])
+        .unwrap_err();
+    assert_eq!(err.to_string(), "there are fewer signers than threshold");
+
+    // Duplicated identifiers
+    let (_, commitments) = round_one(&mut rng, &[&key_packages[0], &key_packages[0]]);
+    let err = Session::new::<PoseidonChallenge>(group_key, &commitments, &message).unwrap_err();
+    assert_eq!(err.to_string(), "identifiers are not distinct");
+
+    // Signer is not part of the session
+    let (mut nonces, commitments) = round_one(&mut rng, &[&key_packages[0], &key_packages[1]]);
+    let session = Session::new::<PoseidonChallenge>(group_key, &commitments, &message).unwrap();
+    let err = session
+        .sign(nonces.pop().unwrap(), &key_packages[2])
+        .unwrap_err();
+    assert_eq!(err.to_string(), "participant is not part of the session");
+
+    // Nonces don't match commitments
+    let err = session
+        .sign(nonces.pop().unwrap(), &key_packages[1])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "signing nonces do not match commitments in the session"
+    );
+
+    assert!(frost::keygen_with_dealer(4, 3, &mut rng).is_err());
+    assert!(Identifier::try_from(0u16).is_err());
+}
+
+fn round_one(
+    rng: &mut DevRng,
+    signers: &[&KeyPackage],
+) -> (
+    Vec<frost::SigningNonces>,
+    Vec<(Identifier, frost::SigningCommitments)>,
+) {
+    signers
+        .iter()
+        .map(|key| {
+            let (nonces, commitments) = frost::commit(rng);
+            (nonces, (key.identifier(), commitments))
+        })
+        .unzip()
+}
+
+fn sign<H: FrostHash>(
+    rng: &mut DevRng,
+    signers: &[&KeyPackage],
+    public_key_package: &PublicKeyPackage,
+    message: &H::Message,
+) -> Signature {
+    let (nonces, commitments) = round_one(rng, signers);
+    let session = Session::new::<H>(public_key_package.group_key(), &commitments, message).unwrap();
+
+    let shares: Vec<_> = signers
+        .iter()
+        .zip(nonces)
+        .map(|(key, nonces)| (key.identifier(), session.sign(nonces, key).unwrap()))
+        .collect();
+    session.aggregate(&shares, public_key_package).unwrap()
+}
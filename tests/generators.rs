@@ -0,0 +1,103 @@
+use primeorder::elliptic_curve::sec1::ToEncodedPoint;
+use stark_curve::{constants, generators, AffinePoint, FieldElement};
+
+#[test]
+fn pi_digits_reproduce_curve_constants() {
+    let expected = [
+        (
+            1,
+            constants::SHIFT_POINT,
+            starknet_curve::curve_params::SHIFT_POINT,
+        ),
+        (
+            2,
+            constants::GENERATOR,
+            starknet_curve::curve_params::GENERATOR,
+        ),
+        (
+            3,
+            constants::PEDERSEN_P0,
+            starknet_curve::curve_params::PEDERSEN_P0,
+        ),
+        (
+            4,
+            constants::PEDERSEN_P1,
+            starknet_curve::curve_params::PEDERSEN_P1,
+        ),
+        (
+            5,
+            constants::PEDERSEN_P2,
+            starknet_curve::curve_params::PEDERSEN_P2,
+        ),
+        (
+            6,
+            constants::PEDERSEN_P3,
+            starknet_curve::curve_params::PEDERSEN_P3,
+        ),
+    ];
+
+    for (index, (x, y), reference) in expected {
+        println!("index: {index}");
+        let derived = generators::from_pi_digits(index).unwrap();
+        let (derived_x, derived_y) = coords(&derived);
+
+        assert_eq!(derived_x, x);
+        assert_eq!(derived_y, y);
+        assert_eq!(x.to_be_bytes().0, reference.x.to_bytes_be());
+        assert_eq!(y.to_be_bytes().0, reference.y.to_bytes_be());
+    }
+
+    assert_eq!(
+        generators::from_pi_digits(2).unwrap(),
+        AffinePoint::GENERATOR
+    );
+    assert!(generators::from_pi_digits(7).is_none());
+    assert!(generators::from_pi_digits(usize::MAX).is_none());
+}
+
+#[test]
+fn decimal_digits_are_validated() {
+    assert!(generators::from_decimal_digits(b"12a4").is_none());
+    assert!(generators::from_decimal_digits(b"-1").is_none());
+    assert_eq!(
+        generators::from_decimal_digits(b"0"),
+        Some(generators::increment_to_curve(FieldElement::ZERO))
+    );
+}
+
+#[test]
+fn increment_to_curve_picks_smallest_x() {
+    let p = generators::from_pi_digits(3).unwrap();
+    let (x, _) = coords(&p);
+
+    // PEDERSEN_P0 was found after incrementing x by one
+    let x_minus_one = x - FieldElement::ONE;
+    assert_eq!(generators::increment_to_curve(x_minus_one), p);
+    assert_eq!(generators::increment_to_curve(x), p);
+}
+
+#[cfg(feature = "hash2curve")]
+#[test]
+fn points_derived_from_labels_are_distinct() {
+    use primeorder::elliptic_curve::hash2curve::ExpandMsgXmd;
+    use sha2::Sha256;
+
+    let dst: &[&[u8]] = &[b"STARK-CURVE-TEST-GENERATORS"];
+    let derive =
+        |label: &[u8]| generators::from_label::<ExpandMsgXmd<Sha256>>(&[label], dst).unwrap();
+
+    let h0 = derive(b"H0");
+    let h1 = derive(b"H1");
+
+    assert_eq!(h0, derive(b"H0"));
+    assert_ne!(h0, h1);
+    assert_ne!(h0, AffinePoint::GENERATOR);
+    assert!(!bool::from(h0.is_identity()));
+}
+
+fn coords(point: &AffinePoint) -> (FieldElement, FieldElement) {
+    let encoded = point.to_encoded_point(false);
+    let x = FieldElement::from_be_bytes((*encoded.x().unwrap()).into()).unwrap();
+    let y = FieldElement::from_be_bytes((*encoded.y().unwrap()).into()).unwrap();
+    (x, y)
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "hash2curve")]
+
+use rand::RngCore;
+use rand_dev::DevRng;
+use sha2::Sha256;
+use stark_curve::bigint::{Encoding, NonZero, U384};
+use stark_curve::elliptic_curve::hash2curve::{hash_to_field, ExpandMsgXmd, FromOkm};
+use stark_curve::elliptic_curve::PrimeField;
+use stark_curve::generic_array::GenericArray;
+use stark_curve::{FieldElement, Scalar};
+
+#[test]
+fn from_okm_reduces_modulo_order() {
+    let mut rng = DevRng::new();
+
+    for _ in 0..100 {
+        let mut okm = GenericArray::default();
+        rng.fill_bytes(&mut okm);
+
+        assert_eq!(
+            FieldElement::from_okm(&okm).to_repr().as_slice(),
+            reduce::<FieldElement>(&okm)
+        );
+        assert_eq!(
+            Scalar::from_okm(&okm).to_repr().as_slice(),
+            reduce::<Scalar>(&okm)
+        );
+    }
+
+    let max = GenericArray::from([0xff; 48]);
+    assert_eq!(
+        FieldElement::from_okm(&max).to_repr().as_slice(),
+        reduce::<FieldElement>(&max)
+    );
+    assert_eq!(
+        Scalar::from_okm(&max).to_repr().as_slice(),
+        reduce::<Scalar>(&max)
+    );
+}
+
+#[test]
+fn hash_to_field_is_domain_separated() {
+    let msg: &[&[u8]] = &[b"transcript message"];
+
+    let hash = |dst: &'static [u8]| {
+        let mut out = [FieldElement::ZERO; 2];
+        hash_to_field::<ExpandMsgXmd<Sha256>, _>(msg, &[dst], &mut out).unwrap();
+        out
+    };
+
+    let a = hash(b"STARK-CURVE-TEST-DST-A");
+    let a2 = hash(b"STARK-CURVE-TEST-DST-A");
+    let b = hash(b"STARK-CURVE-TEST-DST-B");
+
+    assert_eq!(a, a2);
+    assert_ne!(a[0], a[1]);
+    assert_ne!(a[0], b[0]);
+    assert_ne!(a[1], b[1]);
+
+    let mut scalars = [Scalar::ZERO; 2];
+    hash_to_field::<ExpandMsgXmd<Sha256>, _>(msg, &[b"STARK-CURVE-TEST-DST-A"], &mut scalars)
+        .unwrap();
+    assert_ne!(scalars[0], Scalar::ZERO);
+    assert_ne!(scalars[0], scalars[1]);
+}
+
+/// Reduces 48 bytes integer modulo `F::MODULUS` using big integer arithmetic, returns
+/// 32 bytes big-endian encoding of the result
+fn reduce<F: PrimeField>(okm: &[u8]) -> [u8; 32] {
+    let modulus = F::MODULUS.strip_prefix("0x").unwrap();
+    let modulus = U384::from_be_slice(&hex::decode(format!("{modulus:0>96}")).unwrap());
+    let n = U384::from_be_slice(okm);
+    let r = n.rem(&NonZero::new(modulus).unwrap());
+    r.to_be_bytes()[16..].try_into().unwrap()
+}
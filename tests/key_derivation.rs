@@ -0,0 +1,104 @@
+use hex_literal::hex;
+use stark_curve::key_derivation::{self, grind_key, Eip2645Path};
+use stark_curve::Scalar;
+
+struct GrindKeyVector {
+    seed: &'static [u8],
+    key: [u8; 32],
+}
+
+const GRIND_KEY_VECTORS: &[GrindKeyVector] = &[
+    // Taken from noble-curves stark test suite
+    GrindKeyVector {
+        seed: &hex!("86F3E7293141F20A 8BAFF320E8EE4ACC B9D4A4BF2B4D295E 8CEE784DB46E0519"),
+        key: hex!("05c8c8683596c732 541a59e03007b2d3 0dbbbb873556fe65 b5fb63c16688f941"),
+    },
+    // First candidate is rejected
+    GrindKeyVector {
+        seed: &hex!("8121548be80ce485 69c0cca7273604a9 aa76e9024f88f894 d0d04dd686e13d79"),
+        key: hex!("023d959d5acabfbc 4eb9b78b60ada15f 3ee636e3a3614f75 10071c8b4ff270bb"),
+    },
+    // Leading zeroes of the seed are hashed
+    GrindKeyVector {
+        seed: &hex!("0011111111111111 1111111111111111 1111111111111111 1111111111111111"),
+        key: hex!("06a165d4d3b29019 eef75378dab833c2 138cd00fff83007e a9f9842c3000eb63"),
+    },
+    GrindKeyVector {
+        seed: &hex!("11111111111111 1111111111111111 1111111111111111 1111111111111111"),
+        key: hex!("03cb3d83ea54f849 e0cc6f72ec87a198 1e022fdd6954d354 8c63a0b9e3b08771"),
+    },
+];
+
+#[test]
+fn grind_key_vectors() {
+    for vector in GRIND_KEY_VECTORS {
+        let expected = Scalar::from_be_bytes(vector.key.into()).unwrap();
+        assert_eq!(grind_key(vector.seed), expected);
+    }
+}
+
+#[test]
+fn eip2645_path() {
+    // Example from StarkWare's starkware-crypto-utils
+    let eth_address = hex!("a4864d977b944315389d1765ffa7e66F74ee8cd7");
+    let path = Eip2645Path::new(b"starkex", b"starkdeployement", &eth_address, 0).unwrap();
+
+    assert_eq!(path.layer, 579218131);
+    assert_eq!(path.application, 891216374);
+    assert_eq!(path.eth_address_1, 1961790679);
+    assert_eq!(path.eth_address_2, 2135936222);
+    assert_eq!(
+        path.to_string(),
+        "m/2645'/579218131'/891216374'/1961790679'/2135936222'/0"
+    );
+    assert_eq!(
+        path.to_child_indexes(),
+        [
+            0x8000_0a55,
+            0x8000_0000 | 579218131,
+            0x8000_0000 | 891216374,
+            0x8000_0000 | 1961790679,
+            0x8000_0000 | 2135936222,
+            0,
+        ]
+    );
+
+    let path = Eip2645Path::new(b"starkex", b"starkdeployement", &eth_address, 7).unwrap();
+    assert!(path.to_string().ends_with("'/7"));
+    assert!(Eip2645Path::new(b"starkex", b"starkdeployement", &eth_address, 1 << 31).is_none());
+}
+
+#[test]
+fn key_from_eth_signature() {
+    // Taken from noble-curves stark test suite
+    let signature = hex!(
+        "21fbf0696d5e0aa2ef41a2b4ffb623bcaf070461d61cf7251c74161f82fec3a4"
+        "370854bc0a34b3ab487c1bc021cd318c734c51ae29374f2beb0e6f2dd49b4bf4"
+        "1c"
+    );
+    let expected = hex!("0766f11e90cd7c7b43085b56da35c781f8c067ac0d578eabdceebc4886435bda");
+    assert_eq!(
+        key_derivation::from_eth_signature(&signature),
+        Scalar::from_be_bytes(expected.into()).unwrap()
+    );
+
+    // `s` and `v` don't affect the key
+    let mut other = signature;
+    other[32..].fill(0xff);
+    assert_eq!(
+        key_derivation::from_eth_signature(&other),
+        key_derivation::from_eth_signature(&signature)
+    );
+
+    // Leading zero of `r` is preserved
+    let mut signature = [0x11; 65];
+    signature[0] = 0;
+    assert_eq!(
+        key_derivation::from_eth_signature(&signature),
+        grind_key(&signature[..32])
+    );
+    assert_ne!(
+        key_derivation::from_eth_signature(&signature),
+        grind_key(&signature[1..32])
+    );
+}
@@ -0,0 +1,137 @@
+use rand::Rng;
+use rand_dev::DevRng;
+use stark_curve::elliptic_curve::Field;
+use stark_curve::messaging::{EthAddress, L1ToL2Message, L2ToL1Message};
+use stark_curve::FieldElement;
+use starknet_core::types::{EthAddress as RefEthAddress, MsgToL1, MsgToL2};
+
+mod common;
+use common::to_felt;
+
+#[test]
+fn l1_to_l2_hash_matches_known_message() {
+    // Goerli transaction 0x374286ae28f201e61ffbc5b022cc9701208640b405ea34ea9799f97d5d2d23c
+    let msg = L1ToL2Message {
+        from_address: "0xc3511006C04EF1d78af4C8E0e74Ec18A6E64Ff9e"
+            .parse()
+            .unwrap(),
+        to_address: "0x73314940630fd6dcda0d772d4c972c4e0a9946bef9dabf4ef84eda8ef542b82"
+            .parse()
+            .unwrap(),
+        selector: "0x2d757788a8d8d6f21d1cd40bce38a8222d70654214e96ff95d8086e684fbee5"
+            .parse()
+            .unwrap(),
+        payload: &[
+            "0x689ead7d814e51ed93644bc145f0754839b8dcb340027ce0c30953f38f55d7"
+                .parse()
+                .unwrap(),
+            "0x2c68af0bb140000".parse().unwrap(),
+            FieldElement::ZERO,
+        ],
+        nonce: 775628,
+    };
+    assert_eq!(
+        hex::encode(msg.hash()),
+        "c51a543ef9563ad2545342b390b67edfcddf9886aa36846cf70382362fc5fab3"
+    );
+}
+
+#[test]
+fn l2_to_l1_hash_matches_known_message() {
+    // Goerli transaction 0x4e0bbc07ff29e5df13dfbcb7e4746fdde52c3649a6a69bd86b15397769722fd
+    let msg = L2ToL1Message {
+        from_address: "0x164cba33fb7152531f6b4cfc3fff26b4d7b26b4900e0881042edd607b428a92"
+            .parse()
+            .unwrap(),
+        to_address: "0xb6dbfaa86bb683152e4fc2401260f9ca249519c0"
+            .parse()
+            .unwrap(),
+        payload: &[0, 0, 0x182b8, 0, 0x384, 0].map(FieldElement::from),
+    };
+    assert_eq!(
+        hex::encode(msg.hash()),
+        "326a04493fc8f24ac6c6ae7bdba23243ce03ec3aae53f0ed3a0d686eb8cac930"
+    );
+}
+
+#[test]
+fn hashes_match_reference() {
+    let mut rng = DevRng::new();
+    for len in 0..4 {
+        let from_address = EthAddress::from_bytes(rng.gen());
+        let to_address = EthAddress::from_bytes(rng.gen());
+        let [contract, selector] = core::array::from_fn(|_| FieldElement::random(&mut rng));
+        let payload: Vec<_> = (0..len).map(|_| FieldElement::random(&mut rng)).collect();
+        let nonce = rng.gen();
+
+        let msg = L1ToL2Message {
+            from_address,
+            to_address: contract,
+            selector,
+            payload: &payload,
+            nonce,
+        };
+        let expected = MsgToL2 {
+            from_address: RefEthAddress::from_bytes(from_address.to_bytes()),
+            to_address: to_felt(contract),
+            selector: to_felt(selector),
+            payload: payload.iter().copied().map(to_felt).collect(),
+            nonce,
+        };
+        assert_eq!(&msg.hash(), expected.hash().as_bytes());
+
+        let msg = L2ToL1Message {
+            from_address: contract,
+            to_address,
+            payload: &payload,
+        };
+        let expected = MsgToL1 {
+            from_address: to_felt(contract),
+            to_address: to_felt(to_address.into()),
+            payload: payload.iter().copied().map(to_felt).collect(),
+        };
+        assert_eq!(&msg.hash(), expected.hash().as_bytes());
+    }
+}
+
+#[test]
+fn eth_address_conversions() {
+    let address: EthAddress = "0xb6dbfaa86bb683152e4fc2401260f9ca249519c0"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        address.to_string(),
+        "0xb6dbfaa86bb683152e4fc2401260f9ca249519c0"
+    );
+    let x = FieldElement::from(address);
+    assert_eq!(EthAddress::try_from(x), Ok(address));
+
+    let small = EthAddress::try_from(FieldElement::from(1)).unwrap();
+    assert_eq!(
+        small.to_string(),
+        "0x0000000000000000000000000000000000000001"
+    );
+
+    let max = EthAddress::from_bytes([0xff; 20]);
+    let too_big = FieldElement::from(max) + FieldElement::ONE;
+    assert!(EthAddress::try_from(too_big).is_err());
+    assert!("0x10000000000000000000000000000000000000000"
+        .parse::<EthAddress>()
+        .is_err());
+    assert!("0xzz".parse::<EthAddress>().is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn eth_address_serde_roundtrip() {
+    let eth: EthAddress = "0xb6dbfaa86bb683152e4fc2401260f9ca249519c0"
+        .parse()
+        .unwrap();
+    let json = serde_json::to_string(&eth).unwrap();
+    assert_eq!(json, r#""0xb6dbfaa86bb683152e4fc2401260f9ca249519c0""#);
+    assert_eq!(serde_json::from_str::<EthAddress>(&json).unwrap(), eth);
+    assert!(
+        serde_json::from_str::<EthAddress>(r#""0x10000000000000000000000000000000000000000""#)
+            .is_err()
+    );
+}
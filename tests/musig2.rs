@@ -0,0 +1,128 @@
+use primeorder::elliptic_curve::Field;
+use rand_dev::DevRng;
+use stark_curve::musig2::{
+    AggregatedNonce, KeyAggContext, MuSig2Hash, PartialSignature, SecretNonce, Session,
+};
+use stark_curve::schnorr::{PoseidonChallenge, Signature, SigningKey, VerifyingKey};
+use stark_curve::{FieldElement, ProjectivePoint, Scalar};
+
+#[test]
+fn poseidon_multisig() {
+    let mut rng = DevRng::new();
+    let message = [FieldElement::random(&mut rng), FieldElement::from(7)];
+
+    for n in 1..=5 {
+        println!("n: {n}");
+        let signature = run_signing::<PoseidonChallenge>(&mut rng, n, &message);
+        signature
+            .0
+            .verify::<PoseidonChallenge>(&message, &signature.1)
+            .unwrap();
+        signature
+            .0
+            .verify::<PoseidonChallenge>(&message[..1], &signature.1)
+            .unwrap_err();
+    }
+}
+
+#[cfg(feature = "hash2curve")]
+#[test]
+fn sha256_multisig() {
+    type Sha256Challenge = stark_curve::schnorr::ExpandMsgChallenge<
+        primeorder::elliptic_curve::hash2curve::ExpandMsgXmd<sha2::Sha256>,
+    >;
+
+    let mut rng = DevRng::new();
+    let (key, signature) = run_signing::<Sha256Challenge>(&mut rng, 3, b"transfer 10 STRK");
+    key.verify::<Sha256Challenge>(b"transfer 10 STRK", &signature)
+        .unwrap();
+    key.verify::<Sha256Challenge>(b"transfer 11 STRK", &signature)
+        .unwrap_err();
+}
+
+#[test]
+fn invalid_partial_signature_is_detected() {
+    let mut rng = DevRng::new();
+    let message = [FieldElement::ONE];
+
+    let signers = [(); 3].map(|_| SigningKey::random(&mut rng));
+    let keys = signers.each_ref().map(|s| *s.verifying_key());
+    let ctx = KeyAggContext::new(&keys).unwrap();
+
+    let nonces = [(); 3].map(|_| SecretNonce::generate(&mut rng));
+    let public_nonces = nonces.each_ref().map(|(_, public)| *public);
+    let agg_nonce = AggregatedNonce::sum(&public_nonces);
+    let session = Session::new::<PoseidonChallenge>(&ctx, &agg_nonce, &message).unwrap();
+
+    let mut partial_sigs = Vec::new();
+    for ((signer, (secnonce, _)), public_nonce) in signers.iter().zip(nonces).zip(&public_nonces) {
+        let sig = session.partial_sign(secnonce, signer).unwrap();
+        session
+            .verify_partial(&sig, public_nonce, signer.verifying_key())
+            .unwrap();
+        partial_sigs.push(sig);
+    }
+
+    // Partial signature is bound to signer's nonce and key
+    session
+        .verify_partial(&partial_sigs[0], &public_nonces[1], &keys[0])
+        .unwrap_err();
+    session
+        .verify_partial(&partial_sigs[0], &public_nonces[0], &keys[1])
+        .unwrap_err();
+
+    partial_sigs[1] = PartialSignature(partial_sigs[1].0 + Scalar::ONE);
+    session
+        .verify_partial(&partial_sigs[1], &public_nonces[1], &keys[1])
+        .unwrap_err();
+    let signature = session.aggregate(&partial_sigs);
+    ctx.aggregated_key()
+        .verify::<PoseidonChallenge>(&message, &signature)
+        .unwrap_err();
+}
+
+#[test]
+fn key_aggregation() {
+    let mut rng = DevRng::new();
+    let keys = [(); 3].map(|_| *SigningKey::random(&mut rng).verifying_key());
+
+    let ctx = KeyAggContext::new(&keys).unwrap();
+    let expected = keys.iter().fold(ProjectivePoint::IDENTITY, |sum, key| {
+        sum + *key.as_point() * ctx.key_coefficient(key)
+    });
+    assert_eq!(*ctx.aggregated_key().as_point(), expected.to_affine());
+
+    // Order of keys matters
+    let reordered = KeyAggContext::new(&[keys[1], keys[0], keys[2]]).unwrap();
+    assert_ne!(ctx.aggregated_key(), reordered.aggregated_key());
+
+    let err = KeyAggContext::new(&[]).unwrap_err();
+    assert_eq!(err.to_string(), "list of keys to aggregate is empty");
+}
+
+fn run_signing<H: MuSig2Hash>(
+    rng: &mut DevRng,
+    n: usize,
+    message: &H::Message,
+) -> (VerifyingKey, Signature) {
+    let signers: Vec<_> = (0..n).map(|_| SigningKey::random(rng)).collect();
+    let keys: Vec<_> = signers.iter().map(|s| *s.verifying_key()).collect();
+    let ctx = KeyAggContext::new(&keys).unwrap();
+
+    let (secret_nonces, public_nonces): (Vec<_>, Vec<_>) =
+        (0..n).map(|_| SecretNonce::generate(rng)).unzip();
+    let agg_nonce = AggregatedNonce::sum(&public_nonces);
+
+    let session = Session::new::<H>(&ctx, &agg_nonce, message).unwrap();
+    let partial_sigs: Vec<_> = signers
+        .iter()
+        .zip(secret_nonces)
+        .map(|(signer, nonce)| session.partial_sign(nonce, signer).unwrap())
+        .collect();
+
+    for ((sig, nonce), key) in partial_sigs.iter().zip(&public_nonces).zip(&keys) {
+        session.verify_partial(sig, nonce, key).unwrap();
+    }
+
+    (*ctx.aggregated_key(), session.aggregate(&partial_sigs))
+}
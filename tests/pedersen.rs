@@ -0,0 +1,60 @@
+use rand_dev::DevRng;
+use stark_curve::elliptic_curve::Field;
+use stark_curve::hash::pedersen;
+use stark_curve::FieldElement;
+use starknet_crypto::Felt;
+
+mod common;
+use common::{felt, from_felt, to_felt};
+
+#[test]
+fn hash_matches_test_vectors() {
+    // Test vectors taken from `starknet-crypto` crate tests
+    let test_vectors = [
+        (
+            "03d937c035c878245caf64531a5756109c53068da139362728feb561405371cb",
+            "0208a0a10250e382e1e4bbe2880906c2791bf6275695e02fbbc6aeff9cd8b31a",
+            "030e480bed5fe53fa909cc0f8c4d99b8f9f2c016be4c41e13a4848797979c662",
+        ),
+        (
+            "058f580910a6ca59b28927c08fe6c43e2e303ca384badc365795fc645d479d45",
+            "078734f65a067be9bdb39de18434d71e79f7b6466a4b66bbd979ab9e7515fe0b",
+            "068cc0b76cddd1dd4ed2301ada9b7c872b23875d5ff837b3a87993e0d9996b87",
+        ),
+    ];
+
+    let points = pedersen::ConstantPoints::new();
+    for (x, y, expected) in test_vectors {
+        assert_eq!(pedersen::hash(felt(x), felt(y)), felt(expected));
+        assert_eq!(points.hash(felt(x), felt(y)), felt(expected));
+    }
+}
+
+#[test]
+fn hash_matches_starknet_crypto() {
+    let mut rng = DevRng::new();
+
+    for _ in 0..10 {
+        let x = FieldElement::random(&mut rng);
+        let y = FieldElement::random(&mut rng);
+
+        assert_eq!(
+            pedersen::hash(x, y),
+            from_felt(starknet_crypto::pedersen_hash(&to_felt(x), &to_felt(y)))
+        );
+    }
+    let max = -FieldElement::ONE;
+    assert_eq!(
+        pedersen::hash(max, FieldElement::ZERO),
+        from_felt(starknet_crypto::pedersen_hash(&to_felt(max), &Felt::ZERO))
+    );
+
+    for len in 0..5 {
+        let msgs: Vec<_> = (0..len).map(|_| FieldElement::random(&mut rng)).collect();
+        let expected = from_felt(starknet_core::crypto::compute_hash_on_elements(
+            &msgs.iter().copied().map(to_felt).collect::<Vec<_>>(),
+        ));
+
+        assert_eq!(pedersen::hash_array(&msgs), expected, "len: {len}");
+    }
+}
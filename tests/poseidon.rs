@@ -0,0 +1,74 @@
+use rand_dev::DevRng;
+use stark_curve::elliptic_curve::Field;
+use stark_curve::hash::poseidon;
+use stark_curve::FieldElement;
+
+mod common;
+use common::{felt, from_felt, to_felt};
+
+#[test]
+fn hash_matches_cairo_lang() {
+    // Test vectors generated by `cairo-lang` v0.11.0, taken from `starknet-crypto` crate tests
+    let test_vectors = [
+        (
+            "000b662f9017fa7956fd70e26129b1833e10ad000fd37b4d9f4e0ce6884b7bbe",
+            "001fe356bf76102cdae1bfbdc173602ead228b12904c00dad9cf16e035468bea",
+            "075540825a6ecc5dc7d7c2f5f868164182742227f1367d66c43ee51ec7937a81",
+        ),
+        (
+            "00f4e01b2032298f86b539e3d3ac05ced20d2ef275273f9325f8827717156529",
+            "00587bc46f5f58e0511b93c31134652a689d761a9e7f234f0f130c52e4679f3a",
+            "00bdb3180fdcfd6d6f172beb401af54dd71b6569e6061767234db2b777adf98b",
+        ),
+    ];
+
+    for (x, y, expected) in test_vectors {
+        assert_eq!(poseidon::hash(felt(x), felt(y)), felt(expected));
+    }
+}
+
+#[test]
+fn hash_matches_starknet_crypto() {
+    let mut rng = DevRng::new();
+
+    for _ in 0..20 {
+        let x = FieldElement::random(&mut rng);
+        let y = FieldElement::random(&mut rng);
+
+        assert_eq!(
+            poseidon::hash(x, y),
+            from_felt(starknet_crypto::poseidon_hash(to_felt(x), to_felt(y)))
+        );
+        assert_eq!(
+            poseidon::hash_single(x),
+            from_felt(starknet_crypto::poseidon_hash_single(to_felt(x)))
+        );
+    }
+
+    for len in 0..8 {
+        let msgs: Vec<_> = (0..len).map(|_| FieldElement::random(&mut rng)).collect();
+        let expected = from_felt(starknet_crypto::poseidon_hash_many(
+            &msgs.iter().copied().map(to_felt).collect::<Vec<_>>(),
+        ));
+
+        assert_eq!(poseidon::hash_many(&msgs), expected, "len: {len}");
+
+        let hasher = msgs
+            .iter()
+            .fold(poseidon::Hasher::new(), |h, m| h.chain(*m));
+        assert_eq!(hasher.finalize(), expected, "len: {len}");
+    }
+}
+
+#[test]
+fn permutation_matches_starknet_crypto() {
+    let mut rng = DevRng::new();
+
+    let mut state = [(); 3].map(|_| FieldElement::random(&mut rng));
+    let mut expected = state.map(to_felt);
+
+    poseidon::hades_permutation(&mut state);
+    starknet_crypto::poseidon_permute_comp(&mut expected);
+
+    assert_eq!(state, expected.map(from_felt));
+}
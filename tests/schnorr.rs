@@ -0,0 +1,160 @@
+use primeorder::elliptic_curve::sec1::ToEncodedPoint;
+use primeorder::elliptic_curve::Field;
+use rand_dev::DevRng;
+use stark_curve::schnorr::{
+    self, ChallengeHash, PoseidonChallenge, Signature, SigningKey, VerifyingKey,
+};
+use stark_curve::{AffinePoint, FieldElement, Scalar};
+use starknet_crypto::Felt;
+
+mod common;
+use common::to_felt;
+
+#[cfg(feature = "hash2curve")]
+type Sha256Challenge =
+    schnorr::ExpandMsgChallenge<primeorder::elliptic_curve::hash2curve::ExpandMsgXmd<sha2::Sha256>>;
+
+#[test]
+fn sign_and_verify_poseidon() {
+    let mut rng = DevRng::new();
+    let key = SigningKey::random(&mut rng);
+
+    let message = [(); 5].map(|_| FieldElement::random(&mut rng));
+    let signature = key.sign::<PoseidonChallenge>(&message, &mut rng).unwrap();
+
+    key.verifying_key()
+        .verify::<PoseidonChallenge>(&message, &signature)
+        .unwrap();
+
+    let mut wrong_message = message;
+    wrong_message[4] += FieldElement::ONE;
+    key.verifying_key()
+        .verify::<PoseidonChallenge>(&wrong_message, &signature)
+        .unwrap_err();
+    key.verifying_key()
+        .verify::<PoseidonChallenge>(&message[..4], &signature)
+        .unwrap_err();
+
+    let other_key = SigningKey::random(&mut rng);
+    other_key
+        .verifying_key()
+        .verify::<PoseidonChallenge>(&message, &signature)
+        .unwrap_err();
+}
+
+#[cfg(feature = "hash2curve")]
+#[test]
+fn sign_and_verify_sha256() {
+    let mut rng = DevRng::new();
+    let key = SigningKey::random(&mut rng);
+
+    let signature = key.sign::<Sha256Challenge>(b"hello", &mut rng).unwrap();
+    key.verifying_key()
+        .verify::<Sha256Challenge>(b"hello", &signature)
+        .unwrap();
+    key.verifying_key()
+        .verify::<Sha256Challenge>(b"hello!", &signature)
+        .unwrap_err();
+
+    let forged = Signature {
+        r: signature.r,
+        s: signature.s + Scalar::ONE,
+    };
+    key.verifying_key()
+        .verify::<Sha256Challenge>(b"hello", &forged)
+        .unwrap_err();
+}
+
+#[test]
+fn poseidon_challenge_is_computable_in_cairo() {
+    let mut rng = DevRng::new();
+    let key = SigningKey::random(&mut rng);
+    let message = [FieldElement::from(42), FieldElement::random(&mut rng)];
+    let signature = key.sign::<PoseidonChallenge>(&message, &mut rng).unwrap();
+
+    let (r_x, r_y) = coords(&signature.r);
+    let (p_x, p_y) = coords(key.verifying_key().as_point());
+    let e = starknet_crypto::poseidon_hash_many(&[
+        r_x,
+        r_y,
+        p_x,
+        p_y,
+        to_felt(message[0]),
+        to_felt(message[1]),
+    ]);
+    let e = Scalar::from_be_bytes_mod_order(&e.to_bytes_be());
+
+    assert_eq!(
+        e,
+        PoseidonChallenge::challenge(&signature.r, key.verifying_key().as_point(), &message)
+            .unwrap()
+    );
+}
+
+#[test]
+fn signature_serialization() {
+    let mut rng = DevRng::new();
+    let key = SigningKey::random(&mut rng);
+    let signature = key
+        .sign::<PoseidonChallenge>(&[FieldElement::ONE], &mut rng)
+        .unwrap();
+
+    let bytes = signature.to_bytes();
+    assert_eq!(Signature::from_bytes(&bytes), Some(signature));
+
+    let mut invalid_s = bytes;
+    invalid_s[33..].fill(0xff);
+    assert_eq!(Signature::from_bytes(&invalid_s), None);
+
+    let mut invalid_r = bytes;
+    invalid_r[0] = 0x05;
+    assert_eq!(Signature::from_bytes(&invalid_r), None);
+}
+
+#[test]
+fn batch_verification() {
+    let mut rng = DevRng::new();
+
+    let keys = [(); 4].map(|_| SigningKey::random(&mut rng));
+    let messages = [
+        [FieldElement::ONE],
+        [FieldElement::from(2)],
+        [FieldElement::from(3)],
+        [FieldElement::from(4)],
+    ];
+    let mut signatures = [0, 1, 2, 3].map(|i| {
+        keys[i]
+            .sign::<PoseidonChallenge>(&messages[i], &mut rng)
+            .unwrap()
+    });
+
+    let batch = |signatures: &[Signature; 4]| {
+        let items: Vec<(&VerifyingKey, &[FieldElement], &Signature)> = (0..4)
+            .map(|i| (keys[i].verifying_key(), &messages[i][..], &signatures[i]))
+            .collect();
+        schnorr::verify_batch::<PoseidonChallenge>(&items, &mut DevRng::new())
+    };
+
+    batch(&signatures).unwrap();
+    schnorr::verify_batch::<PoseidonChallenge>(&[], &mut rng).unwrap();
+
+    signatures[2].s += Scalar::ONE;
+    batch(&signatures).unwrap_err();
+}
+
+#[test]
+fn keys_validation() {
+    assert!(SigningKey::from_scalar(Scalar::ZERO).is_none());
+    assert!(VerifyingKey::from_point(AffinePoint::IDENTITY).is_none());
+
+    let key = SigningKey::from_scalar(Scalar::ONE).unwrap();
+    assert_eq!(*key.verifying_key().as_point(), AffinePoint::GENERATOR);
+}
+
+fn coords(point: &AffinePoint) -> (Felt, Felt) {
+    let encoded = point.to_encoded_point(false);
+    (
+        Felt::from_bytes_be_slice(encoded.x().unwrap()),
+        Felt::from_bytes_be_slice(encoded.y().unwrap()),
+    )
+}
@@ -0,0 +1,158 @@
+#![cfg(feature = "alloc")]
+
+use rand_dev::DevRng;
+use stark_curve::commitment::CommitmentKey;
+use stark_curve::elliptic_curve::Field;
+use stark_curve::secret_sharing::{self, FeldmanCommitment, PedersenCommitment, Polynomial, Share};
+use stark_curve::{generators, ProjectivePoint, Scalar};
+
+#[test]
+fn any_t_shares_reconstruct_secret() {
+    let mut rng = DevRng::new();
+    let secret = Scalar::random(&mut rng);
+
+    let (shares, polynomial) = secret_sharing::split(secret, 3, 5, &mut rng).unwrap();
+    assert_eq!(shares.len(), 5);
+    assert_eq!(polynomial.coefficients().len(), 3);
+    assert_eq!(polynomial.evaluate(&Scalar::ZERO), secret);
+
+    for a in 0..5 {
+        for b in a + 1..5 {
+            for c in b + 1..5 {
+                let subset = [shares[a], shares[b], shares[c]];
+                assert_eq!(secret_sharing::reconstruct(&subset), Some(secret));
+            }
+        }
+    }
+    assert_eq!(secret_sharing::reconstruct(&shares), Some(secret));
+    assert_ne!(secret_sharing::reconstruct(&shares[..2]), Some(secret));
+}
+
+#[test]
+fn invalid_inputs_are_rejected() {
+    let mut rng = DevRng::new();
+
+    assert!(secret_sharing::split(Scalar::ONE, 0, 3, &mut rng).is_err());
+    let err = secret_sharing::split(Scalar::ONE, 4, 3, &mut rng).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid threshold: t = 4, n = 3, expected 0 < t <= n"
+    );
+
+    let (shares, _) = secret_sharing::split(Scalar::ONE, 2, 3, &mut rng).unwrap();
+    assert_eq!(
+        secret_sharing::reconstruct(&[shares[0], shares[0]]),
+        None,
+        "duplicated shares"
+    );
+    let zero_index = Share {
+        index: Scalar::ZERO,
+        value: Scalar::ONE,
+    };
+    assert_eq!(secret_sharing::reconstruct(&[shares[0], zero_index]), None);
+    assert_eq!(secret_sharing::reconstruct(&[]), None);
+
+    let indexes = [Scalar::ONE, Scalar::from(2)];
+    assert!(
+        secret_sharing::lagrange_coefficient(&Scalar::ZERO, &Scalar::from(3), &indexes).is_none()
+    );
+}
+
+#[test]
+fn polynomial_evaluation() {
+    // f(x) = 1 + 2x + 3x^2
+    let f = Polynomial::from_coefficients(vec![Scalar::ONE, Scalar::from(2), Scalar::from(3)]);
+    assert_eq!(f.evaluate(&Scalar::ZERO), Scalar::ONE);
+    assert_eq!(f.evaluate(&Scalar::ONE), Scalar::from(6));
+    assert_eq!(f.evaluate(&Scalar::from(2)), Scalar::from(17));
+    assert_eq!(f.evaluate(&-Scalar::ONE), Scalar::from(2));
+}
+
+#[test]
+fn interpolation_at_arbitrary_point() {
+    let mut rng = DevRng::new();
+    let (shares, polynomial) =
+        secret_sharing::split(Scalar::random(&mut rng), 4, 6, &mut rng).unwrap();
+
+    for _ in 0..5 {
+        let x = Scalar::random(&mut rng);
+        assert_eq!(
+            secret_sharing::interpolate(&x, &shares[2..]),
+            Some(polynomial.evaluate(&x))
+        );
+    }
+    // Interpolating at index of the share returns that share
+    assert_eq!(
+        secret_sharing::interpolate(&shares[5].index, &shares[..4]),
+        Some(shares[5].value)
+    );
+    assert_eq!(
+        secret_sharing::interpolate(&Scalar::ONE, &[shares[0], shares[0]]),
+        None
+    );
+}
+
+#[test]
+fn feldman_vss() {
+    let mut rng = DevRng::new();
+    let secret = Scalar::random(&mut rng);
+    let (shares, commitment) = secret_sharing::split_feldman(secret, 3, 5, &mut rng).unwrap();
+
+    assert_eq!(commitment.points().len(), 3);
+    assert_eq!(commitment.public_key(), ProjectivePoint::GENERATOR * secret);
+    for share in &shares {
+        assert!(commitment.verify_share(share, 3));
+
+        let mut corrupted = *share;
+        corrupted.value += Scalar::ONE;
+        assert!(!commitment.verify_share(&corrupted, 3));
+    }
+
+    let received = FeldmanCommitment::from_points(commitment.points().to_vec());
+    assert_eq!(received, commitment);
+    assert!(received.verify_share(&shares[0], 3));
+
+    // Dealer commits to a polynomial of higher degree than the threshold
+    let mut points = commitment.points().to_vec();
+    points.push(ProjectivePoint::IDENTITY);
+    let higher_degree = FeldmanCommitment::from_points(points);
+    assert!(!higher_degree.verify_share(&shares[0], 3));
+    assert!(higher_degree.verify_share(&shares[0], 4));
+    assert!(!commitment.verify_share(&shares[0], 2));
+}
+
+#[test]
+fn pedersen_vss() {
+    let mut rng = DevRng::new();
+    let h = generators::from_pi_digits(3).unwrap();
+    let key = CommitmentKey::new(ProjectivePoint::GENERATOR, h.into());
+
+    let secret = Scalar::random(&mut rng);
+    let (shares, commitment) = secret_sharing::split_pedersen(secret, 2, 4, key, &mut rng).unwrap();
+
+    assert_eq!(commitment.points().len(), 2);
+    assert_ne!(commitment.points()[0], ProjectivePoint::GENERATOR * secret);
+    for share in &shares {
+        assert!(commitment.verify_share(share, 2));
+        assert!(!commitment.verify_share(share, 3));
+
+        let mut corrupted = *share;
+        corrupted.blinding += Scalar::ONE;
+        assert!(!commitment.verify_share(&corrupted, 2));
+    }
+
+    let secret_shares: Vec<_> = shares.iter().map(|s| s.share()).collect();
+    assert_eq!(
+        secret_sharing::reconstruct(&secret_shares[2..]),
+        Some(secret)
+    );
+
+    // Commitment to polynomials of different degrees
+    let f = Polynomial::from_coefficients(vec![Scalar::ONE, Scalar::from(2), Scalar::from(3)]);
+    let g = Polynomial::from_coefficients(vec![Scalar::from(5)]);
+    let commitment = PedersenCommitment::new(key, &f, &g);
+    assert_eq!(
+        commitment.evaluate(&Scalar::from(2)),
+        *key.commit(&Scalar::from(17), &Scalar::from(5)).as_point()
+    );
+}
@@ -0,0 +1,204 @@
+use rand_dev::DevRng;
+use stark_curve::elliptic_curve::Field;
+use stark_curve::{starkex, FieldElement};
+use starknet_crypto::{pedersen_hash, Felt};
+
+mod common;
+use common::to_felt;
+
+/// Example from StarkWare's starkex-resources
+#[test]
+fn limit_order() {
+    let order = starkex::LimitOrder {
+        vault_sell: 21,
+        vault_buy: 27,
+        amount_sell: 2154686749748910716,
+        amount_buy: 1470242115489520459,
+        token_sell: felt("0x5fa3383597691ea9d827a79e1a4f0f7989c35ced18ca9619de8ab97e661020"),
+        token_buy: felt("0x774961c824a3b0fb3d2965f01471c9c7734bf8dbde659e0c08dca2ef18d56a"),
+        nonce: 0,
+        expiration_timestamp: 438953,
+    };
+    assert_eq!(
+        order.hash().unwrap(),
+        felt("0x397e76d1667c4454bfb83514e120583af836f8e32a516765497823eabe16a3f")
+    );
+}
+
+#[test]
+fn transfer() {
+    let mut rng = DevRng::new();
+    let [token, receiver_public_key, condition] =
+        core::array::from_fn(|_| FieldElement::random(&mut rng));
+
+    let transfer = starkex::Transfer {
+        amount: 2154549703648910716,
+        nonce: 1,
+        sender_vault_id: 34,
+        token,
+        receiver_vault_id: 21,
+        receiver_public_key,
+        expiration_timestamp: 438953,
+        condition: None,
+    };
+    let packed = pack(&[
+        (1, 0),
+        (34, 31),
+        (21, 31),
+        (2154549703648910716, 63),
+        (0, 63),
+        (1, 31),
+        (438953, 22),
+    ]);
+    let expected = pedersen_hash(
+        &pedersen_hash(&to_felt(token), &to_felt(receiver_public_key)),
+        &packed,
+    );
+    assert_eq!(to_felt(transfer.hash().unwrap()), expected);
+
+    let transfer = starkex::Transfer {
+        condition: Some(condition),
+        ..transfer
+    };
+    let packed = packed + Felt::TWO.pow(241u32);
+    let expected = pedersen_hash(
+        &pedersen_hash(
+            &pedersen_hash(&to_felt(token), &to_felt(receiver_public_key)),
+            &to_felt(condition),
+        ),
+        &packed,
+    );
+    assert_eq!(to_felt(transfer.hash().unwrap()), expected);
+}
+
+#[test]
+fn messages_with_fee() {
+    let mut rng = DevRng::new();
+    let [token_a, token_b, fee_token, receiver_public_key, condition] =
+        core::array::from_fn(|_| FieldElement::random(&mut rng));
+    let fee = starkex::Fee {
+        token: fee_token,
+        vault_id: u64::MAX - 1,
+        limit: 7,
+    };
+
+    let order = starkex::LimitOrderWithFee {
+        vault_sell: u64::MAX,
+        vault_buy: 1 << 40,
+        amount_sell: u64::MAX - 2,
+        amount_buy: 12345,
+        token_sell: token_a,
+        token_buy: token_b,
+        nonce: u32::MAX,
+        expiration_timestamp: 438953,
+        fee,
+    };
+    let packed_0 = pack(&[
+        (u64::MAX - 2, 0),
+        (12345, 64),
+        (7, 64),
+        (u32::MAX.into(), 32),
+    ]);
+    let packed_1 = pack(&[
+        (3, 0),
+        (u64::MAX - 1, 64),
+        (u64::MAX, 64),
+        (1 << 40, 64),
+        (438953, 32),
+        (0, 17),
+    ]);
+    let hash = pedersen_hash(&to_felt(token_a), &to_felt(token_b));
+    let hash = pedersen_hash(&hash, &to_felt(fee_token));
+    let expected = pedersen_hash(&pedersen_hash(&hash, &packed_0), &packed_1);
+    assert_eq!(to_felt(order.hash()), expected);
+
+    for condition in [None, Some(condition)] {
+        let transfer = starkex::TransferWithFee {
+            amount: 1000,
+            nonce: 5,
+            sender_vault_id: 1 << 63,
+            token: token_a,
+            receiver_vault_id: 2,
+            receiver_public_key,
+            expiration_timestamp: 438953,
+            condition,
+            fee,
+        };
+        let instruction = if condition.is_some() { 5 } else { 4 };
+        let packed_0 = pack(&[(1 << 63, 0), (2, 64), (u64::MAX - 1, 64), (5, 32)]);
+        let packed_1 = pack(&[(instruction, 0), (1000, 64), (7, 64), (438953, 32), (0, 81)]);
+        let hash = pedersen_hash(&to_felt(token_a), &to_felt(fee_token));
+        let mut hash = pedersen_hash(&hash, &to_felt(receiver_public_key));
+        if let Some(condition) = condition {
+            hash = pedersen_hash(&hash, &to_felt(condition));
+        }
+        let expected = pedersen_hash(&pedersen_hash(&hash, &packed_0), &packed_1);
+        assert_eq!(to_felt(transfer.hash()), expected);
+    }
+
+    let withdrawal = starkex::Withdrawal {
+        collateral_asset_id: token_a,
+        position_id: 0x1234,
+        nonce: 9,
+        expiration_timestamp: 438953,
+        amount: u64::MAX,
+    };
+    let packed = pack(&[
+        (6, 0),
+        (0x1234, 64),
+        (9, 32),
+        (u64::MAX, 64),
+        (438953, 32),
+        (0, 49),
+    ]);
+    assert_eq!(
+        to_felt(withdrawal.hash()),
+        pedersen_hash(&to_felt(token_a), &packed)
+    );
+}
+
+#[test]
+fn out_of_range_fields() {
+    let order = starkex::LimitOrder {
+        vault_sell: 1 << 31,
+        vault_buy: 0,
+        amount_sell: 0,
+        amount_buy: 0,
+        token_sell: FieldElement::ONE,
+        token_buy: FieldElement::ONE,
+        nonce: 0,
+        expiration_timestamp: 0,
+    };
+    let err = order.hash().unwrap_err();
+    assert_eq!(err.to_string(), "`vault_sell` must be less than 2^31");
+
+    let order = starkex::LimitOrder {
+        vault_sell: 0,
+        amount_buy: 1 << 63,
+        ..order
+    };
+    let err = order.hash().unwrap_err();
+    assert_eq!(err.to_string(), "`amount_buy` must be less than 2^63");
+
+    let order = starkex::LimitOrder {
+        amount_buy: 0,
+        expiration_timestamp: 1 << 22,
+        ..order
+    };
+    let err = order.hash().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`expiration_timestamp` must be less than 2^22"
+    );
+}
+
+/// Packs `(value, bits)` pairs, most significant first
+fn pack(fields: &[(u64, u32)]) -> Felt {
+    fields.iter().fold(Felt::ZERO, |acc, &(value, bits)| {
+        acc * Felt::TWO.pow(bits) + Felt::from(value)
+    })
+}
+
+fn felt(s: &str) -> FieldElement {
+    s.parse().unwrap()
+}
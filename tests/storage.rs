@@ -0,0 +1,61 @@
+use rand_dev::DevRng;
+use stark_curve::elliptic_curve::Field;
+use stark_curve::storage::{self, StoragePath};
+use stark_curve::{account, FieldElement};
+use starknet_core::utils::{get_storage_var_address, starknet_keccak};
+use starknet_crypto::{pedersen_hash, Felt};
+
+mod common;
+use common::to_felt;
+
+#[test]
+fn storage_address_matches_starknet_core() {
+    let mut rng = DevRng::new();
+
+    for len in 0..4 {
+        let keys: Vec<_> = (0..len).map(|_| FieldElement::random(&mut rng)).collect();
+        let expected = get_storage_var_address(
+            "ERC20_balances",
+            &keys.iter().copied().map(to_felt).collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let address = storage::storage_address("ERC20_balances", &keys);
+        assert_eq!(to_felt(address), expected);
+        assert!(address.to_uint() < account::ADDRESS_UPPER_BOUND);
+    }
+}
+
+#[test]
+fn cairo1_paths() {
+    let mut rng = DevRng::new();
+    let [owner, spender] = core::array::from_fn(|_| FieldElement::random(&mut rng));
+    let base = starknet_keccak(b"allowances");
+
+    // Map with tuple key, nested maps, and `LegacyMap` agree
+    let path = StoragePath::new("allowances").entry(&[owner, spender]);
+    let nested = StoragePath::new("allowances")
+        .entry(&[owner])
+        .entry(&[spender]);
+    assert_eq!(path, nested);
+    assert_eq!(
+        path.address(),
+        storage::storage_address("allowances", &[owner, spender])
+    );
+    assert_eq!(
+        StoragePath::new("allowances").address(),
+        storage::storage_address("allowances", &[])
+    );
+
+    // Values spanning several slots
+    assert_eq!(path.offset(0), path.address());
+    assert_eq!(path.offset(1), path.address() + FieldElement::ONE);
+
+    // Vec elements and storage node members
+    let element = StoragePath::new("allowances").at(3);
+    let expected = pedersen_hash(&base, &Felt::from(3));
+    assert_eq!(to_felt(element.address()), expected);
+
+    let member = StoragePath::new("allowances").member("owner");
+    let expected = pedersen_hash(&base, &starknet_keccak(b"owner"));
+    assert_eq!(to_felt(member.address()), expected);
+}
@@ -0,0 +1,252 @@
+use rand_dev::DevRng;
+use stark_curve::elliptic_curve::Field;
+use stark_curve::transaction::{
+    DataAvailabilityMode, DeclareV1, DeclareV2, DeclareV3, DeployAccountV1, DeployAccountV3,
+    InvokeV1, InvokeV3, ResourceBounds, ResourceBoundsMapping, V3Fields,
+};
+use stark_curve::FieldElement;
+use starknet_core::crypto::compute_hash_on_elements;
+use starknet_core::utils::cairo_short_string_to_felt;
+use starknet_crypto::{poseidon_hash_many, Felt};
+
+mod common;
+use common::{from_felt, to_felt, to_felts};
+
+#[test]
+fn v1_hashes_match_reference() {
+    let mut rng = DevRng::new();
+    let [sender, class_hash, compiled_class_hash, salt, max_fee, nonce] =
+        core::array::from_fn(|_| FieldElement::random(&mut rng));
+    let calldata: Vec<_> = (0..3).map(|_| FieldElement::random(&mut rng)).collect();
+    let chain_id = cairo_short_string_to_felt("SN_MAIN").unwrap();
+
+    for query in [false, true] {
+        let v = |version: u64| query_version(version, query);
+        let common = |prefix: &str, version: u64, data_hash: Felt| {
+            vec![
+                cairo_short_string_to_felt(prefix).unwrap(),
+                v(version),
+                to_felt(sender),
+                Felt::ZERO,
+                data_hash,
+                to_felt(max_fee),
+                chain_id,
+                to_felt(nonce),
+            ]
+        };
+
+        let invoke = InvokeV1 {
+            sender_address: sender,
+            calldata: &calldata,
+            max_fee,
+            chain_id: from_felt(chain_id),
+            nonce,
+            query,
+        };
+        let expected = common("invoke", 1, compute_hash_on_elements(&to_felts(&calldata)));
+        assert_eq!(to_felt(invoke.hash()), compute_hash_on_elements(&expected));
+
+        let declare = DeclareV1 {
+            sender_address: sender,
+            class_hash,
+            max_fee,
+            chain_id: from_felt(chain_id),
+            nonce,
+            query,
+        };
+        let expected = common(
+            "declare",
+            1,
+            compute_hash_on_elements(&[to_felt(class_hash)]),
+        );
+        assert_eq!(to_felt(declare.hash()), compute_hash_on_elements(&expected));
+
+        let declare = DeclareV2 {
+            sender_address: sender,
+            class_hash,
+            compiled_class_hash,
+            max_fee,
+            chain_id: from_felt(chain_id),
+            nonce,
+            query,
+        };
+        let mut expected = common(
+            "declare",
+            2,
+            compute_hash_on_elements(&[to_felt(class_hash)]),
+        );
+        expected.push(to_felt(compiled_class_hash));
+        assert_eq!(to_felt(declare.hash()), compute_hash_on_elements(&expected));
+
+        let deploy_account = DeployAccountV1 {
+            contract_address: sender,
+            class_hash,
+            contract_address_salt: salt,
+            constructor_calldata: &calldata,
+            max_fee,
+            chain_id: from_felt(chain_id),
+            nonce,
+            query,
+        };
+        let data = [class_hash, salt]
+            .into_iter()
+            .chain(calldata.iter().copied())
+            .map(to_felt)
+            .collect::<Vec<_>>();
+        let expected = common("deploy_account", 1, compute_hash_on_elements(&data));
+        assert_eq!(
+            to_felt(deploy_account.hash()),
+            compute_hash_on_elements(&expected)
+        );
+    }
+}
+
+#[test]
+fn v3_hashes_match_reference() {
+    let mut rng = DevRng::new();
+    let [sender, class_hash, compiled_class_hash, salt, nonce] =
+        core::array::from_fn(|_| FieldElement::random(&mut rng));
+    let calldata: Vec<_> = (0..3).map(|_| FieldElement::random(&mut rng)).collect();
+    let paymaster_data: Vec<_> = (0..2).map(|_| FieldElement::random(&mut rng)).collect();
+    let deployment_data: Vec<_> = (0..1).map(|_| FieldElement::random(&mut rng)).collect();
+    let chain_id = cairo_short_string_to_felt("SN_SEPOLIA").unwrap();
+
+    let l1_gas = ResourceBounds {
+        max_amount: 0x186a0,
+        max_price_per_unit: 0x5af3107a4000,
+    };
+    let l2_gas = ResourceBounds {
+        max_amount: u64::MAX,
+        max_price_per_unit: u128::MAX,
+    };
+    let l1_data_gas = ResourceBounds {
+        max_amount: 0x1234,
+        max_price_per_unit: 0x5678,
+    };
+
+    for (query, l1_data_gas) in [
+        (false, Some(l1_data_gas)),
+        (true, Some(l1_data_gas)),
+        (false, None),
+    ] {
+        let fields = V3Fields {
+            sender_address: sender,
+            chain_id: from_felt(chain_id),
+            nonce,
+            tip: 0x1f,
+            resource_bounds: ResourceBoundsMapping {
+                l1_gas,
+                l2_gas,
+                l1_data_gas,
+            },
+            paymaster_data: &paymaster_data,
+            nonce_data_availability_mode: DataAvailabilityMode::L2,
+            fee_data_availability_mode: DataAvailabilityMode::L1,
+            query,
+        };
+
+        let mut fee_fields = vec![
+            Felt::from(0x1f),
+            encode_bounds("L1_GAS", &l1_gas),
+            encode_bounds("L2_GAS", &l2_gas),
+        ];
+        fee_fields.extend(l1_data_gas.map(|b| encode_bounds("L1_DATA", &b)));
+        let common = |prefix: &str| {
+            vec![
+                cairo_short_string_to_felt(prefix).unwrap(),
+                query_version(3, query),
+                to_felt(sender),
+                poseidon_hash_many(&fee_fields),
+                poseidon_hash_many(&to_felts(&paymaster_data)),
+                chain_id,
+                to_felt(nonce),
+                Felt::from(1u64 << 32),
+            ]
+        };
+
+        let invoke = InvokeV3 {
+            fields,
+            account_deployment_data: &deployment_data,
+            calldata: &calldata,
+        };
+        let mut expected = common("invoke");
+        expected.push(poseidon_hash_many(&to_felts(&deployment_data)));
+        expected.push(poseidon_hash_many(&to_felts(&calldata)));
+        assert_eq!(to_felt(invoke.hash()), poseidon_hash_many(&expected));
+
+        let declare = DeclareV3 {
+            fields,
+            account_deployment_data: &deployment_data,
+            class_hash,
+            compiled_class_hash,
+        };
+        let mut expected = common("declare");
+        expected.push(poseidon_hash_many(&to_felts(&deployment_data)));
+        expected.push(to_felt(class_hash));
+        expected.push(to_felt(compiled_class_hash));
+        assert_eq!(to_felt(declare.hash()), poseidon_hash_many(&expected));
+
+        let deploy_account = DeployAccountV3 {
+            fields,
+            class_hash,
+            contract_address_salt: salt,
+            constructor_calldata: &calldata,
+        };
+        let mut expected = common("deploy_account");
+        expected.push(poseidon_hash_many(&to_felts(&calldata)));
+        expected.push(to_felt(class_hash));
+        expected.push(to_felt(salt));
+        assert_eq!(
+            to_felt(deploy_account.hash()),
+            poseidon_hash_many(&expected)
+        );
+    }
+}
+
+#[test]
+fn hash_depends_on_version_and_resource_bounds() {
+    let mut fields = V3Fields {
+        sender_address: FieldElement::from(0x123),
+        chain_id: FieldElement::from(0x456),
+        nonce: FieldElement::ZERO,
+        tip: 0,
+        resource_bounds: ResourceBoundsMapping::default(),
+        paymaster_data: &[],
+        nonce_data_availability_mode: DataAvailabilityMode::L1,
+        fee_data_availability_mode: DataAvailabilityMode::L1,
+        query: false,
+    };
+    let hash = |fields| {
+        InvokeV3 {
+            fields,
+            account_deployment_data: &[],
+            calldata: &[],
+        }
+        .hash()
+    };
+
+    let h0 = hash(fields);
+    fields.resource_bounds.l1_data_gas = Some(ResourceBounds::default());
+    let h1 = hash(fields);
+    fields.query = true;
+    let h2 = hash(fields);
+    assert_ne!(h0, h1);
+    assert_ne!(h1, h2);
+}
+
+/// Version is `2^128 + version` for query transactions
+fn query_version(version: u64, query: bool) -> Felt {
+    let offset = if query {
+        Felt::TWO.pow(128u32)
+    } else {
+        Felt::ZERO
+    };
+    offset + Felt::from(version)
+}
+
+/// Encodes resource bounds as `name * 2^192 + max_amount * 2^128 + max_price_per_unit`
+fn encode_bounds(name: &str, bounds: &ResourceBounds) -> Felt {
+    cairo_short_string_to_felt(name).unwrap() * Felt::TWO.pow(192u32)
+        + Felt::from(bounds.max_amount) * Felt::TWO.pow(128u32)
+        + Felt::from(bounds.max_price_per_unit)
+}
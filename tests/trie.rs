@@ -0,0 +1,320 @@
+use rand::Rng;
+use rand_dev::DevRng;
+use stark_curve::elliptic_curve::Field;
+use stark_curve::trie::{self, MerkleNode, TrieHash};
+use stark_curve::FieldElement;
+use starknet_core::utils::cairo_short_string_to_felt;
+use starknet_crypto::{pedersen_hash, poseidon_hash, poseidon_hash_many, Felt};
+
+mod common;
+use common::{from_felt, to_felt};
+
+#[test]
+fn proofs_of_membership_and_non_membership() {
+    let mut rng = DevRng::new();
+
+    for hash in [TrieHash::Pedersen, TrieHash::Poseidon] {
+        // Keys sharing prefixes of various lengths
+        let mut keys: Vec<FieldElement> = (0..8).map(|_| random_key(&mut rng)).collect();
+        keys.extend((0..4u64).map(FieldElement::from));
+        keys.push(keys[0] + FieldElement::ONE);
+        keys.sort_by_key(|k| k.to_uint());
+        keys.dedup();
+        let leaves: Vec<_> = keys
+            .iter()
+            .map(|k| (key_bits(k), Felt::from(rng.gen_range(1..u64::MAX))))
+            .collect();
+
+        let mut nodes = vec![];
+        let root = from_felt(build(&leaves, 0, hash, &mut nodes));
+
+        for (key, (_, value)) in keys.iter().zip(&leaves) {
+            let proven = trie::verify_proof(root, *key, &nodes, hash).unwrap();
+            assert_eq!(to_felt(proven), *value);
+        }
+        for _ in 0..10 {
+            let key = random_key(&mut rng);
+            let proven = trie::verify_proof(root, key, &nodes, hash).unwrap();
+            assert_eq!(proven, FieldElement::ZERO);
+        }
+        let proven = trie::verify_proof(root, FieldElement::from(4), &nodes, hash).unwrap();
+        assert_eq!(proven, FieldElement::ZERO);
+    }
+}
+
+#[test]
+fn root_of_trie() {
+    let mut rng = DevRng::new();
+
+    for hash in [TrieHash::Pedersen, TrieHash::Poseidon] {
+        let mut keys: Vec<FieldElement> = (0..8).map(|_| random_key(&mut rng)).collect();
+        keys.extend((0..4u64).map(FieldElement::from));
+        keys.sort_by_key(|k| k.to_uint());
+        let leaves: Vec<_> = keys
+            .iter()
+            .map(|k| (*k, FieldElement::random(&mut rng)))
+            .collect();
+        let reference_leaves: Vec<_> = leaves
+            .iter()
+            .map(|(k, v)| (key_bits(k), to_felt(*v)))
+            .collect();
+
+        let expected = build(&reference_leaves, 0, hash, &mut vec![]);
+        assert_eq!(
+            to_felt(trie::root(trie::HEIGHT, &leaves, hash).unwrap()),
+            expected
+        );
+    }
+
+    // Trie of height 64 is the same as trie of height 251 with keys padded by 187 zero bits on the left,
+    // except for the first edge
+    let leaves = [
+        (FieldElement::from(0), FieldElement::from(10)),
+        (FieldElement::from(1), FieldElement::from(11)),
+        (FieldElement::from(5), FieldElement::from(15)),
+    ];
+    let root_64 = trie::root(64, &leaves, TrieHash::Poseidon).unwrap();
+    let root_251 = trie::root(trie::HEIGHT, &leaves, TrieHash::Poseidon).unwrap();
+    let MerkleNode::Edge { child, .. } = find_root(root_251, &leaves) else {
+        unreachable!()
+    };
+    let expected_64 = MerkleNode::Edge {
+        child,
+        path: FieldElement::ZERO,
+        length: 61,
+    };
+    assert_eq!(root_64, expected_64.hash(TrieHash::Poseidon));
+
+    assert_eq!(
+        trie::root(64, &[], TrieHash::Poseidon).unwrap(),
+        FieldElement::ZERO
+    );
+    let unsorted = [leaves[1], leaves[0]];
+    assert!(trie::root(64, &unsorted, TrieHash::Poseidon).is_err());
+    let zero = [(FieldElement::ONE, FieldElement::ZERO)];
+    assert!(trie::root(64, &zero, TrieHash::Poseidon).is_err());
+    let out_of_range = [(-FieldElement::ONE, FieldElement::ONE)];
+    assert!(trie::root(64, &out_of_range, TrieHash::Poseidon).is_err());
+}
+
+#[test]
+fn empty_trie() {
+    let key = FieldElement::from(5);
+    let value = trie::verify_proof(FieldElement::ZERO, key, &[], TrieHash::Pedersen).unwrap();
+    assert_eq!(value, FieldElement::ZERO);
+}
+
+#[test]
+fn invalid_proofs() {
+    let mut rng = DevRng::new();
+    let mut keys = [random_key(&mut rng), random_key(&mut rng)];
+    keys.sort_by_key(|k| k.to_uint());
+    let leaves: Vec<_> = keys
+        .iter()
+        .map(|k| (key_bits(k), Felt::from(7u64)))
+        .collect();
+    let mut nodes = vec![];
+    let root = from_felt(build(&leaves, 0, TrieHash::Pedersen, &mut nodes));
+
+    // Missing node
+    let err = trie::verify_proof(root, keys[0], &nodes[1..], TrieHash::Pedersen).unwrap_err();
+    assert!(err.to_string().starts_with("proof doesn't contain node"));
+
+    // Root doesn't match its hash
+    let mut tampered = nodes.clone();
+    match &mut tampered[0].1 {
+        MerkleNode::Binary { left: x, .. } | MerkleNode::Edge { child: x, .. } => {
+            *x += FieldElement::ONE
+        }
+    }
+    let err = trie::verify_proof(root, keys[0], &tampered, TrieHash::Pedersen).unwrap_err();
+    assert!(err.to_string().ends_with("doesn't match"));
+
+    // Wrong hash function
+    assert!(trie::verify_proof(root, keys[0], &nodes, TrieHash::Poseidon).is_err());
+
+    // Key out of range
+    let err = trie::verify_proof(root, -FieldElement::ONE, &nodes, TrieHash::Pedersen).unwrap_err();
+    assert_eq!(err.to_string(), "key is out of range");
+
+    // Edge longer than the remaining height
+    let node = MerkleNode::Edge {
+        child: FieldElement::ONE,
+        path: FieldElement::ZERO,
+        length: 252,
+    };
+    let root = node.hash(TrieHash::Pedersen);
+    let err = trie::verify_proof(
+        root,
+        FieldElement::ZERO,
+        &[(root, node)],
+        TrieHash::Pedersen,
+    )
+    .unwrap_err();
+    assert!(err.to_string().ends_with("is malformed"));
+}
+
+#[test]
+fn contract_state_hash() {
+    let mut rng = DevRng::new();
+    let [class_hash, storage_root, nonce] =
+        core::array::from_fn(|_| FieldElement::random(&mut rng));
+
+    let expected = pedersen_hash(
+        &pedersen_hash(
+            &pedersen_hash(&to_felt(class_hash), &to_felt(storage_root)),
+            &to_felt(nonce),
+        ),
+        &Felt::ZERO,
+    );
+    assert_eq!(
+        to_felt(trie::contract_state_hash(class_hash, storage_root, nonce)),
+        expected
+    );
+}
+
+#[test]
+fn state_root() {
+    let mut rng = DevRng::new();
+    let [contracts_root, classes_root, compiled_class_hash] =
+        core::array::from_fn(|_| FieldElement::random(&mut rng));
+
+    let prefix = cairo_short_string_to_felt("CONTRACT_CLASS_LEAF_V0").unwrap();
+    assert_eq!(
+        to_felt(trie::class_leaf_hash(compiled_class_hash)),
+        poseidon_hash(prefix, to_felt(compiled_class_hash))
+    );
+
+    let prefix = cairo_short_string_to_felt("STARKNET_STATE_V0").unwrap();
+    assert_eq!(
+        to_felt(trie::state_root(contracts_root, classes_root)),
+        poseidon_hash_many(&[prefix, to_felt(contracts_root), to_felt(classes_root)])
+    );
+    assert_eq!(
+        trie::state_root(contracts_root, FieldElement::ZERO),
+        contracts_root
+    );
+}
+
+#[test]
+fn storage_value_against_state_root() {
+    let mut rng = DevRng::new();
+    let [address, class_hash, nonce, storage_key, storage_value] =
+        core::array::from_fn(|_| random_key(&mut rng));
+
+    let storage_leaves = [(storage_key, storage_value)];
+    let storage_root = trie::root(trie::HEIGHT, &storage_leaves, TrieHash::Pedersen).unwrap();
+    let contract_leaf = trie::contract_state_hash(class_hash, storage_root, nonce);
+    let contracts_leaves = [(address, contract_leaf)];
+    let contracts_root = trie::root(trie::HEIGHT, &contracts_leaves, TrieHash::Pedersen).unwrap();
+    let classes_leaves = [(class_hash, trie::class_leaf_hash(random_key(&mut rng)))];
+    let classes_root = trie::root(trie::HEIGHT, &classes_leaves, TrieHash::Poseidon).unwrap();
+    let state_root = trie::state_root(contracts_root, classes_root);
+
+    // Proofs of single-leaf tries consist of the root edge
+    let edge = |key, child| MerkleNode::Edge {
+        child,
+        path: key,
+        length: trie::HEIGHT,
+    };
+    let storage_proof = [(storage_root, edge(storage_key, storage_value))];
+    let contracts_proof = [(contracts_root, edge(address, contract_leaf))];
+
+    // Roots of contracts and classes tries come with the proof, and must match the trusted state root
+    assert_eq!(trie::state_root(contracts_root, classes_root), state_root);
+    let leaf = trie::verify_proof(
+        contracts_root,
+        address,
+        &contracts_proof,
+        TrieHash::Pedersen,
+    )
+    .unwrap();
+    assert_eq!(
+        leaf,
+        trie::contract_state_hash(class_hash, storage_root, nonce)
+    );
+    let value = trie::verify_proof(
+        storage_root,
+        storage_key,
+        &storage_proof,
+        TrieHash::Pedersen,
+    )
+    .unwrap();
+    assert_eq!(value, storage_value);
+}
+
+/// Returns the top edge of the trie of height 251 whose leaves have keys less than 8
+fn find_root(root: FieldElement, leaves: &[(FieldElement, FieldElement)]) -> MerkleNode {
+    let mut nodes = vec![];
+    let reference_leaves: Vec<_> = leaves
+        .iter()
+        .map(|(k, v)| (key_bits(k), to_felt(*v)))
+        .collect();
+    build(&reference_leaves, 0, TrieHash::Poseidon, &mut nodes);
+    nodes
+        .into_iter()
+        .find(|(hash, _)| *hash == root)
+        .map(|(_, node)| node)
+        .unwrap()
+}
+
+/// Builds subtrie of `leaves` (sorted by key) below `height`, returns its hash
+fn build(
+    leaves: &[(Vec<bool>, Felt)],
+    height: usize,
+    hash: TrieHash,
+    nodes: &mut Vec<(FieldElement, MerkleNode)>,
+) -> Felt {
+    if height == 251 {
+        assert_eq!(leaves.len(), 1);
+        return leaves[0].1;
+    }
+    let h = |x: &Felt, y: &Felt| match hash {
+        TrieHash::Pedersen => pedersen_hash(x, y),
+        TrieHash::Poseidon => poseidon_hash(*x, *y),
+    };
+
+    let first = &leaves[0].0;
+    let last = &leaves[leaves.len() - 1].0;
+    let common = (height..251).take_while(|&i| first[i] == last[i]).count();
+    let (node, node_hash) = if common > 0 {
+        let child = build(leaves, height + common, hash, nodes);
+        let path = first[height..height + common]
+            .iter()
+            .fold(Felt::ZERO, |acc, &bit| {
+                acc * Felt::TWO + Felt::from(bit as u64)
+            });
+        let node = MerkleNode::Edge {
+            child: from_felt(child),
+            path: from_felt(path),
+            length: common as u8,
+        };
+        (node, h(&child, &path) + Felt::from(common as u64))
+    } else {
+        let split = leaves.partition_point(|(key, _)| !key[height]);
+        let left = build(&leaves[..split], height + 1, hash, nodes);
+        let right = build(&leaves[split..], height + 1, hash, nodes);
+        let node = MerkleNode::Binary {
+            left: from_felt(left),
+            right: from_felt(right),
+        };
+        (node, h(&left, &right))
+    };
+    nodes.insert(0, (from_felt(node_hash), node));
+    node_hash
+}
+
+/// Bits of the key, most significant first
+fn key_bits(key: &FieldElement) -> Vec<bool> {
+    let bytes: [u8; 32] = key.to_be_bytes().into();
+    (5..256)
+        .map(|i| bytes[i / 8] >> (7 - i % 8) & 1 == 1)
+        .collect()
+}
+
+fn random_key(rng: &mut DevRng) -> FieldElement {
+    let mut bytes = [0u8; 32];
+    rng.fill(&mut bytes);
+    bytes[0] &= 0x07;
+    FieldElement::from_be_bytes_mod_order(&bytes)
+}
@@ -0,0 +1,291 @@
+#![cfg(feature = "alloc")]
+
+use stark_curve::typed_data::{Domain, Member, Revision, TypedData, Value};
+use stark_curve::{cairo, FieldElement};
+
+mod common;
+use common::{felt, to_felt};
+
+/// Typed data and expected message hashes for account `0x1234`, taken from `starknet-core` crate tests
+const TEST_VECTORS: &[(&str, &str)] = &[
+    (
+        include_str!("data/typed_data/v0_struct.json"),
+        "0778d68fe2baf73ee78a6711c29bad4722680984c1553a8035c8cb3feb5310c9",
+    ),
+    (
+        include_str!("data/typed_data/v1_struct.json"),
+        "045bca39274d2b7fdf7dc7c4ecf75f6549f614ce44359cc62ec106f4e5cc87b4",
+    ),
+    (
+        include_str!("data/typed_data/v1_basic_types.json"),
+        "0795c7e03a0ef83c4e3dee6942ef64d4126a91cafbda207356dae1de3bed4063",
+    ),
+    (
+        include_str!("data/typed_data/v1_preset.json"),
+        "068b85f4061d8155c0445f7e3c6bae1e7641b88b1d3b7c034c0b4f6c30eb5049",
+    ),
+    (
+        include_str!("data/typed_data/v1_simple_enum.json"),
+        "05cb0569ef378e0c17c07c13cb86bc6e067f824ccffd79fd49d875ecc0296124",
+    ),
+    (
+        include_str!("data/typed_data/v1_nested_enum.json"),
+        "0470e6107a4d464e16d8f77ff673c06f6fbfe107fef1e496e53b10d3744afd42",
+    ),
+    (
+        include_str!("data/typed_data/v1_merkletree.json"),
+        "064bd27eb802de8c83ff1437394c142bbe771530a248c548fab27ac3bcd2a503",
+    ),
+];
+
+#[test]
+fn message_hash_test_vectors() {
+    let account = FieldElement::from(0x1234);
+    for (json, expected) in TEST_VECTORS {
+        let typed_data = from_json(json);
+        let hash = typed_data.message_hash(account).unwrap();
+        assert_eq!(hash, felt(expected), "{json}");
+
+        let reference = serde_json::from_str::<starknet_core::types::TypedData>(json).unwrap();
+        assert_eq!(
+            to_felt(hash),
+            reference.message_hash(to_felt(account)).unwrap()
+        );
+    }
+}
+
+#[test]
+fn domain_hash() {
+    // Taken from `starknet-core` crate tests
+    let mut domain = Domain {
+        name: cairo::short_string("Starknet Example").unwrap(),
+        version: FieldElement::from(1),
+        chain_id: cairo::short_string("SN_MAIN").unwrap(),
+        revision: Revision::V0,
+    };
+    assert_eq!(
+        domain.hash(),
+        felt("04f8ee4d303cd69ce9c78edadf62442865c89a1eec01fa413e126a058a69c28a")
+    );
+    domain.revision = Revision::V1;
+    assert_eq!(
+        domain.hash(),
+        felt("03bfc3e1ff0f5c85c05bb8073a64a40b038eed00a449bc337c8cd2758f634640")
+    );
+}
+
+#[test]
+fn custom_domain_type() {
+    let account = FieldElement::from(0x1234);
+    let reordered = TEST_VECTORS[0].0.replace(
+        r#"{ "name": "version", "type": "felt" },
+      { "name": "chainId", "type": "felt" }"#,
+        r#"{ "name": "chainId", "type": "felt" },
+      { "name": "version", "type": "felt" }"#,
+    );
+    let renamed = TEST_VECTORS[1]
+        .0
+        .replace(r#""name": "revision""#, r#""name": "salt""#);
+    for json in [reordered, renamed] {
+        assert_eq!(
+            from_json(&json)
+                .message_hash(account)
+                .unwrap_err()
+                .to_string(),
+            "definition of domain type doesn't match the revision"
+        );
+        // `starknet-core` rejects such data too
+        assert!(serde_json::from_str::<starknet_core::types::TypedData>(&json).is_err());
+    }
+}
+
+#[test]
+fn type_encoding() {
+    let typed_data = from_json(TEST_VECTORS[5].0);
+    assert_eq!(
+        typed_data.encode_type("Example Message").unwrap(),
+        r#""Example Message"("Value":"My Enum")"My Enum"("Variant 1":(),"Variant 2":("string","My Object*"),"Variant 3":("u128"))"My Object"("Some Selector":"selector","Some Contract Address":"ContractAddress")"#
+    );
+
+    let typed_data = from_json(TEST_VECTORS[0].0);
+    assert_eq!(
+        typed_data.encode_type("Example Message").unwrap(),
+        "Example Message(Name:string,Some Array:u128*,Some Object:My Object)\
+         My Object(Some Selector:selector,Some Contract Address:ContractAddress)"
+    );
+
+    let typed_data = from_json(TEST_VECTORS[3].0);
+    assert_eq!(
+        typed_data.encode_type("Example Message").unwrap(),
+        r#""Example Message"("Uint":"u256","Amount":"TokenAmount","Id":"NftId")"NftId"("collection_address":"ContractAddress","token_id":"u256")"TokenAmount"("token_address":"ContractAddress","amount":"u256")"u256"("low":"u128","high":"u128")"#
+    );
+}
+
+#[test]
+fn invalid_data() {
+    let error = |message| hash_message(message).unwrap_err().to_string();
+    let leaves = || Value::Array(vec![Value::from(1u128)]);
+
+    assert_eq!(
+        error(Value::Boolean(true)),
+        "value doesn't match type `Message`"
+    );
+    assert_eq!(
+        error(Value::object([("a", Value::from(1u128))])),
+        "number of fields doesn't match definition of `Message`"
+    );
+    assert_eq!(
+        error(Value::object([("a", Value::from(1u128)), ("b", leaves())])),
+        "value of field `tree` is missing"
+    );
+    assert_eq!(
+        error(Value::object([
+            ("a", Value::from(1u128)),
+            ("tree", Value::Array(vec![]))
+        ])),
+        "merkle tree must have at least one leaf"
+    );
+    assert_eq!(
+        error(Value::object([
+            ("a", Value::from(-1i128)),
+            ("tree", leaves())
+        ])),
+        "value doesn't match type `u128`"
+    );
+    assert_eq!(
+        error(Value::object([
+            ("a", Value::from("0x100000000000000000000000000000000")),
+            ("tree", leaves())
+        ])),
+        "`0x100000000000000000000000000000000` is not a valid number"
+    );
+    assert!(hash_message(Value::object([
+        ("a", Value::from("0xffffffffffffffffffffffffffffffff")),
+        ("tree", leaves())
+    ]))
+    .is_ok());
+
+    let typed_data = TypedData::new(
+        [("Broken".to_owned(), vec![Member::new("x", "Missing")])]
+            .into_iter()
+            .collect(),
+        test_domain(),
+        "Broken",
+        Value::Boolean(true),
+    );
+    assert_eq!(
+        typed_data.type_hash("Broken").unwrap_err().to_string(),
+        "type `Missing` is not defined"
+    );
+
+    // Preset types are defined only in revision 1
+    let types = [("Message".to_owned(), vec![Member::new("x", "u256")])]
+        .into_iter()
+        .collect();
+    let message = Value::object([(
+        "x",
+        Value::object([("low", Value::from(1u128)), ("high", Value::from(0u128))]),
+    )]);
+    let domain = Domain {
+        revision: Revision::V0,
+        ..test_domain()
+    };
+    let typed_data = TypedData::new(types, domain, "Message", message);
+    assert_eq!(
+        typed_data
+            .message_hash(FieldElement::ZERO)
+            .unwrap_err()
+            .to_string(),
+        "type `u256` is not defined"
+    );
+}
+
+fn hash_message(message: Value) -> Result<FieldElement, stark_curve::typed_data::Error> {
+    let types = [(
+        "Message".to_owned(),
+        vec![
+            Member::new("a", "u128"),
+            Member::new("tree", "merkletree").with_contains("felt"),
+        ],
+    )]
+    .into_iter()
+    .collect();
+    TypedData::new(types, test_domain(), "Message", message).message_hash(FieldElement::ZERO)
+}
+
+fn test_domain() -> Domain {
+    Domain {
+        name: FieldElement::ONE,
+        version: FieldElement::ONE,
+        chain_id: FieldElement::ONE,
+        revision: Revision::V1,
+    }
+}
+
+fn from_json(json: &str) -> TypedData {
+    let json: serde_json::Value = serde_json::from_str(json).unwrap();
+
+    let types = json["types"]
+        .as_object()
+        .unwrap()
+        .iter()
+        .map(|(name, members)| {
+            let members = members
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|member| {
+                    let m = Member::new(
+                        member["name"].as_str().unwrap(),
+                        member["type"].as_str().unwrap(),
+                    );
+                    match member.get("contains") {
+                        Some(contains) => m.with_contains(contains.as_str().unwrap()),
+                        None => m,
+                    }
+                })
+                .collect();
+            (name.clone(), members)
+        })
+        .collect();
+
+    let domain_field = |name: &str| match &json["domain"][name] {
+        serde_json::Value::String(s) => s.parse().ok().or_else(|| cairo::short_string(s)).unwrap(),
+        serde_json::Value::Number(n) => FieldElement::from(n.as_u64().unwrap()),
+        v => panic!("unexpected domain field {v}"),
+    };
+    let revision = match &json["domain"]["revision"] {
+        serde_json::Value::Null => Revision::V0,
+        v if v == "1" || v == 1 => Revision::V1,
+        _ => Revision::V0,
+    };
+    let domain = Domain {
+        name: domain_field("name"),
+        version: domain_field("version"),
+        chain_id: domain_field("chainId"),
+        revision,
+    };
+
+    TypedData::new(
+        types,
+        domain,
+        json["primaryType"].as_str().unwrap(),
+        value_from_json(&json["message"]),
+    )
+}
+
+fn value_from_json(json: &serde_json::Value) -> Value {
+    match json {
+        serde_json::Value::Bool(b) => Value::Boolean(*b),
+        serde_json::Value::Number(n) => match n.as_u64() {
+            Some(n) => Value::UnsignedInteger(n.into()),
+            None => Value::SignedInteger(n.as_i64().unwrap().into()),
+        },
+        serde_json::Value::String(s) => Value::String(s.clone()),
+        serde_json::Value::Array(a) => Value::Array(a.iter().map(value_from_json).collect()),
+        serde_json::Value::Object(o) => {
+            Value::object(o.iter().map(|(k, v)| (k.as_str(), value_from_json(v))))
+        }
+        serde_json::Value::Null => panic!("null is not supported"),
+    }
+}
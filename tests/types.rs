@@ -0,0 +1,65 @@
+use stark_curve::account::ADDRESS_UPPER_BOUND;
+use stark_curve::bigint::U256;
+use stark_curve::types::{ClassHash, ContractAddress, StorageKey};
+use stark_curve::FieldElement;
+
+#[test]
+fn contract_address_domain() {
+    let bound = FieldElement::from_uint_mod_order(&ADDRESS_UPPER_BOUND);
+    let max = FieldElement::from_uint_mod_order(&ADDRESS_UPPER_BOUND.wrapping_sub(&U256::ONE));
+
+    assert_eq!(FieldElement::from(ContractAddress::new(max).unwrap()), max);
+    assert!(ContractAddress::new(bound).is_err());
+    assert!(ContractAddress::try_from(-FieldElement::from(1)).is_err());
+
+    assert_eq!(
+        FieldElement::from(ContractAddress::reduce(bound)),
+        FieldElement::from(0)
+    );
+    assert_eq!(FieldElement::from(ContractAddress::reduce(max)), max);
+}
+
+#[test]
+fn storage_key_domain() {
+    let max = "0x7ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+    let key: StorageKey = max.parse().unwrap();
+    assert_eq!(format!("{key:#x}"), max);
+    assert!(
+        "0x800000000000000000000000000000000000000000000000000000000000000"
+            .parse::<StorageKey>()
+            .is_err()
+    );
+    assert!("not a number".parse::<StorageKey>().is_err());
+}
+
+#[test]
+fn class_hash_accepts_any_felt() {
+    let p_minus_one = "0x800000000000011000000000000000000000000000000000000000000000000";
+    let hash: ClassHash = p_minus_one.parse().unwrap();
+    assert_eq!(format!("{hash:#x}"), p_minus_one);
+    assert_eq!(
+        ClassHash::from(FieldElement::from(7)),
+        ClassHash::new(7.into())
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_roundtrip() {
+    let address: ContractAddress = "0x1234".parse().unwrap();
+    let json = serde_json::to_string(&address).unwrap();
+    assert_eq!(json, r#""0x1234""#);
+    assert_eq!(
+        serde_json::from_str::<ContractAddress>(&json).unwrap(),
+        address
+    );
+    assert_eq!(
+        serde_json::from_str::<ClassHash>(r#""4660""#).unwrap(),
+        ClassHash::new(0x1234.into())
+    );
+
+    let too_big = r#""0x7ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff00""#;
+    assert!(serde_json::from_str::<ContractAddress>(too_big).is_err());
+    assert!(serde_json::from_str::<StorageKey>(too_big).is_ok());
+    assert!(serde_json::from_str::<StorageKey>("1").is_err());
+}